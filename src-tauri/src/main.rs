@@ -9,7 +9,8 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
-use std::sync::{Arc, Mutex};
+use parking_lot::Mutex;
+use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tauri::{
   image::Image,
@@ -28,30 +29,208 @@ use tokio::time::sleep;
 use image::{ImageBuffer, Rgb};
 use nokhwa::{
     pixel_format::RgbFormat,
-    utils::{ApiBackend, CameraIndex, CameraInfo, RequestedFormat, RequestedFormatType},
-    // Buffer, // ✨ 수정: 사용하지 않는 import 제거
+    utils::{
+        ApiBackend, CameraFormat, CameraIndex, CameraInfo, FrameFormat, RequestedFormat,
+        RequestedFormatType, Resolution,
+    },
+    Buffer,
     Camera,
 };
 
 use sqlx;
 use tauri_plugin_log::{Target, TargetKind};
 use tauri_plugin_sql::{DbInstances, Migration, MigrationKind};
+use tauri_plugin_store::StoreExt;
 
+mod metrics;
 mod pose_analysis;
-use pose_analysis::PoseAnalyzer;
+use metrics::{Metrics, DEFAULT_METRICS_PORT};
+use pose_analysis::{
+    BaselineRatios, DetectorToggles, ImageNormalization, PoseAnalyzer, ResizeFilter, ScoreWeights,
+    SubjectSelection, ANALYSIS_SCHEMA_VERSION, COCO_SKELETON, DEFAULT_CONFIDENCE_THRESHOLD,
+    DEFAULT_MAX_FRAME_DIMENSION, DEFAULT_MODEL_INPUT_SIZE,
+};
+
+const DB_URL: &str = "sqlite:posture_data.db";
+// posture_log.timestamp는 밀리초 단위. 이전 버전이 남긴 초 단위 값(1e11 미만)은 읽을 때 밀리초로 환산
+const TIMESTAMP_MS_SQL: &str =
+    "(CASE WHEN timestamp < 100000000000 THEN timestamp * 1000 ELSE timestamp END)";
+// 프론트엔드와 공유하는 설정 저장소 파일
+const SETTINGS_STORE: &str = ".settings.dat";
+// 설정 백업 파일 형식 버전 (호환되지 않는 변경 시 증가)
+const SETTINGS_BACKUP_VERSION: u32 = 1;
+// 캘리브레이션 문자열 형식 버전 ("pn<버전>:" 접두사 뒤에 base64로 인코딩한 JSON)
+const CALIBRATION_STRING_VERSION: u32 = 1;
+// 기기마다 달라서 백업에 포함하지 않는 설정 키
+const NON_PORTABLE_SETTING_KEYS: &[&str] = &["calibratedImagePath"];
+// 시간별 집계 작업 주기 및 요약 테이블을 사용하기 시작하는 조회 범위 기준
+const HOURLY_ROLLUP_INTERVAL_SECS: u64 = 600;
+const HOURLY_STATS_MIN_RANGE_SECS: i64 = 2 * 24 * 3600;
+// 바른 자세 비율 계산 시 기본 "좋은 자세" 점수 기준
+const DEFAULT_GOOD_SCORE_THRESHOLD: i64 = 80;
+// 자세 추세 기울기(구간당 점수 변화)가 이 값 이하이면 변화 없음으로 판단
+const TREND_FLAT_SLOPE: f64 = 0.5;
+// 자세 추세 조회 한 번에 나눌 수 있는 최대 구간 수 (일 단위로 약 1년)
+const MAX_TREND_BUCKETS: i64 = 366;
+// 시스템 알림 본문 최대 길이 (문자 수)
+const MAX_NOTIFICATION_BODY_CHARS: usize = 200;
+// 카메라 초기화 재시도 간격(초)과 사용자에게 알리기 시작하는 연속 실패 횟수
+const CAMERA_BACKOFF_BASE_SECS: u64 = 5;
+const CAMERA_BACKOFF_MAX_SECS: u64 = 300;
+const CAMERA_ERROR_EVENT_THRESHOLD: u32 = 3;
+// 연결된 카메라가 없을 때 장치 목록을 다시 확인하는 주기(초)
+const CAMERA_RESCAN_INTERVAL_SECS: u64 = 10;
+// 종료 시 백그라운드 작업이 끝나기를 기다리는 최대 시간(초)
+const SHUTDOWN_TIMEOUT_SECS: u64 = 5;
+// 별도 설정이 없을 때 쌓인 알림을 묶어 보내는 주기(초)
+const DEFAULT_ALERT_FLUSH_INTERVAL_SECS: u64 = 3;
+// 평균 밝기(0~255) 기본 경고 기준과, 경고 전에 연속으로 어두워야 하는 프레임 수
+const DEFAULT_LOW_LIGHT_THRESHOLD: f64 = 40.0;
+const LOW_LIGHT_FRAME_COUNT: u32 = 3;
+// 적응형 주기 계산에 사용하는 최근 점수 개수와, 조정을 시작하기 위한 최소 개수
+const ADAPTIVE_SCORE_WINDOW: usize = 10;
+const ADAPTIVE_MIN_SAMPLES: usize = 5;
+// 오래 앉아 있기 경고 기본 시간(분)과, 자리를 비운 것으로 보고 타이머를 초기화하는 유예 시간(초)
+const DEFAULT_SITTING_LIMIT_MINS: u64 = 50;
+const DEFAULT_SITTING_GRACE_SECS: u64 = 120;
+// 설정 가능한 오래 앉아 있기 경고 시간(분)과 자리 비움 유예 시간(초)의 최대값
+const MAX_SITTING_LIMIT_MINS: u64 = 8 * 60;
+const MAX_SITTING_GRACE_SECS: u64 = 3600;
+// 모니터링 시작 직후 자세 알림을 보내지 않는 기본 준비 시간(초)과 설정 가능한 최대값
+const DEFAULT_STARTUP_GRACE_SECS: u64 = 30;
+const MAX_STARTUP_GRACE_SECS: u64 = 600;
+// 절약 모드 캡처 시 카메라를 켠 뒤 기다리는 시간과 첫 프레임을 버린 뒤 기다리는 시간(ms) 기본값과 최대값
+const DEFAULT_CAMERA_WARMUP_MS: u64 = 3000;
+const DEFAULT_CAMERA_DROP_DELAY_MS: u64 = 100;
+const MAX_CAMERA_WARMUP_MS: u64 = 10_000;
+const MAX_CAMERA_DROP_DELAY_MS: u64 = 2000;
+// 주간 리포트 발송 시점 확인 주기
+const WEEKLY_REPORT_CHECK_INTERVAL_SECS: u64 = 600;
+// 화면 잠금/절전 확인 주기(초)와, 벽시계가 이 이상 더 흘렀으면 시스템이 절전했던 것으로 보는 여유 시간(초)
+const SYSTEM_STATE_POLL_SECS: u64 = 5;
+const SYSTEM_SLEEP_GAP_SECS: u64 = 30;
+// 모델 초기화 시도 횟수, 시도당 제한 시간(초), 재시도 대기 간격(초)
+const MODEL_INIT_MAX_ATTEMPTS: u32 = 3;
+const MODEL_INIT_TIMEOUT_SECS: u64 = 60;
+const MODEL_INIT_RETRY_DELAY_SECS: u64 = 2;
+// 기록과 연결해 메모리에 보관하는 최근 프레임 썸네일 수와 썸네일 너비(px)
+const RECENT_FRAME_CACHE_SIZE: usize = 30;
+const THUMBNAIL_WIDTH: u32 = 160;
+// 활동 라벨의 최대 길이 (문자 수)
+const MAX_ACTIVITY_LABEL_CHARS: usize = 64;
+// DB 저장 실패 시 메모리에 보관할 최대 기록 수
+const PENDING_SAMPLE_CAPACITY: usize = 500;
+// 캘리브레이션 이미지와 미리보기 썸네일을 다시 인코딩할 때의 JPEG 품질 기본값 (1~100)
+const DEFAULT_IMAGE_QUALITY: u8 = 80;
+
+// 안내형 캘리브레이션: 채택 프레임 최소 비율과 얼굴-어깨 비율의 최대 변동계수(표준편차/평균)
+const GUIDED_CALIBRATION_MIN_ACCEPTED_RATIO: f32 = 0.6;
+const GUIDED_CALIBRATION_MAX_VARIATION: f32 = 0.15;
+
+// 카메라 풀: 카메라를 매번 여닫을 때의 화면 비교 주기, 전환에 필요한 최소 신뢰도 차이
+const CAMERA_POOL_PROBE_INTERVAL_SECS: u64 = 60;
+const CAMERA_POOL_SWITCH_MARGIN: f32 = 0.1;
+
+// 감도 확인 모드 기본 시간과 최대 시간(초), 확인 중 사용할 모니터링 주기(초)
+const DEFAULT_SENSITIVITY_CHECK_SECS: u64 = 60;
+const MAX_SENSITIVITY_CHECK_SECS: u64 = 300;
+const SENSITIVITY_CHECK_INTERVAL_SECS: u64 = 2;
+// 집중 모드로 지정할 수 있는 최대 시간(분)과 최대 모니터링 주기(초)
+const MAX_FOCUS_MODE_MINS: u64 = 8 * 60;
+const MAX_FOCUS_MODE_INTERVAL_SECS: u64 = 3600;
+
+// 알림 웹훅 요청 제한 시간 (응답이 느려도 모니터링에는 영향 없음)
+const WEBHOOK_TIMEOUT_SECS: u64 = 5;
+
+// 세션 녹화: 최대 프레임 수, 애니메이션 크기와 프레임당 재생 시간 (분석 주기와 관계없이 빠르게 재생)
+const MAX_SESSION_RECORDING_FRAMES: usize = 600;
+const SESSION_RECORDING_WIDTH: u32 = 320;
+const SESSION_RECORDING_HEIGHT: u32 = 240;
+const SESSION_RECORDING_FRAME_DELAY_MS: u32 = 200;
+
+// 지원 번들에 넣는 최근 로그 줄 수 기본값과 최대값
+const DEFAULT_SUPPORT_BUNDLE_LOG_LINES: usize = 200;
+const MAX_SUPPORT_BUNDLE_LOG_LINES: usize = 2000;
+// 지원 번들에서 값을 가리는 설정 키 (외부 주소 등 개인 정보)
+const REDACTED_SETTING_KEYS: &[&str] = &["webhookUrl"];
+// 백엔드가 읽는 설정 키와 기대하는 값 종류 (repair_settings에서 손상된 항목 판단용, 그 밖의 키는 검사하지 않음)
+const SETTING_VALUE_KINDS: &[(&str, SettingKind)] = &[
+    ("alertTiers", SettingKind::Object),
+    ("autoStartMonitoring", SettingKind::Bool),
+    ("autostartEnabled", SettingKind::Bool),
+    ("activeCalibrationProfile", SettingKind::OptionalString),
+    ("cameraPool", SettingKind::Array),
+    ("cameraDropDelayMs", SettingKind::Number),
+    ("cameraPoolKeepOpen", SettingKind::Bool),
+    ("cameraRotation", SettingKind::Number),
+    ("cameraWarmupMs", SettingKind::Number),
+    ("calibratedImagePath", SettingKind::String),
+    ("confidenceThreshold", SettingKind::Number),
+    ("currentActivity", SettingKind::OptionalString),
+    ("currentLanguage", SettingKind::String),
+    ("detectionCounts", SettingKind::OptionalObject),
+    ("detectionThresholds", SettingKind::OptionalObject),
+    ("detectorToggles", SettingKind::Object),
+    ("imageNormalization", SettingKind::String),
+    ("imageQuality", SettingKind::Number),
+    ("loggingEnabled", SettingKind::Bool),
+    ("lowLightThreshold", SettingKind::Number),
+    ("lowPowerInference", SettingKind::Bool),
+    ("maxFrameDimension", SettingKind::Number),
+    ("metricsEnabled", SettingKind::Bool),
+    ("metricsPort", SettingKind::Number),
+    ("minDetectionRegion", SettingKind::Number),
+    ("modelInputSize", SettingKind::Number),
+    ("monitoringShortcut", SettingKind::String),
+    ("notificationFrequency", SettingKind::Number),
+    ("motionGating", SettingKind::Bool),
+    ("motionGatingThreshold", SettingKind::Number),
+    ("onnxOptimizationLevel", SettingKind::Number),
+    ("privacyMode", SettingKind::Bool),
+    ("recalibrationNudge", SettingKind::Bool),
+    ("resizeFilter", SettingKind::String),
+    ("scoreWeights", SettingKind::Object),
+    ("sensitivityLevels", SettingKind::Array),
+    ("sittingGraceSecs", SettingKind::Number),
+    ("sittingLimitMins", SettingKind::Number),
+    ("startupGraceSecs", SettingKind::Number),
+    ("trayVisible", SettingKind::Bool),
+    ("webhookUrl", SettingKind::OptionalString),
+    ("weeklyReportDay", SettingKind::Number),
+    ("weeklyReportHour", SettingKind::Number),
+    ("weeklyReportLastSent", SettingKind::Number),
+];
+
+// 메모리에 보관하는 알림 기록 최대 개수
+const ALERT_HISTORY_CAPACITY: usize = 200;
+
+// 움직임 감지 생략: 비교용 축소 프레임 크기, 평균 픽셀 차이 기본 기준(0~255), 연속으로 결과를 재사용할 최대 횟수
+const MOTION_GATING_FRAME_SIZE: u32 = 64;
+const DEFAULT_MOTION_GATING_THRESHOLD: f32 = 2.0;
+const MOTION_GATING_MAX_CONSECUTIVE_SKIPS: u32 = 10;
+
+// 지원하는 언어 목록과 시스템 언어를 지원하지 않을 때의 기본 언어
+const SUPPORTED_LANGUAGES: &[&str] = &["en", "ko", "ja", "zh"];
+const FALLBACK_LANGUAGE: &str = "en";
+// 키가 빠졌을 때 영어보다 먼저 찾아볼 가까운 언어 (locales/fallbacks.json으로 덮어쓸 수 있음)
+// 지역 변형(zh-tw 등)은 따로 적지 않아도 기본 언어(zh)를 거쳐 영어로 대체됨
+const DEFAULT_LANGUAGE_FALLBACKS: &[(&str, &[&str])] = &[("zh-tw", &["zh-hk", "zh"]), ("zh-hk", &["zh-tw", "zh"])];
+const LANGUAGE_FALLBACKS_FILE: &str = "fallbacks.json";
+
+// 모니터링 켜기/끄기 전역 단축키 기본값 (빈 문자열로 설정하면 사용 안 함)
+const DEFAULT_MONITORING_SHORTCUT: &str = "CommandOrControl+Alt+P";
 
 // --- 번역 관리 구조체 ---
 pub struct Translations {
     data: HashMap<String, HashMap<String, String>>,
+    fallbacks: HashMap<String, Vec<String>>,
 }
 
 impl Translations {
     // ✨ 수정: 함수를 제네릭으로 만들어 어떤 Runtime에서도 동작하게 함
     pub fn new<R: Runtime>(path_resolver: &PathResolver<R>) -> Self {
         let mut data = HashMap::new();
-        let locales = vec!["en", "ko", "ja", "zh"]; // 지원하는 언어 목록
-
-        for lang in locales {
+        for lang in SUPPORTED_LANGUAGES {
             if let Ok(resource_path) =
                 path_resolver.resolve(format!("../locales/{}.json", lang), BaseDirectory::Resource)
             {
@@ -71,21 +250,125 @@ impl Translations {
                 error!("'{}' 언어 리소스 경로를 찾을 수 없습니다.", lang);
             }
         }
-        Self { data }
+
+        // 지역 변형 번역 파일(zh-TW.json 등)이 있으면 함께 로드
+        let mut fallbacks: HashMap<String, Vec<String>> = DEFAULT_LANGUAGE_FALLBACKS
+            .iter()
+            .map(|(lang, chain)| (lang.to_string(), chain.iter().map(|l| l.to_string()).collect()))
+            .collect();
+        if let Ok(locales_dir) = path_resolver.resolve("../locales", BaseDirectory::Resource) {
+            for entry in fs::read_dir(&locales_dir).into_iter().flatten().flatten() {
+                let path = entry.path();
+                let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                    continue;
+                };
+                if file_name == LANGUAGE_FALLBACKS_FILE {
+                    match fs::read_to_string(&path)
+                        .ok()
+                        .and_then(|content| serde_json::from_str::<HashMap<String, Vec<String>>>(&content).ok())
+                    {
+                        Some(custom) => fallbacks.extend(custom.into_iter().map(|(lang, chain)| {
+                            (
+                                Self::locale_key(&lang),
+                                chain.iter().map(|l| Self::locale_key(l)).collect(),
+                            )
+                        })),
+                        None => error!("언어 대체 순서 파일 파싱 실패: {:?}", path),
+                    }
+                    continue;
+                }
+                let Some(locale) = file_name.strip_suffix(".json").map(Self::locale_key) else {
+                    continue;
+                };
+                if data.contains_key(&locale) || !locale.contains('-') {
+                    continue;
+                }
+                match fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<HashMap<String, String>>(&content).ok())
+                {
+                    Some(map) => {
+                        info!("'{}' 지역 언어 번역 파일 로드 성공.", locale);
+                        data.insert(locale, map);
+                    }
+                    None => error!("'{}' 지역 언어 번역 파일 파싱 실패: {:?}", locale, path),
+                }
+            }
+        }
+        Self { data, fallbacks }
+    }
+
+    // "zh_TW", "ZH-tw"를 "zh-tw" 형태로 맞춤
+    fn locale_key(locale: &str) -> String {
+        locale.replace('_', "-").to_lowercase()
+    }
+
+    // "en-US", "zh_CN" 같은 로케일을 지원하는 언어 코드로 변환 (지원하지 않으면 None)
+    pub fn normalize_language(locale: &str) -> Option<&'static str> {
+        let primary = locale.split(['-', '_']).next()?.to_lowercase();
+        SUPPORTED_LANGUAGES.iter().copied().find(|lang| *lang == primary)
+    }
+
+    // 로케일을 로드된 언어 코드로 변환. 지역 변형 번역이 있으면 그것을, 없으면 기본 언어를 사용
+    pub fn resolve_language(&self, locale: &str) -> Option<String> {
+        let key = Self::locale_key(locale);
+        if self.data.contains_key(&key) {
+            return Some(key);
+        }
+        Self::normalize_language(locale).map(str::to_string)
+    }
+
+    // 키를 찾아볼 언어 순서: 요청 언어 → 설정된 대체 언어 → 기본 언어(zh-tw → zh) → 영어
+    fn fallback_chain(&self, lang: &str) -> Vec<String> {
+        let lang = Self::locale_key(lang);
+        let mut chain = vec![lang.clone()];
+        if let Some(configured) = self.fallbacks.get(&lang) {
+            chain.extend(configured.iter().cloned());
+        }
+        if let Some(primary) = lang.split('-').next() {
+            chain.push(primary.to_string());
+        }
+        chain.push(FALLBACK_LANGUAGE.to_string());
+        let mut seen = std::collections::HashSet::new();
+        chain.retain(|l| seen.insert(l.clone()));
+        chain
     }
 
     pub fn get(&self, lang: &str, key: &str) -> String {
-        self.data
-            .get(lang)
-            .and_then(|translations| translations.get(key))
+        self.fallback_chain(lang)
+            .iter()
+            .find_map(|l| self.data.get(l).and_then(|translations| translations.get(key)))
             .cloned()
-            .unwrap_or_else(|| {
-                self.data
-                    .get("en")
-                    .and_then(|translations| translations.get(key))
-                    .cloned()
-                    .unwrap_or_else(|| key.to_string())
-            })
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    // 같은 뜻의 문구 묶음: key, key_2, key_3 ... 순서로 연속된 키를 모두 반환
+    // 문구가 섞이지 않도록 대체 순서에서 key를 처음 가진 언어 하나에서만 모음
+    pub fn variants(&self, lang: &str, key: &str) -> Vec<String> {
+        let chain = self.fallback_chain(lang);
+        let Some(translations) = chain
+            .iter()
+            .find_map(|l| self.data.get(l).filter(|translations| translations.contains_key(key)))
+        else {
+            return vec![key.to_string()];
+        };
+        let mut variants = vec![translations[key].clone()];
+        while let Some(text) = translations.get(&format!("{}_{}", key, variants.len() + 1)) {
+            variants.push(text.clone());
+        }
+        variants
+    }
+
+    // 한 언어의 전체 번역을 대체 순서의 뒤쪽(영어)부터 덮어써 반환 (빠진 키는 가까운 언어로 채움)
+    pub fn all(&self, lang: &str) -> Option<HashMap<String, String>> {
+        let lang = self.resolve_language(lang)?;
+        let mut merged = HashMap::new();
+        for l in self.fallback_chain(&lang).iter().rev() {
+            if let Some(translations) = self.data.get(l) {
+                merged.extend(translations.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+        }
+        Some(merged)
     }
 }
 
@@ -96,19 +379,297 @@ struct CameraDetail {
     name: String,
 }
 
+// 카메라 초기화가 반복 실패할 때 재시도 간격을 늘리기 위한 상태
+#[derive(Default)]
+struct CameraBackoff {
+    consecutive_failures: u32,
+    retry_after: Option<Instant>,
+}
+
+impl CameraBackoff {
+    fn can_retry(&self) -> bool {
+        self.retry_after.map_or(true, |t| Instant::now() >= t)
+    }
+
+    fn record_failure(&mut self) -> Duration {
+        self.consecutive_failures += 1;
+        let exponent = (self.consecutive_failures - 1).min(16);
+        let delay = Duration::from_secs(
+            (CAMERA_BACKOFF_BASE_SECS << exponent).min(CAMERA_BACKOFF_MAX_SECS),
+        );
+        self.retry_after = Some(Instant::now() + delay);
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.consecutive_failures = 0;
+        self.retry_after = None;
+    }
+}
+
+// 움직임 감지 생략용 직전 프레임 (축소한 회색조 픽셀)과 그 분석 결과
+#[derive(Default)]
+struct MotionGate {
+    previous_frame: Option<Vec<u8>>,
+    previous_result: Option<String>,
+    consecutive_skips: u32,
+}
+
+// 여러 카메라 중 사람이 가장 잘 보이는 카메라를 고르기 위한 후보 목록
+// keep_open이면 후보 카메라를 계속 열어 두고 매 주기 비교하고, 아니면 일정 주기마다 잠깐 열어 비교
+#[derive(Default)]
+struct CameraPool {
+    indices: Vec<u32>,
+    keep_open: bool,
+    open_cameras: HashMap<u32, Camera>, // 현재 선택되지 않은 채 열려 있는 후보 카메라 (keep_open일 때만)
+    last_probe: Option<Instant>,
+}
+
+// 사용자가 화면에 계속 감지된 시간 (오래 앉아 있기 경고용)
+#[derive(Default)]
+struct PresenceTracker {
+    sitting_since: Option<Instant>,
+    last_seen: Option<Instant>,
+    warned: bool,
+    away: bool, // 유예 시간보다 오래 감지되지 않아 자리 비움으로 판단한 상태
+}
+
+// 적응형 주기: 최근 점수가 안정적으로 좋으면 max_secs 쪽으로, 나빠지면 min_secs 쪽으로 주기를 조정
+#[derive(Clone, Copy)]
+struct AdaptiveInterval {
+    min_secs: u64,
+    max_secs: u64,
+}
+
+// 집중 모드: 일정 시간 동안 모니터링 주기를 짧게 덮어씀
+#[derive(Clone, Copy)]
+struct FocusMode {
+    interval_secs: u64,
+    ends_at: Instant,
+}
+
+// 감도 확인 모드: 캘리브레이션 후 감지가 잘 되는지 확인하도록 잠시 가장 엄격한 기준을 적용
+// 끝나면 저장해 둔 감지 강도와 감지 횟수 기준으로 되돌림
+#[derive(Clone, Copy)]
+struct SensitivityCheck {
+    ends_at: Instant,
+    saved_thresholds: ((f32, f32), (f32, f32)),
+    saved_required_count: usize,
+    saved_release_count: usize,
+}
+
+// 세션 녹화 중 모은 키포인트 프레임 (원본 영상은 저장하지 않음)
+struct SessionRecording {
+    started_at_ms: i64,
+    frames: Vec<serde_json::Value>, // latest_pose_coco 형식의 프레임별 키포인트
+    truncated: bool,                // MAX_SESSION_RECORDING_FRAMES를 넘어 이후 프레임을 버렸는지
+}
+
+// 자세 점수에 따른 알림 단계 기준: warning_below 미만이면 주의, bad_below 미만이면 나쁨
+// 나쁨 단계에서만 시스템 알림을 보내고, 주의 단계는 앱 안에서 posture-warning 이벤트로만 알림
+// 점수는 확정된(히스테리시스 적용 후) 감지 상태로 계산한 confirmed_posture_score를 사용
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+struct AlertTiers {
+    warning_below: i64,
+    bad_below: i64,
+}
+
+impl Default for AlertTiers {
+    fn default() -> Self {
+        // 기본 가중치에서 거북목(70점)이나 어깨 비대칭(80점)이 확정되면 나쁨, 어깨 긴장만 있으면(90점) 주의
+        Self { warning_below: 95, bad_below: 85 }
+    }
+}
+
+impl AlertTiers {
+    fn classify(&self, score: i64) -> &'static str {
+        if score < self.bad_below {
+            "bad"
+        } else if score < self.warning_below {
+            "warning"
+        } else {
+            "good"
+        }
+    }
+
+    // 거북목/어깨 비대칭이 확정된 상태면 가중치를 낮춰 두었더라도 최소 주의 단계로 판정
+    fn classify_confirmed(&self, score: i64, confirmed_detection: bool) -> &'static str {
+        match self.classify(score) {
+            "good" if confirmed_detection => "warning",
+            tier => tier,
+        }
+    }
+}
+
+// DB에 저장하지 못해 다음 주기에 다시 저장할 posture_log 기록
+struct PendingSample {
+    score: i64,
+    is_turtle: bool,
+    is_shoulder: bool,
+    timestamp_ms: i64,
+    activity: Option<String>,
+    is_test: bool, // trigger_test_detection으로 만든 가짜 기록
+}
+
+// posture_log 기록 시각과 연결된 프레임 썸네일 (JPEG, 메모리에만 보관)
+struct CachedFrame {
+    timestamp_ms: i64,
+    jpeg: Vec<u8>,
+}
+
+// 알림 종류별로 시스템 알림 제목과 아이콘을 구분합니다. (아이콘은 앱 아이콘에 종류별 색상 배지를 붙인 것)
+#[derive(Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AlertKind {
+    TurtleNeck,
+    ShoulderMisaligned,
+    TurtleNeckAndShoulder,
+    SittingTooLong,
+}
+
+impl AlertKind {
+    fn title(self) -> &'static str {
+        match self {
+            AlertKind::TurtleNeck => "🐢",
+            AlertKind::ShoulderMisaligned => "⚖️",
+            AlertKind::TurtleNeckAndShoulder => "🐢⚖️",
+            AlertKind::SittingTooLong => "🪑",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            AlertKind::TurtleNeck => "icons/alert_turtle_neck.png",
+            AlertKind::ShoulderMisaligned => "icons/alert_shoulder.png",
+            AlertKind::TurtleNeckAndShoulder => "icons/alert_turtle_neck_shoulder.png",
+            AlertKind::SittingTooLong => "icons/alert_sitting.png",
+        }
+    }
+}
+
+// 설정 저장소 값의 JSON 종류 (손상된 설정 항목 검사용)
+#[derive(Clone, Copy)]
+enum SettingKind {
+    Bool,
+    Number,
+    String,
+    OptionalString,
+    Array,
+    Object,
+    OptionalObject,
+}
+
+impl SettingKind {
+    fn matches(self, value: &serde_json::Value) -> bool {
+        match self {
+            SettingKind::Bool => value.is_boolean(),
+            SettingKind::Number => value.is_number(),
+            SettingKind::String => value.is_string(),
+            SettingKind::OptionalString => value.is_string() || value.is_null(),
+            SettingKind::Array => value.is_array(),
+            SettingKind::Object => value.is_object(),
+            SettingKind::OptionalObject => value.is_object() || value.is_null(),
+        }
+    }
+}
+
+// 알림 주기마다 모아서 보내는 대기 중인 알림
+#[derive(Clone, serde::Serialize)]
+struct PendingAlert {
+    kind: AlertKind,
+    message: String,
+}
+
+// 읽어도 지워지지 않는 알림 기록 (최근 ALERT_HISTORY_CAPACITY개까지 보관)
+#[derive(Clone, serde::Serialize)]
+struct AlertHistoryEntry {
+    timestamp: i64,
+    kind: AlertKind,
+    message: String,
+}
+
 #[derive(Clone)]
 struct AppState {
     pose_analyzer: Arc<PoseAnalyzer>,
     monitoring_active: Arc<Mutex<bool>>,
     last_alert_time: Arc<Mutex<Instant>>,
-    alert_messages: Arc<Mutex<Vec<String>>>,
+    alert_messages: Arc<Mutex<Vec<PendingAlert>>>,
+    alert_history: Arc<Mutex<std::collections::VecDeque<AlertHistoryEntry>>>,
     camera: Arc<Mutex<Option<Camera>>>,
+    camera_backoff: Arc<Mutex<CameraBackoff>>,
+    preferred_camera_backend: Arc<Mutex<Option<ApiBackend>>>,
     selected_camera_index: Arc<Mutex<u32>>,
+    // 연결된 카메라가 하나도 없으면 true (장치가 다시 발견될 때까지 카메라 열기를 건너뜀)
+    no_camera: Arc<Mutex<bool>>,
+    low_light_threshold: Arc<Mutex<f64>>,
+    image_quality: Arc<Mutex<u8>>,
+    low_light_streak: Arc<Mutex<u32>>,
     monitoring_interval_secs: Arc<Mutex<u64>>,
+    focus_mode: Arc<Mutex<Option<FocusMode>>>,
+    sensitivity_check: Arc<Mutex<Option<SensitivityCheck>>>,
+    session_recording: Arc<Mutex<Option<SessionRecording>>>,
+    adaptive_interval: Arc<Mutex<Option<AdaptiveInterval>>>,
+    presence: Arc<Mutex<PresenceTracker>>,
+    sitting_limit_mins: Arc<Mutex<u64>>, // 0이면 오래 앉아 있기 경고 끔
+    sitting_grace_secs: Arc<Mutex<u64>>,
+    // 모니터링을 마지막으로 시작한 시각과, 그 뒤로 분석·기록만 하고 자세 알림은 보내지 않는 준비 시간(초)
+    monitoring_started_at: Arc<Mutex<Option<Instant>>>,
+    startup_grace_secs: Arc<Mutex<u64>>,
+    camera_warmup_ms: Arc<Mutex<(u64, u64)>>, // 절약 모드 캡처 대기 시간 (카메라 켠 뒤, 첫 프레임 버린 뒤)
+    alert_tiers: Arc<Mutex<AlertTiers>>,
+    posture_tier: Arc<Mutex<&'static str>>, // 마지막으로 판정한 알림 단계 (good/warning/bad)
+    recent_scores: Arc<Mutex<std::collections::VecDeque<f64>>>, // 적응형 주기 계산용 최근 자세 점수
+    // 모니터링 주기 관련 설정이 바뀌면 대기 중인 모니터링 루프를 깨움
+    interval_changed: Arc<tokio::sync::Notify>,
+    // 알림 묶음 전송 주기 (None이면 모니터링 주기를 따름)
+    alert_flush_interval_secs: Arc<Mutex<Option<u64>>>,
+    alert_interval_changed: Arc<tokio::sync::Notify>,
     translations: Arc<Translations>,
     current_language: Arc<Mutex<String>>,
     battery_saving_mode: Arc<Mutex<bool>>,
+    low_power_inference: Arc<Mutex<bool>>, // 배터리 절약 모드에서 저전력 추론까지 사용할지
+    recalibration_nudge: Arc<Mutex<bool>>, // 기준 자세가 오래되어 보이면 재캘리브레이션을 권장할지
+    privacy_mode: Arc<Mutex<bool>>,
+    logging_enabled: Arc<Mutex<bool>>,
+    // 데모 모드: 분석 이벤트는 보내되 DB 기록과 알림을 모두 막음 (저장하지 않으므로 재시작 시 해제)
+    demo_mode: Arc<Mutex<bool>>,
+    recent_frames: Arc<Mutex<std::collections::VecDeque<CachedFrame>>>,
+    pending_samples: Arc<Mutex<std::collections::VecDeque<PendingSample>>>,
+    current_activity: Arc<Mutex<Option<String>>>, // posture_log에 함께 기록할 활동 라벨 (예: coding, reading)
+    metrics: Arc<Metrics>,
+    metrics_server: Arc<Mutex<Option<tauri::async_runtime::JoinHandle<()>>>>,
+    // 앱 종료 시 true로 바뀌며, 백그라운드 루프는 이를 보고 빠져나감
+    shutdown: Arc<tokio::sync::watch::Sender<bool>>,
+    background_tasks: Arc<Mutex<Vec<tauri::async_runtime::JoinHandle<()>>>>,
     tray: Arc<Mutex<Option<TrayIcon>>>,
+    tray_visible: Arc<Mutex<bool>>,
+    // 마지막으로 연 카메라 (인덱스, 협상된 포맷). 바뀌면 분석기의 시간축 기록을 초기화
+    active_camera_source: Arc<Mutex<Option<(u32, CameraFormat)>>>,
+    // 현재 등록된 모니터링 토글 단축키
+    monitoring_shortcut: Arc<Mutex<Option<String>>>,
+    auto_paused: Arc<Mutex<Option<&'static str>>>, // 화면 잠금 등으로 자동 일시정지된 경우 그 이유
+    motion_gating_threshold: Arc<Mutex<Option<f32>>>, // 켜져 있으면 이 값보다 변화가 작은 프레임은 추론을 건너뜀
+    motion_gate: Arc<Mutex<MotionGate>>,
+    camera_pool: Arc<Mutex<CameraPool>>,
+    webhook_url: Arc<Mutex<Option<String>>>, // 알림마다 JSON을 POST할 외부 주소 (기본은 끔)
+    // CPU 사용률은 직전 측정과의 차이로 계산되므로 측정기를 유지
+    resource_monitor: Arc<Mutex<Option<sysinfo::System>>>,
+}
+
+impl AppState {
+    fn is_shutting_down(&self) -> bool {
+        *self.shutdown.borrow()
+    }
+
+    async fn shutdown_requested(&self) {
+        wait_for_shutdown(&self.shutdown).await;
+    }
+}
+
+// 종료 신호가 올 때까지 대기 (이미 종료 중이면 바로 반환)
+async fn wait_for_shutdown(shutdown: &tokio::sync::watch::Sender<bool>) {
+    let mut receiver = shutdown.subscribe();
+    let _ = receiver.wait_for(|shutting_down| *shutting_down).await;
 }
 
 // --- Tauri Commands ---
@@ -126,26 +687,74 @@ async fn analyze_pose_data(
     }
 }
 
+// 카메라 대신 사용자가 고른 이미지 파일을 분석해 결과와 키포인트를 함께 반환합니다 (모델 문제와 카메라 문제 구분용).
+// 실시간 모니터링의 감지 이력, 스무딩, 카메라 회전 설정은 적용하지도 바꾸지도 않습니다.
+#[tauri::command]
+async fn analyze_image_file(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<serde_json::Value, String> {
+    if !state.pose_analyzer.is_model_initialized() {
+        return Err("AI 모델이 아직 초기화되지 않았습니다.".to_string());
+    }
+    // 디코딩과 추론은 블로킹 작업이므로 별도 스레드에서 실행하고, 실시간 감지 상태는 건드리지 않는 경로로 분석
+    let analyzer = state.pose_analyzer.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let image = image::open(&path)
+            .map_err(|e| format!("이미지 파일을 열 수 없습니다 ({}): {}", path, e))?
+            .to_rgb8();
+        analyzer
+            .analyze_still_image(&image)
+            .map_err(|e| format!("이미지 분석 실패: {}", e))
+    })
+    .await
+    .map_err(|e| format!("이미지 분석 작업 실패: {}", e))?
+}
+
+// 현재 카메라 프레임에서 사용자의 위치(바운딩 박스)와 화면 이탈 여부를 확인합니다.
+#[tauri::command]
+async fn check_user_framing(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    if !state.pose_analyzer.is_model_initialized() {
+        return Err("AI 모델이 아직 초기화되지 않았습니다.".to_string());
+    }
+    let image = capture_frame(&app, &state)
+        .await
+        .as_ref()
+        .and_then(decode_frame)
+        .ok_or("카메라 프레임을 가져오지 못했습니다. 모니터링 중인지 확인해주세요.")?;
+    let (bounding_box, in_frame) = state
+        .pose_analyzer
+        .frame_diagnostics(&image)
+        .map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "bounding_box": bounding_box,
+        "in_frame": in_frame,
+        "frame_width": image.width(),
+        "frame_height": image.height(),
+    }))
+}
+
 #[tauri::command]
 async fn initialize_pose_model(
     state: State<'_, AppState>,
     handle: tauri::AppHandle,
-) -> Result<(), String> {
+) -> Result<serde_json::Value, String> {
     info!("Pose 모델 초기화 시작");
-    state
-        .pose_analyzer
-        .initialize_model(handle)
-        .await
-        .map_err(|e| {
-            error!("Pose 모델 초기화 실패: {}", e);
-            e.to_string()
-        })
+    let attempts = initialize_model_with_retry(&handle, &state).await?;
+    Ok(serde_json::json!({
+        "attempts": attempts,
+        "retries": attempts - 1,
+    }))
 }
 
 #[tauri::command]
 async fn start_monitoring(app: AppHandle, state: State<'_, AppState>) -> Result<(), String> {
-    *state.monitoring_active.lock().unwrap() = true;
-    if let Some(tray) = state.tray.lock().unwrap().as_ref() {
+    *state.monitoring_active.lock() = true;
+    *state.monitoring_started_at.lock() = Some(Instant::now());
+    if let Some(tray) = state.tray.lock().as_ref() {
         let monitoring_off_icon_path = app.path().resolve("icons/monitoring_off.png", BaseDirectory::Resource).unwrap();
         let bytes = fs::read(&monitoring_off_icon_path).unwrap();
         let monitoring_off_icon = Image::from_bytes(&bytes).unwrap();
@@ -159,8 +768,9 @@ async fn start_monitoring(app: AppHandle, state: State<'_, AppState>) -> Result<
 
 #[tauri::command]
 async fn stop_monitoring(app: AppHandle, state: State<'_, AppState>) -> Result<(), String> {
-    *state.monitoring_active.lock().unwrap() = false;
-    if let Some(tray) = state.tray.lock().unwrap().as_ref() {
+    *state.monitoring_active.lock() = false;
+    close_pool_cameras(&state);
+    if let Some(tray) = state.tray.lock().as_ref() {
         if let Ok(monitoring_off_icon_path) = app.path().resolve("icons/monitoring_off.png", BaseDirectory::Resource) {
             if let Ok(bytes) = fs::read(&monitoring_off_icon_path) {
                 if let Ok(monitoring_off_icon) = Image::from_bytes(&bytes) {
@@ -188,13 +798,389 @@ async fn calibrate_user_posture(
     image_data: String,
 ) -> Result<(), String> {
     info!("사용자 자세 캘리브레이션 시작");
+    // 미리보기와 같은 방식으로 비율을 계산한 뒤 적용하고, 결과를 calibration-complete 이벤트로 알림
+    let result = state
+        .pose_analyzer
+        .preview_baseline_posture(&image_data)
+        .and_then(|(ratios, confidence)| {
+            state.pose_analyzer.apply_baseline(&ratios, &handle)?;
+            clear_active_profile(&handle);
+            Ok((ratios, confidence))
+        });
+    match result {
+        Ok((ratios, confidence)) => {
+            let mut payload = calibration_ratios_json(&state, &ratios, confidence);
+            payload["success"] = serde_json::json!(true);
+            let _ = handle.emit("calibration-complete", &payload);
+            Ok(())
+        }
+        Err(e) => {
+            error!("자세 캘리브레이션 실패: {}", e);
+            let _ = handle.emit(
+                "calibration-complete",
+                &serde_json::json!({ "success": false, "reason": e.to_string() }),
+            );
+            Err(e.to_string())
+        }
+    }
+}
+
+// 캘리브레이션 비율과 신뢰도를 프론트엔드용 JSON으로 변환 (미리보기와 완료 이벤트가 공유)
+fn calibration_ratios_json(
+    state: &AppState,
+    ratios: &BaselineRatios,
+    confidence: f32,
+) -> serde_json::Value {
+    serde_json::json!({
+        "face_shoulder_ratio": ratios.face_shoulder_ratio,
+        "shoulder_alignment": ratios.shoulder_alignment,
+        "head_forward_ratio": ratios.head_forward_ratio,
+        "shoulder_ear_ratio": ratios.shoulder_ear_ratio,
+        "confidence": confidence,
+        "reliable": !ratios.is_empty() && confidence >= state.pose_analyzer.confidence_threshold(),
+    })
+}
+
+// 열려 있는 카메라의 현재 프레임에서 계산한 원시 비율과 기준값, 감지 항목별 판정 결과를 반환합니다.
+// 감지 강도를 직접 조정할 때 값을 실시간으로 그려 보기 위한 용도이며 분석 기록에는 영향을 주지 않습니다.
+#[tauri::command]
+async fn get_raw_detection_values(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let frame = state
+        .camera
+        .lock()
+        .as_mut()
+        .filter(|cam| cam.is_stream_open())
+        .and_then(|cam| cam.frame().ok())
+        .ok_or_else(|| "열려 있는 카메라에서 프레임을 가져오지 못했습니다. 모니터링 중인지 확인해주세요.".to_string())?;
+    let image = decode_frame(&frame).ok_or_else(|| "프레임 디코딩 실패".to_string())?;
     state
         .pose_analyzer
-        .set_baseline_posture(&image_data, &handle)
+        .raw_detection_values(&image)
+        .map_err(|e| e.to_string())
+}
+
+// 캘리브레이션을 한 적이 있는지, 마지막으로 언제 했는지와 저장된 안정도 점수를 반환합니다.
+#[tauri::command]
+fn get_calibration_status(state: State<'_, AppState>) -> serde_json::Value {
+    calibration_status_json(&state)
+}
+
+fn calibration_status_json(state: &AppState) -> serde_json::Value {
+    let analyzer = &state.pose_analyzer;
+    serde_json::json!({
+        "calibrated": analyzer.is_calibrated(),
+        "calibrated_at": analyzer.calibrated_at(),
+        "quality_score": analyzer.calibration_quality(),
+        "baseline": analyzer.baseline_to_json(),
+    })
+}
+
+// 기준 자세가 없으면 calibration-needed 이벤트와 시스템 알림으로 캘리브레이션을 안내합니다.
+fn prompt_calibration_if_needed(app_handle: &AppHandle, state: &AppState) {
+    if state.pose_analyzer.is_calibrated() {
+        return;
+    }
+    info!("저장된 기준 자세가 없어 캘리브레이션 안내");
+    let _ = app_handle.emit("calibration-needed", ());
+    let lang = state.current_language.lock().clone();
+    if let Err(e) = send_system_notification(
+        app_handle,
+        &state.translations.get(&lang, "calibration_needed_title"),
+        &state.translations.get(&lang, "calibration_needed_body"),
+    ) {
+        error!("캘리브레이션 안내 알림 전송 실패: {}", e);
+    }
+}
+
+// 기준 자세로 저장하기 전에 계산된 비율을 미리 확인합니다. (현재 기준값은 바뀌지 않음)
+#[tauri::command]
+async fn preview_calibration(
+    state: State<'_, AppState>,
+    image_data: String,
+) -> Result<serde_json::Value, String> {
+    let (ratios, confidence) = state
+        .pose_analyzer
+        .preview_baseline_posture(&image_data)
         .map_err(|e| {
-            error!("자세 캘리브레이션 실패: {}", e);
+            warn!("캘리브레이션 미리보기 실패: {}", e);
             e.to_string()
-        })
+        })?;
+    Ok(calibration_ratios_json(&state, &ratios, confidence))
+}
+
+// 백엔드 카메라로 여러 프레임을 촬영해 비율을 평균낸 뒤 기준 자세로 설정합니다.
+// 신뢰도가 낮거나 키포인트가 부족한 프레임은 제외하며, 프레임별 채택 여부를 반환합니다.
+#[tauri::command]
+async fn calibrate_with_samples(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    sample_count: Option<u32>,
+    interval_ms: Option<u64>,
+) -> Result<serde_json::Value, String> {
+    let sample_count = sample_count.unwrap_or(5).clamp(1, 30);
+    let interval = Duration::from_millis(interval_ms.unwrap_or(500));
+    info!("다중 샘플 캘리브레이션 시작: {}장, {:?} 간격", sample_count, interval);
+
+    let (accepted, samples) = collect_calibration_samples(&handle, &state, sample_count, interval).await?;
+
+    if accepted.is_empty() {
+        return Err("신뢰할 수 있는 캘리브레이션 프레임을 얻지 못했습니다. 정면을 보고 가만히 앉아 다시 시도해주세요.".to_string());
+    }
+    let baseline = BaselineRatios::average(&accepted);
+    let std_devs = BaselineRatios::std_dev(&accepted);
+    state
+        .pose_analyzer
+        .apply_baseline_with_std_devs(&baseline, &std_devs, &handle)
+        .map_err(|e| {
+            error!("다중 샘플 캘리브레이션 실패: {}", e);
+            e.to_string()
+        })?;
+    clear_active_profile(&handle);
+    info!("다중 샘플 캘리브레이션 완료: {}/{}장 채택", accepted.len(), sample_count);
+
+    Ok(serde_json::json!({
+        "baseline": baseline,
+        "std_devs": std_devs,
+        "accepted_count": accepted.len(),
+        "sample_count": sample_count,
+        "samples": samples,
+    }))
+}
+
+// 백엔드 카메라로 프레임을 촬영해 캘리브레이션 비율을 측정합니다. (다중 샘플/안내형 캘리브레이션 공용)
+// 채택된 비율 목록과 프레임별 결과를 반환하며, 프레임마다 calibration-sample 이벤트를 보냅니다.
+async fn collect_calibration_samples(
+    handle: &AppHandle,
+    state: &AppState,
+    sample_count: u32,
+    interval: Duration,
+) -> Result<(Vec<BaselineRatios>, Vec<serde_json::Value>), String> {
+    // 모니터링 중인 카메라가 없으면 캘리브레이션 동안만 임시로 엽니다.
+    let stream_open = state
+        .camera
+        .lock()
+        .as_ref()
+        .map(|cam| cam.is_stream_open())
+        .unwrap_or(false);
+    let mut temp_camera = if stream_open {
+        None
+    } else {
+        let index = *state.selected_camera_index.lock();
+        let cam = open_camera(state, index)?;
+        // 카메라 로딩을 위해 잠시 대기
+        sleep(Duration::from_secs(2)).await;
+        Some(cam)
+    };
+
+    let min_confidence = state.pose_analyzer.confidence_threshold();
+    let mut accepted = Vec::new();
+    let mut samples = Vec::new();
+    for i in 0..sample_count {
+        if i > 0 {
+            sleep(interval).await;
+        }
+        let frame = match temp_camera.as_mut() {
+            Some(cam) => cam.frame().ok(),
+            None => state
+                .camera
+                .lock()
+                .as_mut()
+                .and_then(|cam| cam.frame().ok()),
+        };
+        let measurement = match frame.as_ref().and_then(decode_frame) {
+            Some(image) => state
+                .pose_analyzer
+                .measure_baseline_ratios(&image)
+                .map_err(|e| e.to_string()),
+            None => Err("프레임 캡처 실패".to_string()),
+        };
+        let sample = match measurement {
+            Ok((ratios, confidence)) if confidence < min_confidence => serde_json::json!({
+                "index": i, "accepted": false, "confidence": confidence, "ratios": ratios,
+                "reason": "low_confidence",
+            }),
+            Ok((ratios, confidence)) if ratios.is_empty() => serde_json::json!({
+                "index": i, "accepted": false, "confidence": confidence, "ratios": ratios,
+                "reason": "missing_keypoints",
+            }),
+            Ok((ratios, confidence)) => {
+                let sample = serde_json::json!({
+                    "index": i, "accepted": true, "confidence": confidence, "ratios": ratios,
+                    "reason": null,
+                });
+                accepted.push(ratios);
+                sample
+            }
+            Err(e) => serde_json::json!({
+                "index": i, "accepted": false, "confidence": null, "ratios": null,
+                "reason": e,
+            }),
+        };
+        let _ = handle.emit("calibration-sample", &sample);
+        samples.push(sample);
+    }
+
+    if let Some(mut cam) = temp_camera.take() {
+        if let Err(e) = cam.stop_stream() {
+            error!("캘리브레이션용 카메라 스트림 닫기 실패: {}", e);
+        }
+    }
+
+    Ok((accepted, samples))
+}
+
+// 얼굴-어깨 비율은 항상 양수이고 자세 변화에 민감하므로, 이 값의 변동계수(표준편차/평균)로 촬영 중 움직임을 판단합니다.
+fn calibration_variation(baseline: &BaselineRatios, std_devs: &BaselineRatios) -> Option<f32> {
+    baseline
+        .face_shoulder_ratio
+        .zip(std_devs.face_shoulder_ratio)
+        .filter(|(mean, _)| *mean > 0.0)
+        .map(|(mean, std_dev)| std_dev / mean)
+}
+
+// 프레임 여러 장을 연속으로 찍어 비율의 흔들림으로 안정도 점수(0~100)를 계산합니다.
+// 채택 프레임이 충분하고 변동이 허용 범위 안이면 기준 자세로 저장하고, force이면 안정도와 관계없이 저장합니다.
+#[tauri::command]
+async fn capture_calibration_burst(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    count: Option<u32>,
+    interval_ms: Option<u64>,
+    force: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    let count = count.unwrap_or(10).clamp(3, 30);
+    let interval = Duration::from_millis(interval_ms.unwrap_or(300));
+    info!("캘리브레이션 연속 촬영 시작: {}장, {:?} 간격", count, interval);
+
+    let (accepted, samples) = collect_calibration_samples(&handle, &state, count, interval).await?;
+    if accepted.is_empty() {
+        return Err("신뢰할 수 있는 캘리브레이션 프레임을 얻지 못했습니다. 정면을 보고 가만히 앉아 다시 시도해주세요.".to_string());
+    }
+    let baseline = BaselineRatios::average(&accepted);
+    let std_devs = BaselineRatios::std_dev(&accepted);
+    let variance = |std_dev: Option<f32>| std_dev.map(|sd| sd * sd);
+    let variation = calibration_variation(&baseline, &std_devs);
+    let accepted_ratio = accepted.len() as f32 / count as f32;
+    // 변동계수가 허용치의 2배 이상이면 0점, 흔들림이 없으면 100점. 채택 비율만큼 점수를 깎음
+    let stability_score = variation
+        .map(|v| (1.0 - v / (2.0 * GUIDED_CALIBRATION_MAX_VARIATION)).clamp(0.0, 1.0) * accepted_ratio * 100.0)
+        .unwrap_or(0.0);
+    let stable = accepted_ratio >= GUIDED_CALIBRATION_MIN_ACCEPTED_RATIO
+        && variation.map_or(false, |v| v <= GUIDED_CALIBRATION_MAX_VARIATION);
+
+    let committed = stable || force.unwrap_or(false);
+    if committed {
+        state
+            .pose_analyzer
+            .apply_baseline_with_quality(&baseline, &std_devs, Some(stability_score), &handle)
+            .map_err(|e| e.to_string())?;
+        clear_active_profile(&handle);
+        info!("연속 촬영 캘리브레이션 저장 (안정도 {:.0}점, 강제 {})", stability_score, !stable);
+    } else {
+        warn!("연속 촬영 안정도가 낮아 기준 자세를 저장하지 않음 ({:.0}점)", stability_score);
+    }
+
+    Ok(serde_json::json!({
+        "stability_score": stability_score,
+        "stable": stable,
+        "committed": committed,
+        "variation": variation,
+        "variance": {
+            "face_shoulder_ratio": variance(std_devs.face_shoulder_ratio),
+            "shoulder_alignment": variance(std_devs.shoulder_alignment),
+            "head_forward_ratio": variance(std_devs.head_forward_ratio),
+            "shoulder_ear_ratio": variance(std_devs.shoulder_ear_ratio),
+        },
+        "baseline": baseline,
+        "std_devs": std_devs,
+        "accepted_count": accepted.len(),
+        "sample_count": count,
+        "samples": samples,
+    }))
+}
+
+// 처음 사용하는 사용자를 위한 안내형 캘리브레이션
+// 안내 → 카운트다운 → 다중 샘플 촬영 → 품질 검사 → 기준 자세 저장 → 완료 순서로 진행하며,
+// 단계마다 guided-calibration 이벤트({ stage, ... })를 보내 UI가 따라갈 수 있게 합니다.
+// 품질 검사에서 떨어지면 기준값을 바꾸지 않고 failed 단계와 함께 오류를 반환합니다.
+#[tauri::command]
+async fn start_guided_calibration(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    countdown_secs: Option<u64>,
+    sample_count: Option<u32>,
+) -> Result<serde_json::Value, String> {
+    let countdown_secs = countdown_secs.unwrap_or(3).min(10);
+    let sample_count = sample_count.unwrap_or(8).clamp(3, 30);
+    let emit_stage = |stage: &str, detail: serde_json::Value| {
+        let _ = handle.emit(
+            "guided-calibration",
+            &serde_json::json!({ "stage": stage, "detail": detail }),
+        );
+    };
+    let fail = |reason: &str, message: String| {
+        emit_stage("failed", serde_json::json!({ "reason": reason, "message": message }));
+        warn!("안내형 캘리브레이션 실패 ({}): {}", reason, message);
+        message
+    };
+
+    if !state.pose_analyzer.is_model_initialized() {
+        return Err(fail("model_not_initialized", "AI 모델이 아직 초기화되지 않았습니다.".to_string()));
+    }
+    info!("안내형 캘리브레이션 시작: 카운트다운 {}초, {}장", countdown_secs, sample_count);
+
+    emit_stage("prompt", serde_json::json!({ "countdown_secs": countdown_secs, "sample_count": sample_count }));
+    for remaining in (1..=countdown_secs).rev() {
+        emit_stage("countdown", serde_json::json!({ "remaining": remaining }));
+        sleep(Duration::from_secs(1)).await;
+    }
+
+    emit_stage("capturing", serde_json::json!({ "sample_count": sample_count }));
+    let (accepted, samples) =
+        collect_calibration_samples(&handle, &state, sample_count, Duration::from_millis(400))
+            .await
+            .map_err(|e| fail("capture_failed", e))?;
+
+    emit_stage("validating", serde_json::json!({ "accepted_count": accepted.len() }));
+    let accepted_ratio = accepted.len() as f32 / sample_count as f32;
+    if accepted_ratio < GUIDED_CALIBRATION_MIN_ACCEPTED_RATIO {
+        return Err(fail(
+            "too_few_samples",
+            format!(
+                "신뢰할 수 있는 프레임이 부족합니다 ({}/{}장). 밝은 곳에서 얼굴과 어깨가 모두 보이게 앉아 다시 시도해주세요.",
+                accepted.len(),
+                sample_count
+            ),
+        ));
+    }
+    let baseline = BaselineRatios::average(&accepted);
+    let std_devs = BaselineRatios::std_dev(&accepted);
+    let variation = calibration_variation(&baseline, &std_devs);
+    if variation.map_or(false, |v| v > GUIDED_CALIBRATION_MAX_VARIATION) {
+        return Err(fail(
+            "unstable",
+            "촬영 중 움직임이 너무 많았습니다. 바른 자세로 가만히 앉아 다시 시도해주세요.".to_string(),
+        ));
+    }
+
+    emit_stage("committing", serde_json::Value::Null);
+    state
+        .pose_analyzer
+        .apply_baseline_with_std_devs(&baseline, &std_devs, &handle)
+        .map_err(|e| fail("commit_failed", e.to_string()))?;
+    clear_active_profile(&handle);
+
+    let result = serde_json::json!({
+        "baseline": baseline,
+        "std_devs": std_devs,
+        "accepted_count": accepted.len(),
+        "sample_count": sample_count,
+        "variation": variation,
+        "samples": samples,
+    });
+    emit_stage("complete", result.clone());
+    info!("안내형 캘리브레이션 완료: {}/{}장 채택", accepted.len(), sample_count);
+    Ok(result)
 }
 
 #[tauri::command]
@@ -209,395 +1195,4712 @@ fn get_pose_recommendations() -> Result<Vec<String>, String> {
 }
 
 #[tauri::command]
-fn get_alert_messages(state: State<'_, AppState>) -> Result<Vec<String>, String> {
-    let mut alert_messages = state.alert_messages.lock().unwrap();
+fn get_alert_messages(state: State<'_, AppState>) -> Result<Vec<PendingAlert>, String> {
+    let mut alert_messages = state.alert_messages.lock();
     let messages = alert_messages.clone();
     alert_messages.clear();
     Ok(messages)
 }
 
+// 최근 알림 기록을 최신순으로 반환합니다. (get_alert_messages와 달리 읽어도 지워지지 않음)
 #[tauri::command]
-fn get_monitoring_status(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
-    let monitoring_active = *state.monitoring_active.lock().unwrap();
-    Ok(serde_json::json!({ "active": monitoring_active }))
+fn get_alert_history(state: State<'_, AppState>, limit: Option<usize>) -> Result<Vec<AlertHistoryEntry>, String> {
+    let history = state.alert_history.lock();
+    let limit = limit.unwrap_or(ALERT_HISTORY_CAPACITY);
+    Ok(history.iter().rev().take(limit).cloned().collect())
 }
 
+// 카메라 없이 나쁜 자세 감지를 한 번 흉내 내어 기록, analysis-update 이벤트, 알림까지 전체 흐름을 확인합니다.
+// kind: "turtle_neck" | "shoulder_misalignment" | "both". 기록은 is_test로 표시되며 알림 쿨다운은 확인하지만 갱신하지는 않습니다.
 #[tauri::command]
-fn test_model_status(state: State<'_, AppState>) -> Result<String, String> {
-    state
-        .pose_analyzer
-        .test_analysis()
-        .map_err(|e| e.to_string())
+async fn trigger_test_detection(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    kind: String,
+) -> Result<serde_json::Value, String> {
+    let (is_turtle, is_shoulder) = match kind.as_str() {
+        "turtle_neck" => (true, false),
+        "shoulder_misalignment" => (false, true),
+        "both" => (true, true),
+        _ => return Err(format!("알 수 없는 감지 종류입니다: {}", kind)),
+    };
+    // 알림이 나가도록 '나쁨' 단계에 해당하는 점수를 사용
+    let score = (state.alert_tiers.lock().bad_below - 1).max(0);
+    let result = serde_json::json!({
+        "schema_version": ANALYSIS_SCHEMA_VERSION,
+        "status": "yolo_analysis_success",
+        "test": true,
+        "turtle_neck": is_turtle,
+        "shoulder_misalignment": is_shoulder,
+        "shoulder_misalignment_side": null,
+        "hand_on_face": false,
+        "head_dropped": false,
+        "shoulder_tension": false,
+        "posture_score": score,
+        "recommendations": [],
+        "confidence": 1.0,
+        "detection_confidence": 1.0,
+        "bounding_box": null,
+        "in_frame": null,
+        "monitor_height": null,
+        "lean": null,
+        "inference_ms": 0.0,
+        "total_ms": 0.0,
+    });
+    info!("테스트 감지 실행: {}", kind);
+    let alert_queued = handle_analysis_result(&app, &state, &result, None).await;
+    Ok(serde_json::json!({
+        "result": result,
+        "alert_queued": alert_queued,
+        "logged": *state.logging_enabled.lock() && !*state.demo_mode.lock(),
+    }))
 }
 
+// 가장 최근 감지된 17개 키포인트를 COCO 형식으로 반환합니다.
+// record가 true이면 앱 데이터 폴더의 keypoint_recordings/<날짜>.jsonl 파일에 한 줄씩 덧붙입니다.
 #[tauri::command]
-async fn save_calibrated_image(
+async fn export_keypoints_coco(
+    state: State<'_, AppState>,
     handle: tauri::AppHandle,
-    image_data: String,
-) -> Result<String, String> {
-    let base64_str = image_data
-        .split(',')
-        .nth(1)
-        .ok_or_else(|| "잘못된 Base64 데이터 형식입니다.".to_string())?;
-    let decoded_image = STANDARD
-        .decode(base64_str)
-        .map_err(|e| format!("Base64 디코딩 실패: {}", e))?;
-    let app_data_path = handle
+    record: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    let annotation = state
+        .pose_analyzer
+        .latest_pose_coco()
+        .ok_or_else(|| "아직 감지된 키포인트가 없습니다.".to_string())?;
+    if !record.unwrap_or(false) {
+        return Ok(serde_json::json!({ "annotation": annotation, "recorded_to": null }));
+    }
+
+    let record_dir = handle
         .path()
         .app_data_dir()
-        .map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?;
-    let image_dir = app_data_path.join("calibration_images");
-    fs::create_dir_all(&image_dir).map_err(|e| format!("이미지 저장 디렉토리 생성 실패: {}", e))?;
-    let file_path = image_dir.join("calibrated_pose.jpeg");
-    let mut file = fs::File::create(&file_path).map_err(|e| format!("파일 생성 실패: {:?}", e))?;
-    file.write_all(&decoded_image)
-        .map_err(|e| format!("파일 쓰기 실패: {:?}", e))?;
-    info!("캘리브레이션 이미지 덮어쓰기 완료: {:?}", file_path);
-    Ok(file_path.to_string_lossy().into_owned())
+        .map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?
+        .join("keypoint_recordings");
+    fs::create_dir_all(&record_dir).map_err(|e| format!("기록 디렉토리 생성 실패: {}", e))?;
+    let file_path = record_dir.join(format!("{}.jsonl", chrono::Local::now().format("%Y-%m-%d")));
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&file_path)
+        .map_err(|e| format!("키포인트 기록 파일 열기 실패: {}", e))?;
+    writeln!(file, "{}", annotation).map_err(|e| format!("키포인트 기록 실패: {}", e))?;
+    Ok(serde_json::json!({
+        "annotation": annotation,
+        "recorded_to": file_path.to_string_lossy(),
+    }))
+}
+
+// 최근 프레임 동안의 좌우 기울임 추세를 반환합니다. 판단할 프레임이 부족하면 null입니다.
+#[tauri::command]
+fn get_lean_direction(state: State<'_, AppState>) -> Result<Option<serde_json::Value>, String> {
+    Ok(state
+        .pose_analyzer
+        .lean_summary()
+        .map(|lean| serde_json::json!(lean)))
+}
+
+// 최근에 신뢰할 수 있는 사람 감지가 있었는지 반환합니다. (자리 비움 유예 시간 기준)
+#[tauri::command]
+fn is_user_present(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let grace = effective_away_grace(&state);
+    let presence = state.presence.lock();
+    Ok(serde_json::json!({
+        "present": presence.last_seen.map_or(false, |t| t.elapsed() <= grace),
+        "seconds_since_seen": presence.last_seen.map(|t| t.elapsed().as_secs()),
+    }))
+}
+
+// 백엔드 번역 파일의 한 언어 전체를 반환합니다. (영어 번역으로 빠진 키를 채움)
+#[tauri::command]
+fn get_translations(state: State<'_, AppState>, lang: String) -> Result<HashMap<String, String>, String> {
+    state
+        .translations
+        .all(&lang)
+        .ok_or_else(|| format!("지원하지 않는 언어입니다: {}", lang))
+}
+
+// 실제 알림과 같은 경로로 테스트 알림을 보내 OS가 받아들였는지 반환합니다.
+#[tauri::command]
+fn send_test_notification(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    let lang = state.current_language.lock().clone();
+    let title = state.translations.get(&lang, "test_notification_title");
+    let body = state.translations.get(&lang, "test_notification_body");
+    info!("테스트 알림 전송: {}", body);
+    let result = match send_system_notification(&app, &title, &body) {
+        Ok(()) => serde_json::json!({ "success": true, "error": null }),
+        Err(e) => {
+            error!("테스트 알림 전송 실패: {}", e);
+            serde_json::json!({ "success": false, "error": e.to_string() })
+        }
+    };
+    Ok(result)
+}
+
+#[tauri::command]
+fn get_monitoring_status(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let monitoring_active = *state.monitoring_active.lock();
+    let no_camera = *state.no_camera.lock();
+    Ok(serde_json::json!({
+        "active": monitoring_active,
+        "no_camera": no_camera,
+        "auto_paused": *state.auto_paused.lock(),
+        "adaptive_interval": state.adaptive_interval.lock().is_some(),
+        "effective_interval_secs": effective_monitoring_interval(&state).as_secs(),
+        "startup_grace_remaining_secs": startup_grace_remaining(&state).as_secs(),
+    }))
+}
+
+// 앱 프로세스의 CPU 사용률(%)과 메모리 사용량(RSS)을 반환합니다.
+// cpu_percent는 코어 하나 기준이라 100을 넘을 수 있고, cpu_percent_of_total은 전체 코어 대비 비율입니다.
+#[tauri::command]
+async fn get_resource_usage(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let pid = sysinfo::get_current_pid().map_err(|e| format!("프로세스 ID를 가져올 수 없습니다: {}", e))?;
+    // 처음 측정할 때는 비교 기준이 없으므로 잠시 후 한 번 더 측정
+    let first_sample = state.resource_monitor.lock().is_none();
+    if first_sample {
+        let mut system = sysinfo::System::new();
+        system.refresh_process(pid);
+        *state.resource_monitor.lock() = Some(system);
+        sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL).await;
+    }
+
+    let mut monitor = state.resource_monitor.lock();
+    let system = monitor.get_or_insert_with(sysinfo::System::new);
+    if !system.refresh_process(pid) {
+        return Err("프로세스 정보를 가져올 수 없습니다.".to_string());
+    }
+    let process = system
+        .process(pid)
+        .ok_or_else(|| "프로세스 정보를 가져올 수 없습니다.".to_string())?;
+    let cpu_percent = process.cpu_usage();
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    Ok(serde_json::json!({
+        "cpu_percent": cpu_percent,
+        "cpu_percent_of_total": cpu_percent / cores as f32,
+        "cpu_cores": cores,
+        "memory_bytes": process.memory(),
+        "virtual_memory_bytes": process.virtual_memory(),
+    }))
+}
+
+// analysis-update 이벤트 구조 버전 (프론트엔드 호환성 확인용)
+#[tauri::command]
+fn get_analysis_schema_version() -> u32 {
+    ANALYSIS_SCHEMA_VERSION
+}
+
+#[tauri::command]
+fn test_model_status(state: State<'_, AppState>) -> Result<String, String> {
+    state
+        .pose_analyzer
+        .test_analysis()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn get_model_info(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    Ok(model_info_json(&state))
+}
+
+fn model_info_json(state: &AppState) -> serde_json::Value {
+    let analyzer = &state.pose_analyzer;
+    serde_json::json!({
+        "initialized": analyzer.is_model_initialized(),
+        "model_path": analyzer.model_path().map(|p| p.to_string_lossy().into_owned()),
+        "optimization_level": analyzer.optimization_level(),
+        "available_optimization_levels": [0, 1, 2, 3],
+        "input_size": analyzer.model_input_size(),
+        "fixed_input_size": analyzer.fixed_input_size(),
+    })
+}
+
+// 현재 카메라 프레임(없으면 회색 이미지)으로 추론을 반복해 지연 시간과 FPS를 측정합니다.
+#[tauri::command]
+async fn benchmark_inference(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    iterations: Option<u32>,
+) -> Result<serde_json::Value, String> {
+    if !state.pose_analyzer.is_model_initialized() {
+        return Err("AI 모델이 아직 초기화되지 않았습니다.".to_string());
+    }
+    let iterations = iterations.unwrap_or(20).clamp(1, 200);
+    let (image, frame_source) = match capture_frame(&app, &state).await.as_ref().and_then(decode_frame) {
+        Some(image) => (image, "camera"),
+        None => (ImageBuffer::from_pixel(640, 480, Rgb([128, 128, 128])), "synthetic"),
+    };
+    info!("추론 벤치마크 시작: {}회 ({})", iterations, frame_source);
+
+    let analyzer = state.pose_analyzer.clone();
+    let latencies = tauri::async_runtime::spawn_blocking(move || {
+        analyzer
+            .benchmark_inference(&image, iterations)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    let min_ms = latencies.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max_ms = latencies.iter().cloned().fold(0.0, f64::max);
+    let mean_ms = latencies.iter().sum::<f64>() / latencies.len() as f64;
+    info!("추론 벤치마크 완료: 평균 {:.1}ms", mean_ms);
+    Ok(serde_json::json!({
+        "iterations": iterations,
+        "frame_source": frame_source,
+        "optimization_level": state.pose_analyzer.optimization_level(),
+        "input_size": state.pose_analyzer.inference_input_size(),
+        "min_ms": min_ms,
+        "max_ms": max_ms,
+        "mean_ms": mean_ms,
+        "fps": if mean_ms > 0.0 { 1000.0 / mean_ms } else { 0.0 },
+    }))
+}
+
+// 이후 posture_log 기록에 붙일 활동 라벨을 설정하고 저장합니다. 빈 문자열이나 None이면 라벨을 지웁니다.
+#[tauri::command]
+async fn set_current_activity(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    label: Option<String>,
+) -> Result<Option<String>, String> {
+    let label = label
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty());
+    if let Some(l) = &label {
+        if l.chars().count() > MAX_ACTIVITY_LABEL_CHARS {
+            return Err(format!("활동 라벨은 {}자 이하여야 합니다.", MAX_ACTIVITY_LABEL_CHARS));
+        }
+    }
+    info!("현재 활동 라벨 변경: {:?}", label);
+    *state.current_activity.lock() = label.clone();
+    save_setting(&handle, "currentActivity", &label);
+    Ok(label)
+}
+
+#[tauri::command]
+fn get_current_activity(state: State<'_, AppState>) -> Result<Option<String>, String> {
+    Ok(state.current_activity.lock().clone())
+}
+
+// 기준 자세가 오래되어 보일 때 재캘리브레이션을 권장할지 설정하고 저장합니다.
+#[tauri::command]
+async fn set_recalibration_nudge(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    *state.recalibration_nudge.lock() = enabled;
+    save_setting(&handle, "recalibrationNudge", enabled);
+    Ok(())
+}
+
+// 배터리 절약 모드에서 정확도를 조금 희생하고 더 작은 입력으로 추론할지 설정하고 저장합니다.
+#[tauri::command]
+async fn set_low_power_inference(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    *state.low_power_inference.lock() = enabled;
+    save_setting(&handle, "lowPowerInference", enabled);
+    apply_low_power_inference(&state);
+    Ok(())
+}
+
+// 직전 프레임과 거의 같은 프레임은 추론하지 않고 직전 결과를 재사용할지 설정하고 저장합니다.
+// threshold는 축소한 회색조 프레임의 평균 픽셀 차이(0~255) 기준이며, 생략하면 기존 값이나 기본값을 씁니다.
+#[tauri::command]
+async fn set_motion_gating(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    enabled: bool,
+    threshold: Option<f32>,
+) -> Result<(), String> {
+    let threshold = match threshold {
+        Some(t) if !(0.0..=255.0).contains(&t) => {
+            return Err(format!("움직임 기준은 0에서 255 사이여야 합니다: {}", t));
+        }
+        Some(t) => t,
+        None => load_setting(&handle, "motionGatingThreshold").unwrap_or(DEFAULT_MOTION_GATING_THRESHOLD),
+    };
+    *state.motion_gating_threshold.lock() = enabled.then_some(threshold);
+    *state.motion_gate.lock() = MotionGate::default();
+    save_setting(&handle, "motionGating", enabled);
+    save_setting(&handle, "motionGatingThreshold", threshold);
+    info!("움직임 감지 생략 설정: {} (기준 {})", enabled, threshold);
+    Ok(())
+}
+
+// 같은 프레임으로 기본 추론과 저전력 추론의 소요 시간과 키포인트 신뢰도를 비교합니다.
+#[tauri::command]
+async fn measure_low_power_tradeoff(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    if !state.pose_analyzer.is_model_initialized() {
+        return Err("AI 모델이 아직 초기화되지 않았습니다.".to_string());
+    }
+    let image = capture_frame(&app, &state)
+        .await
+        .as_ref()
+        .and_then(decode_frame)
+        .ok_or_else(|| "카메라 프레임을 가져오지 못했습니다.".to_string())?;
+    let analyzer = state.pose_analyzer.clone();
+    let results = tauri::async_runtime::spawn_blocking(move || analyzer.measure_low_power_tradeoff(&image))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(serde_json::json!(results
+        .into_iter()
+        .map(|(low_power, input_size, elapsed_ms, confidence, detected)| serde_json::json!({
+            "low_power": low_power,
+            "input_size": input_size,
+            "elapsed_ms": elapsed_ms,
+            "confidence": confidence,
+            "detected": detected,
+        }))
+        .collect::<Vec<_>>()))
+}
+
+// 같은 프레임으로 보간 필터별 소요 시간과 키포인트 신뢰도를 비교합니다.
+#[tauri::command]
+async fn measure_resize_filters(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    if !state.pose_analyzer.is_model_initialized() {
+        return Err("AI 모델이 아직 초기화되지 않았습니다.".to_string());
+    }
+    let image = capture_frame(&app, &state)
+        .await
+        .as_ref()
+        .and_then(decode_frame)
+        .ok_or_else(|| "카메라 프레임을 가져오지 못했습니다.".to_string())?;
+    let analyzer = state.pose_analyzer.clone();
+    let results = tauri::async_runtime::spawn_blocking(move || analyzer.measure_resize_filters(&image))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(serde_json::json!(results
+        .into_iter()
+        .map(|(filter, elapsed_ms, confidence, detected)| serde_json::json!({
+            "filter": filter.name(),
+            "elapsed_ms": elapsed_ms,
+            "confidence": confidence,
+            "detected": detected,
+        }))
+        .collect::<Vec<_>>()))
+}
+
+#[tauri::command]
+async fn set_optimization_level(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    level: u8,
+) -> Result<serde_json::Value, String> {
+    let analyzer = state.pose_analyzer.clone();
+    let rebuilt = tauri::async_runtime::spawn_blocking(move || analyzer.set_optimization_level(level))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| {
+            error!("최적화 수준 변경 실패: {}", e);
+            e.to_string()
+        })?;
+    save_setting(&handle, "onnxOptimizationLevel", level);
+    Ok(serde_json::json!({ "level": level, "session_rebuilt": rebuilt }))
+}
+
+// 캘리브레이션 시점의 웹캠 이미지를 앱 데이터 디렉토리의
+// `calibration_images/calibrated_pose.jpeg`에 저장하고 경로를 반환합니다.
+// 개인정보 보호 모드에서는 이미지를 저장하지 않고 기존 이미지도 삭제하며 빈 문자열을 반환합니다.
+// (기준 자세 수치는 이미지와 별개로 `baseline.json`에 저장됩니다.)
+#[tauri::command]
+async fn save_calibrated_image(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    image_data: String,
+) -> Result<String, String> {
+    if *state.privacy_mode.lock() {
+        info!("개인정보 보호 모드: 캘리브레이션 이미지를 저장하지 않습니다.");
+        remove_calibration_image(&handle)?;
+        return Ok(String::new());
+    }
+    let base64_str = image_data
+        .split(',')
+        .nth(1)
+        .ok_or_else(|| "잘못된 Base64 데이터 형식입니다.".to_string())?;
+    let decoded_image = STANDARD
+        .decode(base64_str)
+        .map_err(|e| format!("Base64 디코딩 실패: {}", e))?;
+    // 프론트엔드가 보낸 이미지를 그대로 쓰지 않고 설정된 품질로 다시 인코딩해 디스크 사용량을 줄임
+    let image = image::load_from_memory(&decoded_image)
+        .map_err(|e| format!("이미지 디코딩 실패: {}", e))?
+        .to_rgb8();
+    let quality = *state.image_quality.lock();
+    let jpeg = encode_jpeg(&image, quality).map_err(|e| format!("이미지 인코딩 실패: {}", e))?;
+    let app_data_path = handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?;
+    let image_dir = app_data_path.join("calibration_images");
+    fs::create_dir_all(&image_dir).map_err(|e| format!("이미지 저장 디렉토리 생성 실패: {}", e))?;
+    let file_path = image_dir.join(CALIBRATION_IMAGE_FILE);
+    let mut file = fs::File::create(&file_path).map_err(|e| format!("파일 생성 실패: {:?}", e))?;
+    file.write_all(&jpeg)
+        .map_err(|e| format!("파일 쓰기 실패: {:?}", e))?;
+    info!("캘리브레이션 이미지 덮어쓰기 완료: {:?}", file_path);
+    Ok(file_path.to_string_lossy().into_owned())
+}
+
+// 저장된 캘리브레이션 이미지를 삭제합니다. 삭제한 파일이 있었으면 `true`를 반환합니다.
+#[tauri::command]
+async fn delete_calibration_image(handle: tauri::AppHandle) -> Result<bool, String> {
+    remove_calibration_image(&handle)
+}
+
+#[tauri::command]
+async fn set_privacy_mode(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    *state.privacy_mode.lock() = enabled;
+    save_setting(&handle, "privacyMode", enabled);
+    info!("개인정보 보호 모드 설정: {}", enabled);
+    if enabled {
+        state.recent_frames.lock().clear();
+        remove_calibration_image(&handle)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn get_privacy_mode(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(*state.privacy_mode.lock())
+}
+
+#[tauri::command]
+async fn get_available_cameras(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<CameraDetail>, String> {
+    let cameras = query_available_cameras()?;
+    set_camera_availability(&app, &state, !cameras.is_empty());
+    Ok(cameras)
+}
+
+// 백엔드 순서대로 조회해 카메라가 발견되는 첫 결과를 사용
+fn query_available_cameras() -> Result<Vec<CameraDetail>, String> {
+    let mut query_result = Err(nokhwa::NokhwaError::GeneralError("카메라 백엔드가 없습니다".to_string()));
+    for backend in camera_backend_chain() {
+        query_result = nokhwa::query(backend);
+        match &query_result {
+            Ok(cameras) if !cameras.is_empty() => {
+                info!("{:?} 백엔드로 카메라 목록 조회 성공", backend);
+                break;
+            }
+            Ok(_) => {}
+            Err(e) => warn!("{:?} 백엔드로 카메라 목록 조회 실패: {}", backend, e),
+        }
+    }
+    match query_result {
+        Ok(cameras) => {
+            info!("사용 가능한 카메라 {}개 발견", cameras.len());
+            let camera_details = cameras
+                .into_iter()
+                .map(|cam: CameraInfo| CameraDetail {
+                    index: cam.index().as_index().unwrap_or(0) as u32,
+                    name: cam.human_name(),
+                })
+                .collect();
+            Ok(camera_details)
+        }
+        Err(e) => {
+            error!("카메라 목록 조회 실패: {}", e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+async fn set_selected_camera(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    index: u32,
+) -> Result<(), String> {
+    info!("선택된 카메라 변경: index {}", index);
+    // 다른 장치로 바꾸는 것이므로 이전 장치의 재시도 대기 상태는 초기화
+    state.camera_backoff.lock().reset();
+    // 카메라 풀이 새 장치를 열어 두고 있을 수 있으므로 먼저 닫음 (다음 비교 때 다시 열림)
+    close_pool_cameras(&state);
+    if *state.selected_camera_index.lock() != index {
+        state.pose_analyzer.reset_for_source_change();
+    }
+    let mut current_cam_lock = state.camera.lock();
+
+    if *state.monitoring_active.lock() && current_cam_lock.is_some() {
+        info!("모니터링 중 카메라 변경 시도...");
+        if let Some(mut cam) = current_cam_lock.take() {
+            if cam.is_stream_open() {
+                let _ = cam.stop_stream();
+            }
+        }
+
+        if let Some(new_cam) = open_camera_with_backoff(&app, &state, index) {
+            info!("새 카메라 스트림 시작됨: {}", new_cam.info().human_name());
+            *current_cam_lock = Some(new_cam);
+        }
+    }
+
+    *state.selected_camera_index.lock() = index;
+    Ok(())
+}
+
+// 사람이 가장 잘 보이는 카메라를 자동으로 고를 후보 카메라 목록을 설정하고 저장합니다. 2개 미만이면 끕니다.
+// keep_open이면 후보 카메라를 모두 열어 두고 매 주기 비교하며, 아니면 일정 주기마다 하나씩 잠깐 열어 비교합니다.
+#[tauri::command]
+async fn set_camera_pool(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    indices: Vec<u32>,
+    keep_open: Option<bool>,
+) -> Result<(), String> {
+    let mut indices = indices;
+    indices.sort_unstable();
+    indices.dedup();
+    let keep_open = keep_open.unwrap_or(false);
+    apply_camera_pool(&state, indices.clone(), keep_open);
+    save_setting(&handle, "cameraPool", &indices);
+    save_setting(&handle, "cameraPoolKeepOpen", keep_open);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_camera_pool(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let pool = state.camera_pool.lock();
+    Ok(serde_json::json!({
+        "indices": pool.indices,
+        "keep_open": pool.keep_open,
+        "open_indices": pool.open_cameras.keys().collect::<Vec<_>>(),
+    }))
+}
+
+fn apply_camera_pool(state: &AppState, indices: Vec<u32>, keep_open: bool) {
+    close_pool_cameras(state);
+    let mut pool = state.camera_pool.lock();
+    info!("카메라 풀 설정: {:?} (계속 열어 두기 {})", indices, keep_open);
+    pool.indices = indices;
+    pool.keep_open = keep_open;
+    pool.last_probe = None;
+}
+
+#[tauri::command]
+async fn set_detection_settings(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    frequency: u8,
+    turtle_sensitivity: u8,
+    shoulder_sensitivity: u8,
+) -> Result<(), String> {
+    ensure_no_sensitivity_check(&state)?;
+    state.pose_analyzer.set_notification_frequency(frequency);
+    state
+        .pose_analyzer
+        .set_turtle_neck_sensitivity(turtle_sensitivity);
+    state
+        .pose_analyzer
+        .set_shoulder_sensitivity(shoulder_sensitivity);
+
+    // 단계가 그대로면(시작 시/설정 화면 진입 시 재전송) 가져온 감지 강도 원시값을 유지하고,
+    // 사용자가 단계를 바꿨을 때만 원시값을 버립니다.
+    let levels = (turtle_sensitivity, shoulder_sensitivity);
+    let previous_levels = load_setting::<(u8, u8)>(&handle, "sensitivityLevels");
+    save_setting(&handle, "sensitivityLevels", levels);
+    let levels_changed = previous_levels.is_some_and(|previous| previous != levels);
+    if levels_changed {
+        save_setting(&handle, "detectionThresholds", None::<DetectionThresholds>);
+    } else {
+        apply_saved_detection_thresholds(&handle, &state.pose_analyzer);
+    }
+    // 알림 빈도 단계도 같은 방식으로, 직접 지정한 감지 횟수는 단계를 바꿨을 때만 버림
+    let previous_frequency = load_setting::<u8>(&handle, "notificationFrequency");
+    save_setting(&handle, "notificationFrequency", frequency);
+    let frequency_changed = previous_frequency.is_some_and(|previous| previous != frequency);
+    if frequency_changed {
+        save_setting(&handle, "detectionCounts", None::<DetectionCounts>);
+    } else {
+        apply_saved_detection_counts(&handle, &state.pose_analyzer);
+    }
+    // 적용 중인 프로필의 감지 기준도 단계를 바꾸지 않았으면 유지하고, 바꿨으면 프로필 적용 상태를 해제
+    if levels_changed || frequency_changed {
+        clear_active_profile(&handle);
+    } else {
+        reapply_active_profile(&handle, &state);
+    }
+    Ok(())
+}
+
+// set_notification_threshold_count/set_detection_hysteresis로 직접 지정한 감지/해제 횟수 (빈도 단계 대신 저장)
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+struct DetectionCounts {
+    on_count: usize,
+    off_count: usize,
+}
+
+fn save_detection_counts(app_handle: &AppHandle, analyzer: &PoseAnalyzer) {
+    let counts = DetectionCounts {
+        on_count: analyzer.required_detection_count(),
+        off_count: analyzer.release_threshold_count(),
+    };
+    save_setting(app_handle, "detectionCounts", Some(counts));
+}
+
+fn apply_saved_detection_counts(app_handle: &AppHandle, analyzer: &PoseAnalyzer) {
+    let Some(saved) = load_setting::<Option<DetectionCounts>>(app_handle, "detectionCounts").flatten() else {
+        return;
+    };
+    if let Err(e) = analyzer.set_detection_hysteresis(saved.on_count, saved.off_count) {
+        warn!("저장된 감지 횟수 기준을 무시합니다: {}", e);
+    }
+}
+
+// 캘리브레이션 문자열에서 가져온 거북목/어깨 감지 강도 원시값 (단계 프리셋 대신 저장)
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+struct DetectionThresholds {
+    turtle_neck: (f32, f32),
+    shoulder_alignment: (f32, f32),
+}
+
+fn apply_saved_detection_thresholds(app_handle: &AppHandle, analyzer: &PoseAnalyzer) {
+    let Some(saved) = load_setting::<Option<DetectionThresholds>>(app_handle, "detectionThresholds").flatten() else {
+        return;
+    };
+    match PoseAnalyzer::validate_detection_thresholds(saved.turtle_neck, saved.shoulder_alignment) {
+        Ok(()) => analyzer.set_detection_thresholds(saved.turtle_neck, saved.shoulder_alignment),
+        Err(e) => warn!("저장된 감지 강도 원시값을 무시합니다: {}", e),
+    }
+}
+
+// set_detection_settings의 단계(1~3) 대신 알림에 필요한 감지 횟수를 직접 지정합니다. (저장되어 재시작 후에도 유지)
+#[tauri::command]
+async fn set_notification_threshold_count(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    count: usize,
+) -> Result<serde_json::Value, String> {
+    ensure_no_sensitivity_check(&state)?;
+    state
+        .pose_analyzer
+        .set_required_detection_count(count)
+        .map_err(|e| e.to_string())?;
+    save_detection_counts(&handle, &state.pose_analyzer);
+    Ok(serde_json::json!({
+        "required_detections": count,
+        "window_size": state.pose_analyzer.temporal_window_size(),
+    }))
+}
+
+// 감지를 켜는 횟수(on_count)와 끄는 횟수(off_count)를 따로 지정해 경계값 근처의 알림 깜빡임을 줄입니다.
+#[tauri::command]
+async fn set_detection_hysteresis(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    on_count: usize,
+    off_count: usize,
+) -> Result<serde_json::Value, String> {
+    ensure_no_sensitivity_check(&state)?;
+    state
+        .pose_analyzer
+        .set_detection_hysteresis(on_count, off_count)
+        .map_err(|e| e.to_string())?;
+    save_detection_counts(&handle, &state.pose_analyzer);
+    Ok(serde_json::json!({
+        "on_count": on_count,
+        "off_count": off_count,
+        "window_size": state.pose_analyzer.temporal_window_size(),
+    }))
+}
+
+// 키포인트 신뢰도 기준을 설정하고 저장합니다. 허용 범위를 벗어나면 범위 안으로 맞춘 값을 적용해 반환합니다.
+#[tauri::command]
+async fn set_confidence_threshold(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    threshold: f32,
+) -> Result<f32, String> {
+    if !threshold.is_finite() {
+        return Err("신뢰도 기준은 숫자여야 합니다.".to_string());
+    }
+    let applied = state.pose_analyzer.set_confidence_threshold(threshold);
+    save_setting(&handle, "confidenceThreshold", applied);
+    Ok(applied)
+}
+
+// 감지 이력에서 오래된 프레임의 비중을 줄이는 감쇠 계수를 설정합니다 (1.0이면 기존처럼 동일 가중치).
+#[tauri::command]
+async fn set_temporal_decay(state: State<'_, AppState>, decay: f32) -> Result<(), String> {
+    state
+        .pose_analyzer
+        .set_temporal_decay(decay)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn set_keypoint_smoothing(state: State<'_, AppState>, window: usize) -> Result<(), String> {
+    state.pose_analyzer.set_keypoint_smoothing_window(window);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_subject_selection(state: State<'_, AppState>, mode: String) -> Result<(), String> {
+    let selection = SubjectSelection::from_name(&mode)
+        .ok_or_else(|| format!("지원하지 않는 대상 선택 방식입니다: {}", mode))?;
+    state.pose_analyzer.set_subject_selection(selection);
+    Ok(())
+}
+
+// 추론 전 밝기 보정 방식(off, gamma, equalize)을 설정하고 저장합니다. 어두운 환경의 감지율을 높이지만 CPU를 더 씁니다.
+// 분석 결과의 image_normalization과 confidence로 보정 효과를 비교할 수 있습니다.
+#[tauri::command]
+async fn set_image_normalization(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    mode: String,
+) -> Result<(), String> {
+    let normalization = ImageNormalization::from_name(&mode)
+        .ok_or_else(|| format!("지원하지 않는 밝기 보정 방식입니다: {}", mode))?;
+    state.pose_analyzer.set_image_normalization(normalization);
+    save_setting(&handle, "imageNormalization", mode);
+    Ok(())
+}
+
+// 모델 입력 크기로 줄일 때 쓰는 보간 필터(nearest, triangle, catmull_rom, lanczos3)를 설정하고 저장합니다.
+// nearest는 가장 빨라 배터리 절약에 유리하고, lanczos3는 느리지만 작은 특징을 더 선명하게 남깁니다.
+#[tauri::command]
+async fn set_resize_filter(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    filter: String,
+) -> Result<(), String> {
+    let resize_filter = ResizeFilter::from_name(&filter)
+        .ok_or_else(|| format!("지원하지 않는 보간 필터입니다: {}", filter))?;
+    state.pose_analyzer.set_resize_filter(resize_filter);
+    save_setting(&handle, "resizeFilter", filter);
+    Ok(())
+}
+
+// 분석 대상으로 볼 최소 바운딩 박스 크기(프레임 면적 대비 비율, 0~0.5)를 설정하고 저장합니다. 0이면 제한하지 않습니다.
+#[tauri::command]
+async fn set_min_detection_region(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    fraction: f32,
+) -> Result<(), String> {
+    state
+        .pose_analyzer
+        .set_min_detection_area(fraction)
+        .map_err(|e| e.to_string())?;
+    save_setting(&handle, "minDetectionRegion", fraction);
+    Ok(())
+}
+
+// 자세 점수에서 항목별로 깎는 점수를 설정하고 저장합니다. 지정하지 않은 항목은 현재 값을 유지합니다.
+#[tauri::command]
+async fn set_score_weights(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    turtle_neck: Option<u8>,
+    shoulder_misalignment: Option<u8>,
+    hand_on_face: Option<u8>,
+    head_dropped: Option<u8>,
+    shoulder_tension: Option<u8>,
+) -> Result<ScoreWeights, String> {
+    let current = state.pose_analyzer.score_weights();
+    state.pose_analyzer.set_score_weights(ScoreWeights {
+        turtle_neck: turtle_neck.unwrap_or(current.turtle_neck),
+        shoulder_misalignment: shoulder_misalignment.unwrap_or(current.shoulder_misalignment),
+        hand_on_face: hand_on_face.unwrap_or(current.hand_on_face),
+        head_dropped: head_dropped.unwrap_or(current.head_dropped),
+        shoulder_tension: shoulder_tension.unwrap_or(current.shoulder_tension),
+    });
+    let weights = state.pose_analyzer.score_weights();
+    save_setting(&handle, "scoreWeights", weights);
+    Ok(weights)
+}
+
+// 감지 항목(turtle_neck, shoulder_misalignment, hand_on_face, head_dropped, shoulder_tension)을 하나씩 켜거나 끄고 저장합니다.
+// 꺼진 항목은 분석 결과에 null로 표시되고 알림도 보내지 않습니다.
+#[tauri::command]
+async fn set_detector_enabled(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    name: String,
+    enabled: bool,
+) -> Result<DetectorToggles, String> {
+    state
+        .pose_analyzer
+        .set_detector_enabled(&name, enabled)
+        .map_err(|e| e.to_string())?;
+    let toggles = state.pose_analyzer.detector_toggles();
+    save_setting(&handle, "detectorToggles", toggles);
+    Ok(toggles)
+}
+
+// 로컬(127.0.0.1) Prometheus 메트릭 서버를 켜거나 끄고 포트를 저장합니다.
+#[tauri::command]
+async fn set_metrics_server(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    enabled: bool,
+    port: Option<u16>,
+) -> Result<(), String> {
+    if port == Some(0) {
+        return Err("메트릭 서버 포트는 0일 수 없습니다.".to_string());
+    }
+    save_setting(&handle, "metricsEnabled", enabled);
+    if let Some(port) = port {
+        save_setting(&handle, "metricsPort", port);
+    }
+    apply_metrics_server_setting(&handle, &state);
+    Ok(())
+}
+
+// 알림이 생길 때마다 종류, 점수, 시각을 JSON으로 POST할 웹훅 주소를 설정하고 저장합니다. None이나 빈 문자열이면 끕니다.
+#[tauri::command]
+async fn set_webhook_url(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    url: Option<String>,
+) -> Result<(), String> {
+    let url = url.map(|url| url.trim().to_string()).filter(|url| !url.is_empty());
+    if let Some(url) = &url {
+        let parsed = reqwest::Url::parse(url).map_err(|e| format!("웹훅 주소가 올바르지 않습니다: {}", e))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(format!("웹훅 주소는 http 또는 https여야 합니다: {}", url));
+        }
+    }
+    info!("알림 웹훅 {}", if url.is_some() { "설정" } else { "해제" });
+    save_setting(&handle, "webhookUrl", &url);
+    *state.webhook_url.lock() = url;
+    Ok(())
+}
+
+#[tauri::command]
+fn get_webhook_url(state: State<'_, AppState>) -> Result<Option<String>, String> {
+    Ok(state.webhook_url.lock().clone())
+}
+
+// 트레이 아이콘을 숨기거나 다시 표시하고 저장합니다.
+// 숨긴 동안에는 메인 창을 닫으면 앱이 종료됩니다.
+#[tauri::command]
+async fn set_tray_visible(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    visible: bool,
+) -> Result<(), String> {
+    apply_tray_visibility(&handle, &state, visible);
+    save_setting(&handle, "trayVisible", visible);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_tray_visible(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(*state.tray_visible.lock())
+}
+
+// 모니터링 토글 전역 단축키를 바꾸고 저장합니다. 빈 문자열이면 단축키를 해제합니다.
+#[tauri::command]
+async fn set_monitoring_shortcut(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    shortcut: String,
+) -> Result<(), String> {
+    apply_monitoring_shortcut(&handle, &state, &shortcut)?;
+    save_setting(&handle, "monitoringShortcut", shortcut.trim());
+    Ok(())
+}
+
+// 앱 실행 시 모니터링을 자동으로 시작할지 설정하고 저장합니다. 다음 실행부터 적용됩니다.
+#[tauri::command]
+async fn set_auto_start_monitoring(handle: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    save_setting(&handle, "autoStartMonitoring", enabled);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_auto_start_monitoring(handle: tauri::AppHandle) -> Result<bool, String> {
+    Ok(load_setting::<bool>(&handle, "autoStartMonitoring").unwrap_or(false))
+}
+
+#[tauri::command]
+fn get_monitoring_shortcut(state: State<'_, AppState>) -> Result<Option<String>, String> {
+    Ok(state.monitoring_shortcut.lock().clone())
+}
+
+// 로그인 시 자동 시작 여부를 설정하고 저장합니다. 변경되면 autostart-changed 이벤트를 보냅니다.
+#[tauri::command]
+async fn set_autostart(handle: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    apply_autostart(&handle, enabled)?;
+    save_setting(&handle, "autostartEnabled", enabled);
+    let _ = handle.emit("autostart-changed", &serde_json::json!({ "enabled": enabled }));
+    Ok(())
+}
+
+#[tauri::command]
+fn get_autostart(handle: tauri::AppHandle) -> Result<bool, String> {
+    use tauri_plugin_autostart::ManagerExt;
+
+    handle.autolaunch().is_enabled().map_err(|e| e.to_string())
+}
+
+// 감지 항목별 ID, 현재 언어의 표시 이름, 사용 여부, 감지 강도를 반환합니다. 설정 화면이 이 목록으로 항목을 그립니다.
+// sensitivity는 1~3단계이며, 강도를 조절할 수 있지만 원시값을 직접 지정한 경우와 조절할 수 없는 항목은 null입니다.
+#[tauri::command]
+fn get_detectors(state: State<'_, AppState>) -> serde_json::Value {
+    let analyzer = &state.pose_analyzer;
+    let toggles = analyzer.detector_toggles();
+    let weights = serde_json::to_value(analyzer.score_weights()).unwrap_or_default();
+    let (turtle_neck_thresholds, shoulder_alignment_thresholds) = analyzer.detection_thresholds();
+    let lang = state.current_language.lock().clone();
+    serde_json::json!(DetectorToggles::NAMES
+        .iter()
+        .map(|&id| {
+            let thresholds = match id {
+                "turtle_neck" => Some(turtle_neck_thresholds),
+                "shoulder_misalignment" => Some(shoulder_alignment_thresholds),
+                _ => None,
+            };
+            serde_json::json!({
+                "id": id,
+                "name": state.translations.get(&lang, &format!("detector_{}", id)),
+                "enabled": toggles.get(id).unwrap_or(false),
+                "sensitivity_adjustable": thresholds.is_some(),
+                "sensitivity": analyzer.sensitivity_level(id),
+                "thresholds": thresholds,
+                "score_weight": weights.get(id),
+            })
+        })
+        .collect::<Vec<_>>())
+}
+
+// 문제 분석을 위해 분석기와 모니터링 루프의 현재 상태를 한 번에 반환합니다.
+#[tauri::command]
+fn get_debug_state(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    Ok(debug_state_json(&state))
+}
+
+fn debug_state_json(state: &AppState) -> serde_json::Value {
+    serde_json::json!({
+        "analyzer": state.pose_analyzer.debug_state(),
+        "monitoring_active": *state.monitoring_active.lock(),
+        "monitoring_interval_secs": *state.monitoring_interval_secs.lock(),
+        "alert_flush_interval_secs": *state.alert_flush_interval_secs.lock(),
+        "focus_mode": focus_mode_status(state),
+        "battery_saving_mode": *state.battery_saving_mode.lock(),
+        "low_power_inference": state.pose_analyzer.low_power_inference(),
+        "privacy_mode": *state.privacy_mode.lock(),
+        "logging_enabled": *state.logging_enabled.lock(),
+        "demo_mode": *state.demo_mode.lock(),
+        "selected_camera_index": *state.selected_camera_index.lock(),
+        "no_camera": *state.no_camera.lock(),
+        "camera_open": state.camera.lock().is_some(),
+        "camera_backoff_failures": state.camera_backoff.lock().consecutive_failures,
+        "pending_alerts": state.alert_messages.lock().len(),
+        "current_language": state.current_language.lock().clone(),
+    })
+}
+
+// 추론 전에 축소할 프레임 긴 변의 최대 크기(px)를 설정하고 저장합니다.
+#[tauri::command]
+async fn set_max_frame_dimension(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    max_dimension: u32,
+) -> Result<(), String> {
+    state
+        .pose_analyzer
+        .set_max_frame_dimension(max_dimension)
+        .map_err(|e| e.to_string())?;
+    save_setting(&handle, "maxFrameDimension", max_dimension);
+    Ok(())
+}
+
+// 가변 입력 모델에 사용할 입력 크기(px)를 설정하고 저장합니다. 고정 입력 모델과 크기가 다르면 오류를 반환합니다.
+#[tauri::command]
+async fn set_model_input_size(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    size: u32,
+) -> Result<(), String> {
+    state
+        .pose_analyzer
+        .set_model_input_size(size)
+        .map_err(|e| e.to_string())?;
+    save_setting(&handle, "modelInputSize", size);
+    Ok(())
+}
+
+// 어두운 조명 경고를 보낼 평균 밝기 기준(0~255)을 설정하고 저장합니다.
+#[tauri::command]
+async fn set_low_light_threshold(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    threshold: f64,
+) -> Result<(), String> {
+    if !(0.0..=255.0).contains(&threshold) {
+        return Err(format!("밝기 기준은 0에서 255 사이여야 합니다: {}", threshold));
+    }
+    *state.low_light_threshold.lock() = threshold;
+    *state.low_light_streak.lock() = 0;
+    save_setting(&handle, "lowLightThreshold", threshold);
+    Ok(())
+}
+
+// 캘리브레이션 이미지와 미리보기 썸네일의 JPEG 품질(1~100)을 설정하고 저장합니다.
+#[tauri::command]
+async fn set_image_quality(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    quality: u8,
+) -> Result<(), String> {
+    if !(1..=100).contains(&quality) {
+        return Err(format!("JPEG 품질은 1에서 100 사이여야 합니다: {}", quality));
+    }
+    *state.image_quality.lock() = quality;
+    save_setting(&handle, "imageQuality", quality);
+    Ok(())
+}
+
+// 카메라 회전 각도(0/90/180/270)를 설정하고 저장합니다.
+#[tauri::command]
+async fn set_camera_rotation(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    degrees: u16,
+) -> Result<(), String> {
+    state
+        .pose_analyzer
+        .set_camera_rotation(degrees)
+        .map_err(|e| e.to_string())?;
+    save_setting(&handle, "cameraRotation", degrees);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_monitoring_interval(
+    state: State<'_, AppState>,
+    interval_secs: Option<u64>,
+    interval_mins: Option<u64>,
+) -> Result<(), String> {
+    let interval_secs_final = if let Some(secs) = interval_secs {
+        secs
+    } else if let Some(mins) = interval_mins {
+        mins * 60
+    } else {
+        3
+    };
+    info!("모니터링 주기 변경: {}초", interval_secs_final);
+    *state.monitoring_interval_secs.lock() = interval_secs_final;
+    notify_interval_changed(&state);
+    Ok(())
+}
+
+// 지정한 시간(분) 동안 모니터링 주기를 interval_secs로 덮어씁니다. 종료되면 기존 주기로 돌아갑니다.
+// 배터리 절약 모드에서는 카메라를 매번 켜고 끄므로 집중 모드 주기를 적용하지 않습니다.
+#[tauri::command]
+async fn start_focus_mode(
+    state: State<'_, AppState>,
+    duration_mins: u64,
+    interval_secs: u64,
+) -> Result<serde_json::Value, String> {
+    if duration_mins == 0 || interval_secs == 0 {
+        return Err("집중 모드 시간과 주기는 0보다 커야 합니다.".to_string());
+    }
+    if duration_mins > MAX_FOCUS_MODE_MINS {
+        return Err(format!("집중 모드 시간은 최대 {}분입니다: {}", MAX_FOCUS_MODE_MINS, duration_mins));
+    }
+    if interval_secs > MAX_FOCUS_MODE_INTERVAL_SECS {
+        return Err(format!(
+            "집중 모드 주기는 최대 {}초입니다: {}",
+            MAX_FOCUS_MODE_INTERVAL_SECS, interval_secs
+        ));
+    }
+    let ends_at = Instant::now()
+        .checked_add(Duration::from_secs(duration_mins * 60))
+        .ok_or_else(|| "집중 모드 종료 시각을 계산할 수 없습니다.".to_string())?;
+    *state.focus_mode.lock() = Some(FocusMode { interval_secs, ends_at });
+    notify_interval_changed(&state);
+    info!("집중 모드 시작: {}분 동안 {}초 주기", duration_mins, interval_secs);
+    Ok(focus_mode_status(&state))
+}
+
+#[tauri::command]
+async fn stop_focus_mode(state: State<'_, AppState>) -> Result<(), String> {
+    if state.focus_mode.lock().take().is_some() {
+        notify_interval_changed(&state);
+        info!("집중 모드 중지");
+    }
+    Ok(())
+}
+
+// 지정한 시간(초, 기본 60초, 최대 300초) 동안 가장 엄격한 감지 강도와 1회 감지 기준을 적용합니다.
+// 그동안 감지될 때마다 sensitivity-check-triggered 이벤트를 보내고 시스템 알림은 보내지 않으며, 끝나면 원래 기준으로 되돌립니다.
+// 확인 중에는 모니터링 주기를 짧게 줄이고, 감지 기준을 바꾸는 명령은 거부합니다.
+#[tauri::command]
+async fn start_sensitivity_check(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    duration_secs: Option<u64>,
+) -> Result<serde_json::Value, String> {
+    let duration_secs = duration_secs.unwrap_or(DEFAULT_SENSITIVITY_CHECK_SECS);
+    if duration_secs == 0 || duration_secs > MAX_SENSITIVITY_CHECK_SECS {
+        return Err(format!(
+            "감도 확인 시간은 1~{}초여야 합니다: {}",
+            MAX_SENSITIVITY_CHECK_SECS, duration_secs
+        ));
+    }
+    let analyzer = &state.pose_analyzer;
+    let ends_at = Instant::now() + Duration::from_secs(duration_secs);
+    {
+        let mut check = state.sensitivity_check.lock();
+        match check.as_mut() {
+            // 이미 진행 중이면 원래 기준은 그대로 두고 시간만 연장
+            Some(active) => active.ends_at = ends_at,
+            None => {
+                *check = Some(SensitivityCheck {
+                    ends_at,
+                    saved_thresholds: analyzer.detection_thresholds(),
+                    saved_required_count: analyzer.required_detection_count(),
+                    saved_release_count: analyzer.release_threshold_count(),
+                });
+            }
+        }
+    }
+    analyzer.set_turtle_neck_sensitivity(3);
+    analyzer.set_shoulder_sensitivity(3);
+    analyzer
+        .set_detection_hysteresis(1, 0)
+        .map_err(|e| e.to_string())?;
+    analyzer.clear_recent_results();
+    notify_interval_changed(&state);
+    // 모니터링 루프가 긴 주기로 잠들어 있어도 정해진 시각에 끝나도록 별도 타이머로 종료 (연장되었으면 그 타이머가 종료)
+    let timer_app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep_until(ends_at.into()).await;
+        let state = timer_app.state::<AppState>();
+        if sensitivity_check_expired(&state) {
+            end_sensitivity_check(&timer_app, &state);
+        }
+    });
+    info!("감도 확인 모드 시작: {}초", duration_secs);
+    let status = serde_json::json!({ "active": true, "remaining_secs": duration_secs });
+    let _ = app.emit("sensitivity-check-started", &status);
+    Ok(status)
+}
+
+#[tauri::command]
+async fn stop_sensitivity_check(app: AppHandle, state: State<'_, AppState>) -> Result<(), String> {
+    end_sensitivity_check(&app, &state);
+    Ok(())
+}
+
+// 감도 확인 모드를 끝내고 저장해 둔 기준으로 되돌립니다. (진행 중이 아니면 아무것도 하지 않음)
+fn end_sensitivity_check(app_handle: &AppHandle, state: &AppState) {
+    let Some(check) = state.sensitivity_check.lock().take() else {
+        return;
+    };
+    let analyzer = &state.pose_analyzer;
+    let (turtle_neck, shoulder_alignment) = check.saved_thresholds;
+    analyzer.set_detection_thresholds(turtle_neck, shoulder_alignment);
+    if let Err(e) = analyzer.set_detection_hysteresis(check.saved_required_count, check.saved_release_count) {
+        warn!("감지 횟수 기준 복원 실패: {}", e);
+    }
+    analyzer.clear_recent_results();
+    notify_interval_changed(state);
+    info!("감도 확인 모드 종료: 원래 감지 기준으로 복원");
+    let _ = app_handle.emit("sensitivity-check-ended", &serde_json::json!({}));
+}
+
+fn sensitivity_check_expired(state: &AppState) -> bool {
+    state
+        .sensitivity_check
+        .lock()
+        .is_some_and(|check| Instant::now() >= check.ends_at)
+}
+
+// 감도 확인 중에는 감지 기준을 바꾸지 못하게 합니다. (끝날 때 확인 전 기준으로 되돌리면서 바꾼 값이 사라지므로)
+fn ensure_no_sensitivity_check(state: &AppState) -> Result<(), String> {
+    if state.sensitivity_check.lock().is_some() {
+        return Err("감도 확인 모드 중에는 감지 기준을 바꿀 수 없습니다. 확인을 끝낸 뒤 다시 시도해주세요.".to_string());
+    }
+    Ok(())
+}
+
+// 오래 앉아 있기 경고 시간(분, 0이면 끔)과 자리 비움으로 판단할 유예 시간(초)을 설정하고 저장합니다.
+// 유예 시간은 1초 이상이어야 하며, 범위를 벗어난 값은 아무것도 바꾸지 않고 거부합니다.
+#[tauri::command]
+async fn set_sitting_limit(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    limit_mins: u64,
+    grace_secs: Option<u64>,
+) -> Result<(), String> {
+    if limit_mins > MAX_SITTING_LIMIT_MINS {
+        return Err(format!("경고 시간은 {}분 이하여야 합니다: {}", MAX_SITTING_LIMIT_MINS, limit_mins));
+    }
+    if let Some(grace_secs) = grace_secs {
+        if !(1..=MAX_SITTING_GRACE_SECS).contains(&grace_secs) {
+            return Err(format!("유예 시간은 1~{}초여야 합니다: {}", MAX_SITTING_GRACE_SECS, grace_secs));
+        }
+    }
+    *state.sitting_limit_mins.lock() = limit_mins;
+    save_setting(&handle, "sittingLimitMins", limit_mins);
+    if let Some(grace_secs) = grace_secs {
+        *state.sitting_grace_secs.lock() = grace_secs;
+        save_setting(&handle, "sittingGraceSecs", grace_secs);
+    }
+    info!("오래 앉아 있기 경고 설정: {}분", limit_mins);
+    Ok(())
+}
+
+// 모니터링 시작 직후 자세 알림을 보내지 않을 준비 시간(초, 0이면 끔)을 설정하고 저장합니다.
+// 준비 시간 동안에도 분석과 기록은 그대로 진행됩니다.
+#[tauri::command]
+async fn set_startup_grace(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    secs: u64,
+) -> Result<(), String> {
+    if secs > MAX_STARTUP_GRACE_SECS {
+        return Err(format!("준비 시간은 {}초 이하여야 합니다: {}", MAX_STARTUP_GRACE_SECS, secs));
+    }
+    *state.startup_grace_secs.lock() = secs;
+    save_setting(&handle, "startupGraceSecs", secs);
+    info!("모니터링 시작 후 알림 준비 시간 설정: {}초", secs);
+    Ok(())
+}
+
+// 절약 모드에서 카메라를 켠 뒤 첫 캡처까지 기다리는 시간과 첫 프레임을 버린 뒤 기다리는 시간(ms)을 설정하고 저장합니다.
+// 빠른 웹캠은 줄여서 카메라가 켜져 있는 시간을 줄이고, 느린 웹캠은 늘려서 어두운 첫 프레임을 피할 수 있습니다.
+#[tauri::command]
+async fn set_camera_warmup(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    warmup_ms: u64,
+    drop_delay_ms: Option<u64>,
+) -> Result<(), String> {
+    if warmup_ms > MAX_CAMERA_WARMUP_MS {
+        return Err(format!("카메라 준비 시간은 {}ms 이하여야 합니다: {}", MAX_CAMERA_WARMUP_MS, warmup_ms));
+    }
+    let drop_delay_ms = drop_delay_ms.unwrap_or(DEFAULT_CAMERA_DROP_DELAY_MS);
+    if drop_delay_ms > MAX_CAMERA_DROP_DELAY_MS {
+        return Err(format!(
+            "프레임 폐기 후 대기 시간은 {}ms 이하여야 합니다: {}",
+            MAX_CAMERA_DROP_DELAY_MS, drop_delay_ms
+        ));
+    }
+    *state.camera_warmup_ms.lock() = (warmup_ms, drop_delay_ms);
+    save_setting(&handle, "cameraWarmupMs", warmup_ms);
+    save_setting(&handle, "cameraDropDelayMs", drop_delay_ms);
+    info!("절약 모드 카메라 대기 시간 설정: 준비 {}ms, 프레임 폐기 후 {}ms", warmup_ms, drop_delay_ms);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_camera_warmup(state: State<'_, AppState>) -> serde_json::Value {
+    let (warmup_ms, drop_delay_ms) = *state.camera_warmup_ms.lock();
+    serde_json::json!({ "warmup_ms": warmup_ms, "drop_delay_ms": drop_delay_ms })
+}
+
+// 모니터링 시작 후 남은 알림 준비 시간 (준비 시간이 지났거나 모니터링을 시작한 적이 없으면 0)
+fn startup_grace_remaining(state: &AppState) -> Duration {
+    let grace = Duration::from_secs(*state.startup_grace_secs.lock());
+    state
+        .monitoring_started_at
+        .lock()
+        .map_or(Duration::ZERO, |started| grace.saturating_sub(started.elapsed()))
+}
+
+// 알림 단계를 나누는 자세 점수 기준을 설정하고 저장합니다. (bad_below <= warning_below)
+#[tauri::command]
+async fn set_alert_tiers(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    warning_below: i64,
+    bad_below: i64,
+) -> Result<(), String> {
+    if !(0..=100).contains(&warning_below) || !(0..=100).contains(&bad_below) {
+        return Err("알림 단계 기준 점수는 0에서 100 사이여야 합니다.".to_string());
+    }
+    if bad_below > warning_below {
+        return Err(format!(
+            "나쁨 기준({})은 주의 기준({})보다 클 수 없습니다.",
+            bad_below, warning_below
+        ));
+    }
+    let tiers = AlertTiers { warning_below, bad_below };
+    *state.alert_tiers.lock() = tiers;
+    save_setting(&handle, "alertTiers", tiers);
+    info!("알림 단계 기준 변경: 주의 < {}, 나쁨 < {}", warning_below, bad_below);
+    Ok(())
+}
+
+// 최근 자세 안정도에 따라 모니터링 주기를 min_secs~max_secs 사이에서 자동으로 조정합니다.
+// 집중 모드가 켜져 있으면 집중 모드 주기를 우선 사용합니다.
+#[tauri::command]
+async fn set_adaptive_interval(
+    state: State<'_, AppState>,
+    enabled: bool,
+    min_secs: Option<u64>,
+    max_secs: Option<u64>,
+) -> Result<serde_json::Value, String> {
+    if enabled {
+        let min_secs = min_secs.unwrap_or(3);
+        let max_secs = max_secs.unwrap_or(30);
+        if min_secs == 0 || min_secs > max_secs {
+            return Err(format!("적응형 주기 범위가 올바르지 않습니다: {}~{}초", min_secs, max_secs));
+        }
+        *state.adaptive_interval.lock() = Some(AdaptiveInterval { min_secs, max_secs });
+        info!("적응형 모니터링 주기 사용: {}~{}초", min_secs, max_secs);
+    } else {
+        *state.adaptive_interval.lock() = None;
+        info!("적응형 모니터링 주기 해제");
+    }
+    notify_interval_changed(&state);
+    Ok(serde_json::json!({
+        "enabled": enabled,
+        "effective_interval_secs": effective_monitoring_interval(&state).as_secs(),
+    }))
+}
+
+// 알림을 모아서 보내는 주기를 설정합니다. 값이 없거나 0이면 기본 주기(3초)를 사용합니다.
+#[tauri::command]
+async fn set_alert_flush_interval(
+    state: State<'_, AppState>,
+    interval_secs: Option<u64>,
+) -> Result<(), String> {
+    let interval_secs = interval_secs.filter(|&secs| secs > 0);
+    match interval_secs {
+        Some(secs) => info!("알림 전송 주기 변경: {}초", secs),
+        None => info!("알림 전송 주기 변경: 기본 {}초", DEFAULT_ALERT_FLUSH_INTERVAL_SECS),
+    }
+    *state.alert_flush_interval_secs.lock() = interval_secs;
+    state.alert_interval_changed.notify_one();
+    Ok(())
+}
+
+#[tauri::command]
+fn get_focus_mode_status(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    Ok(focus_mode_status(&state))
+}
+
+#[tauri::command]
+async fn set_battery_saving_mode(state: State<'_, AppState>, mode: bool) -> Result<(), String> {
+    *state.battery_saving_mode.lock() = mode;
+    apply_low_power_inference(&state);
+    notify_interval_changed(&state);
+    info!("배터리 절약 모드 설정: {}", mode);
+
+    if mode {
+        // 절약 모드: 기존 카메라 닫기
+        if let Some(mut cam) = state.camera.lock().take() {
+            if cam.is_stream_open() {
+                if let Err(e) = cam.stop_stream() {
+                    error!("절약 모드 전환 시 카메라 스트림 닫기 실패: {}", e);
+                } else {
+                    info!("절약 모드 전환 시 카메라 스트림 닫음.");
+                }
+            }
+        }
+    } else {
+        // 일반 모드: 모니터링 중이면 카메라 열기
+        if *state.monitoring_active.lock() {
+            let mut cam_lock = state.camera.lock();
+            if cam_lock.is_none() {
+                let index = *state.selected_camera_index.lock();
+                match open_camera(&state, index) {
+                    Ok(cam) => {
+                        info!("일반 모드 전환 시 카메라 스트림 열음.");
+                        *cam_lock = Some(cam);
+                    }
+                    Err(e) => {
+                        error!("일반 모드 전환 시 카메라 열기 실패: {}", e);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// 분석과 알림은 그대로 두고 posture_log 기록만 켜거나 끕니다.
+#[tauri::command]
+async fn set_logging_enabled(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    *state.logging_enabled.lock() = enabled;
+    save_setting(&handle, "loggingEnabled", enabled);
+    info!("자세 기록 저장 설정: {}", enabled);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_logging_enabled(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(*state.logging_enabled.lock())
+}
+
+// 스크린샷, 튜토리얼 등 일시적인 화면을 위한 데모 모드를 켜고 끕니다.
+// 분석과 analysis-update 이벤트는 그대로 동작하지만 DB 기록과 알림은 보내지 않습니다.
+// 설정에 저장하지 않으므로 앱을 다시 시작하면 항상 꺼진 상태로 돌아갑니다.
+#[tauri::command]
+async fn set_demo_mode(state: State<'_, AppState>, enabled: bool) -> Result<(), String> {
+    *state.demo_mode.lock() = enabled;
+    if enabled {
+        // 데모 시작 전에 쌓여 있던 알림도 보내지 않음
+        state.alert_messages.lock().clear();
+    }
+    info!("데모 모드 설정: {}", enabled);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_demo_mode(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(*state.demo_mode.lock())
+}
+
+#[tauri::command]
+async fn set_current_language(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    lang: String,
+) -> Result<(), String> {
+    let normalized = state.translations.resolve_language(&lang).unwrap_or_else(|| {
+        warn!("지원하지 않는 언어 '{}', {}로 대체", lang, FALLBACK_LANGUAGE);
+        FALLBACK_LANGUAGE.to_string()
+    });
+    info!("현재 언어 변경: {}", normalized);
+    save_setting(&handle, "currentLanguage", &normalized);
+    *state.current_language.lock() = normalized;
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_posture_stats(
+    app: AppHandle,
+    start_timestamp: i64,
+    end_timestamp: i64,
+) -> Result<serde_json::Value, String> {
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+
+    // 긴 범위는 요약 테이블에서, 짧은 범위는 원본 로그에서 시간별로 집계
+    let use_hourly = end_timestamp - start_timestamp >= HOURLY_STATS_MIN_RANGE_SECS;
+    if use_hourly {
+        // 마지막 요약 이후(진행 중인 시간 포함)의 기록도 포함되도록 먼저 요약 테이블을 갱신
+        if let Err(e) = rollup_hourly_stats(&pool).await {
+            warn!("자세 통계 조회 전 시간별 요약 갱신 실패: {}", e);
+        }
+    }
+    let (query, scale) = if use_hourly {
+        (
+            "SELECT hour_start, sample_count, avg_score, turtle_neck_count, shoulder_misaligned_count FROM posture_hourly WHERE hour_start >= ? AND hour_start < ? ORDER BY hour_start ASC".to_string(),
+            1,
+        )
+    } else {
+        (
+            format!(
+                "SELECT ({ts} / 3600000) * 3600 AS hour_start, COUNT(*), AVG(score), SUM(is_turtle_neck), SUM(is_shoulder_misaligned) FROM posture_log WHERE is_test = 0 AND {ts} >= ? AND {ts} < ? GROUP BY hour_start ORDER BY hour_start ASC",
+                ts = TIMESTAMP_MS_SQL
+            ),
+            1000,
+        )
+    };
+    let rows = sqlx::query_as::<_, (i64, i64, f64, i64, i64)>(&query)
+        .bind(start_timestamp * scale)
+        .bind(end_timestamp * scale)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("자세 통계 조회 실패: {}", e))?;
+
+    let sample_count: i64 = rows.iter().map(|r| r.1).sum();
+    let average_score = if sample_count > 0 {
+        Some(rows.iter().map(|r| r.2 * r.1 as f64).sum::<f64>() / sample_count as f64)
+    } else {
+        None
+    };
+    let buckets: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|(hour_start, count, avg_score, turtle, shoulder)| {
+            serde_json::json!({
+                "hour_start": hour_start,
+                "sample_count": count,
+                "average_score": avg_score,
+                "turtle_neck_count": turtle,
+                "shoulder_misaligned_count": shoulder,
+            })
+        })
+        .collect();
+
+    Ok(serde_json::json!({
+        "source": if use_hourly { "hourly" } else { "raw" },
+        "sample_count": sample_count,
+        "average_score": average_score,
+        "turtle_neck_count": rows.iter().map(|r| r.3).sum::<i64>(),
+        "shoulder_misaligned_count": rows.iter().map(|r| r.4).sum::<i64>(),
+        "buckets": buckets,
+    }))
+}
+
+// 자세 기록과 통계를 모두 지우고 처음부터 다시 시작합니다. 실수를 막기 위해 confirm이 true여야 실행됩니다.
+// reset_streaks이면 연속 착석 시간 등 진행 중인 누적 상태도, reset_calibration이면 기준 자세도 함께 지웁니다.
+#[tauri::command]
+async fn reset_all_data(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    confirm: bool,
+    reset_streaks: Option<bool>,
+    reset_calibration: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    if !confirm {
+        return Err("모든 자세 기록을 삭제하려면 confirm을 true로 전달해야 합니다.".to_string());
+    }
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+
+    let mut tx = pool.begin().await.map_err(|e| format!("트랜잭션 시작 실패: {}", e))?;
+    let posture_log_rows = sqlx::query("DELETE FROM posture_log")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("자세 기록 삭제 실패: {}", e))?
+        .rows_affected();
+    let hourly_rows = sqlx::query("DELETE FROM posture_hourly")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("시간별 통계 삭제 실패: {}", e))?
+        .rows_affected();
+    tx.commit().await.map_err(|e| format!("트랜잭션 커밋 실패: {}", e))?;
+
+    let pending_samples = std::mem::take(&mut *state.pending_samples.lock()).len();
+    let cached_frames = std::mem::take(&mut *state.recent_frames.lock()).len();
+    let recent_scores = std::mem::take(&mut *state.recent_scores.lock()).len();
+
+    let streaks_reset = reset_streaks.unwrap_or(false);
+    if streaks_reset {
+        *state.presence.lock() = PresenceTracker::default();
+        *state.low_light_streak.lock() = 0;
+        state.pose_analyzer.clear_recent_results();
+    }
+
+    let calibration_reset = reset_calibration.unwrap_or(false);
+    let baseline_file_removed = if calibration_reset {
+        let removed = state.pose_analyzer.clear_baseline(&app).map_err(|e| e.to_string())?;
+        remove_calibration_image(&app)?;
+        clear_active_profile(&app);
+        removed
+    } else {
+        false
+    };
+
+    info!(
+        "모든 자세 기록 초기화: posture_log {}건, 시간별 통계 {}건, 대기 기록 {}건",
+        posture_log_rows, hourly_rows, pending_samples
+    );
+    Ok(serde_json::json!({
+        "posture_log_rows": posture_log_rows,
+        "hourly_summary_rows": hourly_rows,
+        "pending_samples": pending_samples,
+        "cached_frames": cached_frames,
+        "recent_scores": recent_scores,
+        "streaks_reset": streaks_reset,
+        "calibration_reset": calibration_reset,
+        "baseline_file_removed": baseline_file_removed,
+    }))
+}
+
+// 기간 내 posture_log 기록을 활동 라벨별로 집계합니다. 라벨이 없는 기록은 activity가 null인 항목으로 묶입니다.
+#[tauri::command]
+async fn get_posture_stats_by_activity(
+    app: AppHandle,
+    start_timestamp: i64,
+    end_timestamp: i64,
+) -> Result<serde_json::Value, String> {
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+
+    let query = format!(
+        "SELECT activity, COUNT(*), AVG(score), SUM(is_turtle_neck), SUM(is_shoulder_misaligned) FROM posture_log WHERE is_test = 0 AND {ts} >= ? AND {ts} < ? GROUP BY activity ORDER BY COUNT(*) DESC",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let rows = sqlx::query_as::<_, (Option<String>, i64, f64, i64, i64)>(&query)
+    .bind(start_timestamp * 1000)
+    .bind(end_timestamp * 1000)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("활동별 자세 통계 조회 실패: {}", e))?;
+
+    let activities: Vec<serde_json::Value> = rows
+        .into_iter()
+        .map(|(activity, count, avg_score, turtle, shoulder)| {
+            serde_json::json!({
+                "activity": activity,
+                "sample_count": count,
+                "average_score": avg_score,
+                "turtle_neck_count": turtle,
+                "shoulder_misaligned_count": shoulder,
+            })
+        })
+        .collect();
+    Ok(serde_json::json!({ "activities": activities }))
+}
+
+// 기간을 일/주 단위 구간으로 나눠 평균 점수를 반환합니다. (start_timestamp부터 구간을 나눔)
+// 기록이 없는 구간은 null로 두며, 기록이 있는 구간들로 선형 회귀 기울기를 계산해 개선/악화 추세를 함께 반환합니다.
+#[tauri::command]
+async fn get_posture_trend(
+    app: AppHandle,
+    start_timestamp: i64,
+    end_timestamp: i64,
+    bucket: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let bucket = bucket.unwrap_or_else(|| "daily".to_string());
+    let bucket_secs: i64 = match bucket.as_str() {
+        "daily" => 24 * 3600,
+        "weekly" => 7 * 24 * 3600,
+        other => return Err(format!("지원하지 않는 구간 단위입니다: {} (daily, weekly)", other)),
+    };
+    if end_timestamp <= start_timestamp {
+        return Err("종료 시각은 시작 시각보다 뒤여야 합니다.".to_string());
+    }
+    let bucket_count = end_timestamp
+        .checked_sub(start_timestamp)
+        .map(|span| span / bucket_secs + i64::from(span % bucket_secs != 0))
+        .filter(|count| *count <= MAX_TREND_BUCKETS)
+        .ok_or_else(|| format!("조회 기간이 너무 깁니다. (최대 {}개 구간)", MAX_TREND_BUCKETS))?;
+    let (Some(start_ms), Some(end_ms)) = (start_timestamp.checked_mul(1000), end_timestamp.checked_mul(1000)) else {
+        return Err("조회 시각이 올바르지 않습니다.".to_string());
+    };
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+
+    let query = format!(
+        "SELECT ({ts} / 1000 - ?) / ? AS bucket, AVG(score), COUNT(*) FROM posture_log WHERE is_test = 0 AND {ts} >= ? AND {ts} < ? GROUP BY bucket",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let rows = sqlx::query_as::<_, (i64, f64, i64)>(&query)
+    .bind(start_timestamp)
+    .bind(bucket_secs)
+    .bind(start_ms)
+    .bind(end_ms)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("자세 추세 조회 실패: {}", e))?;
+    let by_bucket: HashMap<i64, (f64, i64)> = rows
+        .into_iter()
+        .map(|(index, avg, count)| (index, (avg, count)))
+        .collect();
+
+    let mut points = Vec::new();
+    let buckets: Vec<serde_json::Value> = (0..bucket_count)
+        .map(|index| {
+            let entry = by_bucket.get(&index);
+            if let Some((avg, _)) = entry {
+                points.push((index as f64, *avg));
+            }
+            serde_json::json!({
+                "start": start_timestamp + index * bucket_secs,
+                "average_score": entry.map(|(avg, _)| *avg),
+                "sample_count": entry.map(|(_, count)| *count).unwrap_or(0),
+            })
+        })
+        .collect();
+
+    // 최소제곱 기울기 (구간당 평균 점수 변화량). 기록이 있는 구간이 2개 미만이면 계산하지 않음
+    let slope = if points.len() >= 2 {
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let covariance: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        Some(covariance / variance)
+    } else {
+        None
+    };
+    let trend = slope.map(|slope| {
+        if slope > TREND_FLAT_SLOPE {
+            "improving"
+        } else if slope < -TREND_FLAT_SLOPE {
+            "declining"
+        } else {
+            "stable"
+        }
+    });
+
+    Ok(serde_json::json!({
+        "bucket": bucket,
+        "bucket_secs": bucket_secs,
+        "buckets": buckets,
+        "slope": slope,
+        "trend": trend,
+    }))
+}
+
+// 최근 days일(기본 28일) 기록을 요일(0=월요일 ~ 6=일요일) × 시각(0~23) 칸으로 묶어 평균 점수를 반환합니다.
+// 로컬 시간 기준으로 나누며, 기록이 없는 칸은 null입니다.
+#[tauri::command]
+async fn get_posture_heatmap(app: AppHandle, days: Option<u32>) -> Result<serde_json::Value, String> {
+    let days = days.unwrap_or(28).max(1);
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+    let since_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+        - days as i64 * 24 * 3_600_000;
+
+    // SQLite의 %w는 0=일요일이므로 0=월요일이 되도록 변환
+    let query = format!(
+        "SELECT (CAST(strftime('%w', {ts} / 1000, 'unixepoch', 'localtime') AS INTEGER) + 6) % 7 AS weekday, \
+         CAST(strftime('%H', {ts} / 1000, 'unixepoch', 'localtime') AS INTEGER) AS hour, AVG(score), COUNT(*) \
+         FROM posture_log WHERE is_test = 0 AND {ts} >= ? GROUP BY weekday, hour",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let rows = sqlx::query_as::<_, (i64, i64, f64, i64)>(&query)
+    .bind(since_ms)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("자세 히트맵 조회 실패: {}", e))?;
+
+    let mut scores = vec![vec![None::<f64>; 24]; 7];
+    let mut counts = vec![vec![0i64; 24]; 7];
+    for (weekday, hour, avg_score, count) in rows {
+        if let (Ok(weekday), Ok(hour)) = (usize::try_from(weekday), usize::try_from(hour)) {
+            if weekday < 7 && hour < 24 {
+                scores[weekday][hour] = Some(avg_score);
+                counts[weekday][hour] = count;
+            }
+        }
+    }
+    Ok(serde_json::json!({
+        "days": days,
+        "scores": scores,
+        "sample_counts": counts,
+    }))
+}
+
+// 데이터 관리 화면용으로 posture_log 행 수, 가장 이른/늦은 기록 시각(초), DB 파일 크기(WAL 포함)를 반환합니다.
+// DB가 아직 만들어지지 않았거나 연결되지 않았으면 행 정보는 null로 반환합니다.
+#[tauri::command]
+async fn get_database_info(app: AppHandle) -> Result<serde_json::Value, String> {
+    let path = database_file_path(&app)?;
+    let file_size = |path: &std::path::Path| fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let exists = path.exists();
+    let wal_path = path.with_extension("db-wal");
+    let size_bytes = file_size(&path) + file_size(&wal_path);
+
+    let stats = match get_sqlite_pool(&app).await {
+        Some(pool) if exists => {
+            let query = format!(
+                "SELECT COUNT(*), MIN({ts}), MAX({ts}) FROM posture_log",
+                ts = TIMESTAMP_MS_SQL
+            );
+            let row = sqlx::query_as::<_, (i64, Option<i64>, Option<i64>)>(&query)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| format!("데이터베이스 정보 조회 실패: {}", e))?;
+            Some(row)
+        }
+        _ => None,
+    };
+    Ok(serde_json::json!({
+        "path": path.to_string_lossy(),
+        "exists": exists,
+        "size_bytes": size_bytes,
+        "row_count": stats.map(|(count, _, _)| count),
+        "earliest_timestamp": stats.and_then(|(_, earliest, _)| earliest).map(|ms| ms / 1000),
+        "latest_timestamp": stats.and_then(|(_, _, latest)| latest).map(|ms| ms / 1000),
+    }))
+}
+
+// posture_log 원본 기록을 페이지 단위로 반환합니다. 기본은 최신순이며 점수 범위로 거를 수 있습니다.
+// 테스트 감지 기록은 include_test를 켠 경우에만 포함합니다.
+#[tauri::command]
+async fn get_posture_log(
+    app: AppHandle,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    since_timestamp: Option<i64>,
+    min_score: Option<i64>,
+    max_score: Option<i64>,
+    oldest_first: Option<bool>,
+    include_test: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+    let limit = limit.unwrap_or(100).clamp(1, 1000);
+    let offset = offset.unwrap_or(0);
+    let include_test = include_test.unwrap_or(false);
+
+    // 조건은 값이 없으면 무시되도록 작성해 정렬 방향만 쿼리 문자열로 바꿈 (since_timestamp는 초 단위)
+    let filter = format!(
+        "(?1 IS NULL OR {ts} >= ?1 * 1000) AND (?2 IS NULL OR score >= ?2) AND (?3 IS NULL OR score <= ?3) AND (?4 OR is_test = 0)",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let order = if oldest_first.unwrap_or(false) { "ASC" } else { "DESC" };
+    let rows_query = format!(
+        "SELECT id, score, is_turtle_neck, is_shoulder_misaligned, {}, activity, is_test FROM posture_log WHERE {} ORDER BY {} {}, id {} LIMIT ?5 OFFSET ?6",
+        TIMESTAMP_MS_SQL, filter, TIMESTAMP_MS_SQL, order, order
+    );
+    let rows = sqlx::query_as::<_, (i64, i64, bool, bool, i64, Option<String>, bool)>(&rows_query)
+        .bind(since_timestamp)
+        .bind(min_score)
+        .bind(max_score)
+        .bind(include_test)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("자세 기록 조회 실패: {}", e))?;
+    let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM posture_log WHERE {}", filter))
+        .bind(since_timestamp)
+        .bind(min_score)
+        .bind(max_score)
+        .bind(include_test)
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| format!("자세 기록 개수 조회 실패: {}", e))?;
+
+    let rows: Vec<serde_json::Value> = rows
+        .into_iter()
+        .map(|(id, score, is_turtle, is_shoulder, timestamp_ms, activity, is_test)| {
+            serde_json::json!({
+                "id": id,
+                "score": score,
+                "is_turtle_neck": is_turtle,
+                "is_shoulder_misaligned": is_shoulder,
+                "timestamp": timestamp_ms / 1000,
+                "timestamp_ms": timestamp_ms,
+                "activity": activity,
+                "is_test": is_test,
+            })
+        })
+        .collect();
+    Ok(serde_json::json!({
+        "rows": rows,
+        "total": total,
+        "limit": limit,
+        "offset": offset,
+    }))
+}
+
+// 하루(로컬 날짜, 기본은 오늘) 동안 good_threshold점 이상이었던 샘플 비율을 반환합니다.
+// 기록이 없는 날은 percentage가 null입니다.
+#[tauri::command]
+async fn get_good_posture_percentage(
+    app: AppHandle,
+    date: Option<String>,
+    good_threshold: Option<i64>,
+) -> Result<serde_json::Value, String> {
+    let good_threshold = good_threshold.unwrap_or(DEFAULT_GOOD_SCORE_THRESHOLD);
+    let day = match date.as_deref() {
+        Some(date) => chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|e| format!("날짜 형식이 올바르지 않습니다 (YYYY-MM-DD): {}", e))?,
+        None => chrono::Local::now().date_naive(),
+    };
+    let day_start = |day: chrono::NaiveDate| {
+        day.and_hms_opt(0, 0, 0)
+            .and_then(|t| t.and_local_timezone(chrono::Local).earliest())
+            .map(|t| t.timestamp())
+            .ok_or_else(|| format!("{}의 시작 시각을 계산할 수 없습니다.", day))
+    };
+    let start_timestamp = day_start(day)?;
+    let end_timestamp = day_start(day + chrono::Duration::days(1))?;
+
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+    let query = format!(
+        "SELECT COUNT(*), COALESCE(SUM(score >= ?), 0) FROM posture_log WHERE is_test = 0 AND {ts} >= ? AND {ts} < ?",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let (sample_count, good_count) = sqlx::query_as::<_, (i64, i64)>(&query)
+    .bind(good_threshold)
+    .bind(start_timestamp * 1000)
+    .bind(end_timestamp * 1000)
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| format!("바른 자세 비율 조회 실패: {}", e))?;
+
+    let percentage = if sample_count > 0 {
+        Some(good_count as f64 * 100.0 / sample_count as f64)
+    } else {
+        None
+    };
+    Ok(serde_json::json!({
+        "date": day.format("%Y-%m-%d").to_string(),
+        "good_threshold": good_threshold,
+        "sample_count": sample_count,
+        "good_count": good_count,
+        "percentage": percentage,
+    }))
+}
+
+// 최근 hours시간 동안 가장 점수가 낮았던 기록을 반환합니다.
+// 해당 시점의 프레임이 메모리에 남아 있고 개인정보 보호 모드가 아니면 썸네일(data URL)을 함께 반환합니다.
+#[tauri::command]
+async fn get_worst_recent_event(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    hours: Option<u32>,
+    include_thumbnail: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    let pool = get_sqlite_pool(&app)
+        .await
+        .ok_or_else(|| "데이터베이스가 아직 준비되지 않았습니다.".to_string())?;
+    let since_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+        - hours.unwrap_or(24) as i64 * 3_600_000;
+
+    let query = format!(
+        "SELECT score, is_turtle_neck, is_shoulder_misaligned, {ts} FROM posture_log WHERE is_test = 0 AND {ts} >= ? ORDER BY score ASC, {ts} DESC LIMIT 1",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let row = sqlx::query_as::<_, (i64, bool, bool, i64)>(&query)
+    .bind(since_ms)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("최근 기록 조회 실패: {}", e))?;
+    let Some((score, is_turtle, is_shoulder, timestamp_ms)) = row else {
+        return Ok(serde_json::Value::Null);
+    };
+
+    let thumbnail = if include_thumbnail.unwrap_or(true) && !*state.privacy_mode.lock() {
+        state
+            .recent_frames
+            .lock()
+            .iter()
+            .find(|frame| frame.timestamp_ms == timestamp_ms)
+            .map(|frame| format!("data:image/jpeg;base64,{}", STANDARD.encode(&frame.jpeg)))
+    } else {
+        None
+    };
+
+    Ok(serde_json::json!({
+        "score": score,
+        "is_turtle_neck": is_turtle,
+        "is_shoulder_misaligned": is_shoulder,
+        "timestamp": timestamp_ms / 1000,
+        "timestamp_ms": timestamp_ms,
+        "thumbnail": thumbnail,
+    }))
+}
+
+// 저장소의 설정과 기준 자세 값을 하나의 JSON 파일로 내보냅니다. (이미지 제외)
+#[tauri::command]
+async fn export_settings(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    path: String,
+) -> Result<String, String> {
+    let store = handle
+        .store(SETTINGS_STORE)
+        .map_err(|e| format!("설정 저장소 열기 실패: {}", e))?;
+    let settings: serde_json::Map<String, serde_json::Value> = store
+        .entries()
+        .into_iter()
+        .filter(|(key, _)| !NON_PORTABLE_SETTING_KEYS.contains(&key.as_str()))
+        .collect();
+    let backup = serde_json::json!({
+        "schema_version": SETTINGS_BACKUP_VERSION,
+        "app_version": handle.package_info().version.to_string(),
+        "exported_at": SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+        "settings": settings,
+        "baseline": state.pose_analyzer.baseline_to_json(),
+    });
+    let json_str = serde_json::to_string_pretty(&backup).map_err(|e| e.to_string())?;
+    fs::write(&path, json_str).map_err(|e| format!("설정 백업 파일 쓰기 실패: {}", e))?;
+    info!("설정 내보내기 완료: {}", path);
+    Ok(path)
+}
+
+// 키포인트만 기록하는 세션 녹화를 시작합니다. 원본 영상은 저장하지 않고, 멈출 때 스켈레톤 애니메이션(GIF)으로 만듭니다.
+#[tauri::command]
+fn start_session_recording(state: State<'_, AppState>) -> Result<(), String> {
+    let mut recording = state.session_recording.lock();
+    if recording.is_some() {
+        return Err("이미 세션 녹화 중입니다.".to_string());
+    }
+    *recording = Some(SessionRecording {
+        started_at_ms: chrono::Utc::now().timestamp_millis(),
+        frames: Vec::new(),
+        truncated: false,
+    });
+    info!("세션 녹화 시작");
+    Ok(())
+}
+
+// 세션 녹화를 멈추고 스켈레톤 애니메이션 GIF와 키포인트 JSON을 저장합니다.
+// path를 생략하면 앱 데이터 폴더의 session_recordings/session-<시각>.gif에 저장합니다.
+#[tauri::command]
+async fn stop_session_recording(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    path: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let recording = state
+        .session_recording
+        .lock()
+        .take()
+        .ok_or_else(|| "세션 녹화 중이 아닙니다.".to_string())?;
+    info!("세션 녹화 중지: {}프레임", recording.frames.len());
+    if recording.frames.is_empty() {
+        return Err("녹화된 키포인트가 없습니다. 모니터링 중에 녹화해주세요.".to_string());
+    }
+
+    let gif_path = match path {
+        Some(path) => std::path::PathBuf::from(path),
+        None => {
+            let record_dir = handle
+                .path()
+                .app_data_dir()
+                .map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?
+                .join("session_recordings");
+            fs::create_dir_all(&record_dir).map_err(|e| format!("녹화 디렉토리 생성 실패: {}", e))?;
+            record_dir.join(format!("session-{}.gif", chrono::Local::now().format("%Y%m%d-%H%M%S")))
+        }
+    };
+    let keypoints_path = gif_path.with_extension("json");
+    let duration_secs = recording
+        .frames
+        .last()
+        .and_then(|frame| frame.get("timestamp_ms"))
+        .and_then(|v| v.as_i64())
+        .map_or(0, |last_ms| (last_ms - recording.started_at_ms) / 1000);
+
+    let keypoints_json = serde_json::to_string(&serde_json::json!({
+        "started_at_ms": recording.started_at_ms,
+        "skeleton": COCO_SKELETON,
+        "frames": recording.frames,
+    }))
+    .map_err(|e| e.to_string())?;
+    fs::write(&keypoints_path, keypoints_json).map_err(|e| format!("키포인트 파일 쓰기 실패: {}", e))?;
+
+    let frame_count = recording.frames.len();
+    let truncated = recording.truncated;
+    let render_path = gif_path.clone();
+    tauri::async_runtime::spawn_blocking(move || render_skeleton_gif(&recording.frames, &render_path))
+        .await
+        .map_err(|e| e.to_string())??;
+    info!("세션 녹화 저장 완료: {:?}", gif_path);
+
+    Ok(serde_json::json!({
+        "path": gif_path.to_string_lossy(),
+        "keypoints_path": keypoints_path.to_string_lossy(),
+        "frame_count": frame_count,
+        "duration_secs": duration_secs,
+        "truncated": truncated,
+    }))
+}
+
+// 세션 녹화 중이면 가장 최근 분석의 키포인트를 프레임으로 추가 (직전 결과를 재사용한 같은 프레임은 건너뜀)
+fn record_session_frame(state: &AppState) {
+    let mut recording = state.session_recording.lock();
+    let Some(recording) = recording.as_mut() else {
+        return;
+    };
+    let Some(frame) = state.pose_analyzer.latest_pose_coco() else {
+        return;
+    };
+    let timestamp = |frame: &serde_json::Value| frame.get("timestamp_ms").and_then(|v| v.as_i64());
+    if recording.frames.last().and_then(timestamp) == timestamp(&frame) {
+        return;
+    }
+    if recording.frames.len() >= MAX_SESSION_RECORDING_FRAMES {
+        if !recording.truncated {
+            warn!("세션 녹화 프레임이 {}개를 넘어 이후 프레임은 저장하지 않음", MAX_SESSION_RECORDING_FRAMES);
+            recording.truncated = true;
+        }
+        return;
+    }
+    recording.frames.push(frame);
+}
+
+// 키포인트 프레임을 어두운 배경 위의 스켈레톤만 그린 GIF 애니메이션으로 저장
+fn render_skeleton_gif(frames: &[serde_json::Value], path: &std::path::Path) -> Result<(), String> {
+    use image::codecs::gif::{GifEncoder, Repeat};
+    use image::{Delay, Frame, Rgba, RgbaImage};
+    use imageproc::drawing::{draw_filled_circle_mut, draw_line_segment_mut};
+
+    let file = fs::File::create(path).map_err(|e| format!("애니메이션 파일 생성 실패: {}", e))?;
+    let mut encoder = GifEncoder::new_with_speed(std::io::BufWriter::new(file), 10);
+    encoder
+        .set_repeat(Repeat::Infinite)
+        .map_err(|e| format!("애니메이션 설정 실패: {}", e))?;
+    let background = Rgba([24, 24, 24, 255]);
+    let bone_color = Rgba([80, 200, 120, 255]);
+    let joint_color = Rgba([240, 240, 240, 255]);
+
+    for frame in frames {
+        let width = frame.get("image_width").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+        let height = frame.get("image_height").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+        let keypoints: Vec<f32> = frame
+            .get("keypoints")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default();
+        let mut canvas = RgbaImage::from_pixel(SESSION_RECORDING_WIDTH, SESSION_RECORDING_HEIGHT, background);
+        if width > 0.0 && height > 0.0 {
+            // 비율을 유지한 채 캔버스 가운데에 맞춤
+            let scale = (SESSION_RECORDING_WIDTH as f32 / width).min(SESSION_RECORDING_HEIGHT as f32 / height);
+            let offset_x = (SESSION_RECORDING_WIDTH as f32 - width * scale) / 2.0;
+            let offset_y = (SESSION_RECORDING_HEIGHT as f32 - height * scale) / 2.0;
+            // 가시성 플래그가 0인 키포인트는 그리지 않음
+            let points: Vec<Option<(f32, f32)>> = keypoints
+                .chunks_exact(3)
+                .map(|point| {
+                    (point[2] > 0.0).then(|| (offset_x + point[0] * scale, offset_y + point[1] * scale))
+                })
+                .collect();
+            for &(from, to) in COCO_SKELETON.iter() {
+                if let (Some(Some(start)), Some(Some(end))) = (points.get(from), points.get(to)) {
+                    draw_line_segment_mut(&mut canvas, *start, *end, bone_color);
+                }
+            }
+            for &(x, y) in points.iter().flatten() {
+                draw_filled_circle_mut(&mut canvas, (x.round() as i32, y.round() as i32), 3, joint_color);
+            }
+        }
+        encoder
+            .encode_frame(Frame::from_parts(
+                canvas,
+                0,
+                0,
+                Delay::from_numer_denom_ms(SESSION_RECORDING_FRAME_DELAY_MS, 1),
+            ))
+            .map_err(|e| format!("애니메이션 프레임 저장 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+// 버그 리포트용으로 앱/OS 버전, 카메라와 모델 정보, 분석기 디버그 상태, 최근 로그, 설정, 캘리브레이션 상태를
+// 하나의 JSON 파일로 저장합니다. 이미지는 include_images가 true일 때만 캘리브레이션 이미지를 base64로 넣습니다.
+#[tauri::command]
+async fn generate_support_bundle(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    path: String,
+    log_lines: Option<usize>,
+    include_images: Option<bool>,
+) -> Result<String, String> {
+    let log_lines = log_lines
+        .unwrap_or(DEFAULT_SUPPORT_BUNDLE_LOG_LINES)
+        .min(MAX_SUPPORT_BUNDLE_LOG_LINES);
+    let store = handle
+        .store(SETTINGS_STORE)
+        .map_err(|e| format!("설정 저장소 열기 실패: {}", e))?;
+    let settings: serde_json::Map<String, serde_json::Value> = store
+        .entries()
+        .into_iter()
+        .map(|(key, value)| {
+            if REDACTED_SETTING_KEYS.contains(&key.as_str()) && !value.is_null() {
+                (key, serde_json::json!("<redacted>"))
+            } else {
+                (key, value)
+            }
+        })
+        .collect();
+
+    let camera = {
+        let camera_lock = state.camera.lock();
+        serde_json::json!({
+            "selected_index": *state.selected_camera_index.lock(),
+            "open": camera_lock.is_some(),
+            "name": camera_lock.as_ref().map(|cam| cam.info().human_name()),
+            "source": state
+                .active_camera_source
+                .lock()
+                .as_ref()
+                .map(|(index, format)| format!("{} {:?}", index, format)),
+            "backend": state
+                .preferred_camera_backend
+                .lock()
+                .as_ref()
+                .map(|backend| format!("{:?}", backend)),
+            "no_camera": *state.no_camera.lock(),
+        })
+    };
+
+    let calibration_image = if include_images.unwrap_or(false) {
+        load_setting::<String>(&handle, "calibratedImagePath")
+            .and_then(|image_path| fs::read(image_path).ok())
+            .map(|bytes| STANDARD.encode(bytes))
+    } else {
+        None
+    };
+
+    let bundle = serde_json::json!({
+        "generated_at": chrono::Utc::now().timestamp(),
+        "app": {
+            "name": handle.package_info().name,
+            "version": handle.package_info().version.to_string(),
+            "analysis_schema_version": ANALYSIS_SCHEMA_VERSION,
+        },
+        "os": {
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+            "version": sysinfo::System::long_os_version(),
+        },
+        "camera": camera,
+        "model": model_info_json(&state),
+        "debug_state": debug_state_json(&state),
+        "calibration": calibration_status_json(&state),
+        "settings": settings,
+        "logs": read_recent_log_lines(&handle, log_lines),
+        "calibration_image": calibration_image,
+    });
+    let json_str = serde_json::to_string_pretty(&bundle).map_err(|e| e.to_string())?;
+    fs::write(&path, json_str).map_err(|e| format!("지원 번들 파일 쓰기 실패: {}", e))?;
+    info!("지원 번들 생성 완료: {}", path);
+    Ok(path)
+}
+
+// 로그 디렉토리에서 가장 최근에 수정된 .log 파일의 마지막 max_lines 줄 (파일이 없으면 빈 목록)
+fn read_recent_log_lines(app_handle: &AppHandle, max_lines: usize) -> Vec<String> {
+    let Ok(log_dir) = app_handle.path().app_log_dir() else {
+        return Vec::new();
+    };
+    let latest_log = fs::read_dir(&log_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "log"))
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok());
+    let Some(contents) = latest_log.and_then(|entry| fs::read_to_string(entry.path()).ok()) else {
+        return Vec::new();
+    };
+    let lines: Vec<&str> = contents.lines().collect();
+    lines[lines.len().saturating_sub(max_lines)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+// export_settings로 만든 백업 파일에서 설정과 기준 자세를 복원하고 즉시 적용합니다.
+#[tauri::command]
+async fn import_settings(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    path: String,
+) -> Result<serde_json::Value, String> {
+    let json_str = fs::read_to_string(&path).map_err(|e| format!("설정 백업 파일 읽기 실패: {}", e))?;
+    let backup: serde_json::Value =
+        serde_json::from_str(&json_str).map_err(|e| format!("설정 백업 파일 형식이 올바르지 않습니다: {}", e))?;
+    let version = backup.get("schema_version").and_then(|v| v.as_u64());
+    if version != Some(SETTINGS_BACKUP_VERSION as u64) {
+        return Err(format!(
+            "호환되지 않는 설정 백업 파일입니다. (파일 버전: {:?}, 지원 버전: {})",
+            version, SETTINGS_BACKUP_VERSION
+        ));
+    }
+    let settings = backup
+        .get("settings")
+        .and_then(|v| v.as_object())
+        .ok_or_else(|| "설정 백업 파일에 settings 항목이 없습니다.".to_string())?;
+
+    // 쓰기 전에 전부 검증: 종류가 맞지 않는 설정 값이나 잘못된 기준 자세가 있으면 아무것도 복원하지 않음
+    let bad_keys: Vec<&str> = settings
+        .iter()
+        .filter(|(key, value)| {
+            SETTING_VALUE_KINDS
+                .iter()
+                .any(|(known, kind)| *known == key.as_str() && !kind.matches(value))
+        })
+        .map(|(key, _)| key.as_str())
+        .collect();
+    if !bad_keys.is_empty() {
+        return Err(format!("설정 백업 파일의 값 형식이 올바르지 않습니다: {}", bad_keys.join(", ")));
+    }
+    // 캘리브레이션하지 않은 기기에서 만든 백업이면 기준 자세는 건너뛰어 현재 기준 자세를 유지
+    let baseline = backup
+        .get("baseline")
+        .filter(|baseline| baseline.is_object() && PoseAnalyzer::baseline_json_has_ratios(baseline));
+    if let Some(baseline) = baseline {
+        PoseAnalyzer::validate_baseline_json(baseline)
+            .map_err(|e| format!("설정 백업 파일의 기준 자세가 올바르지 않습니다: {}", e))?;
+    }
+
+    let store = handle
+        .store(SETTINGS_STORE)
+        .map_err(|e| format!("설정 저장소 열기 실패: {}", e))?;
+    let mut restored = 0;
+    for (key, value) in settings {
+        if NON_PORTABLE_SETTING_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        store.set(key.clone(), value.clone());
+        restored += 1;
+    }
+    store.save().map_err(|e| format!("설정 저장 실패: {}", e))?;
+
+    let baseline_restored = match baseline {
+        Some(baseline) => {
+            state.pose_analyzer.apply_baseline_json(baseline);
+            state
+                .pose_analyzer
+                .save_baseline_to_file(&handle)
+                .map_err(|e| format!("기준 자세 저장 실패: {}", e))?;
+            clear_active_profile(&handle);
+            true
+        }
+        None => false,
+    };
+
+    reload_settings(&handle, &state);
+    let result = serde_json::json!({
+        "settings_restored": restored,
+        "baseline_restored": baseline_restored,
+    });
+    let _ = handle.emit("settings-imported", &result);
+    info!("설정 가져오기 완료: {}", path);
+    Ok(result)
+}
+
+// 기준 자세와 감지에 영향을 주는 설정을 한 줄 문자열로 내보냅니다. 버그 리포트에 붙여 넣어 같은 감지 동작을 재현하는 용도입니다.
+#[tauri::command]
+fn export_calibration_string(state: State<'_, AppState>) -> Result<String, String> {
+    let analyzer = &state.pose_analyzer;
+    let (turtle_neck_thresholds, shoulder_alignment_thresholds) = analyzer.detection_thresholds();
+    let payload = serde_json::json!({
+        "baseline": analyzer.baseline_to_json(),
+        "turtle_neck_thresholds": turtle_neck_thresholds,
+        "shoulder_alignment_thresholds": shoulder_alignment_thresholds,
+        "confidence_threshold": analyzer.confidence_threshold(),
+        "score_weights": analyzer.score_weights(),
+        "detector_toggles": analyzer.detector_toggles(),
+    });
+    let json_str = serde_json::to_string(&payload).map_err(|e| e.to_string())?;
+    Ok(format!("pn{}:{}", CALIBRATION_STRING_VERSION, STANDARD.encode(json_str)))
+}
+
+// export_calibration_string으로 만든 문자열에서 기준 자세와 감지 설정을 복원합니다.
+// 문자열 전체를 먼저 검증하고, 하나라도 올바르지 않으면 아무것도 적용하지 않습니다.
+// 감지 강도 원시값을 포함한 모든 항목이 저장되어 재시작 후에도 유지됩니다.
+#[tauri::command]
+async fn import_calibration_string(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    code: String,
+) -> Result<(), String> {
+    ensure_no_sensitivity_check(&state)?;
+    let code = code.trim();
+    let (version, encoded) = code
+        .strip_prefix("pn")
+        .and_then(|rest| rest.split_once(':'))
+        .ok_or_else(|| "캘리브레이션 문자열 형식이 올바르지 않습니다.".to_string())?;
+    if version.parse::<u32>().ok() != Some(CALIBRATION_STRING_VERSION) {
+        return Err(format!(
+            "호환되지 않는 캘리브레이션 문자열입니다. (문자열 버전: {}, 지원 버전: {})",
+            version, CALIBRATION_STRING_VERSION
+        ));
+    }
+    let json_bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| format!("캘리브레이션 문자열 디코딩 실패: {}", e))?;
+    let payload: serde_json::Value = serde_json::from_slice(&json_bytes)
+        .map_err(|e| format!("캘리브레이션 문자열 내용이 올바르지 않습니다: {}", e))?;
+    let baseline = payload
+        .get("baseline")
+        .filter(|v| v.is_object())
+        .ok_or_else(|| "캘리브레이션 문자열에 기준 자세가 없습니다.".to_string())?;
+
+    PoseAnalyzer::validate_baseline_json(baseline)
+        .map_err(|e| format!("캘리브레이션 문자열의 기준 자세가 올바르지 않습니다: {}", e))?;
+
+    // 선택 항목: 없으면 건너뛰지만, 있는데 형식이 틀리면 전체를 거부
+    fn optional_field<T: serde::de::DeserializeOwned>(
+        payload: &serde_json::Value,
+        key: &str,
+    ) -> Result<Option<T>, String> {
+        match payload.get(key) {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(value) => serde_json::from_value(value.clone())
+                .map(Some)
+                .map_err(|e| format!("캘리브레이션 문자열의 {} 값이 올바르지 않습니다: {}", key, e)),
+        }
+    }
+    let thresholds = match (
+        optional_field::<(f32, f32)>(&payload, "turtle_neck_thresholds")?,
+        optional_field::<(f32, f32)>(&payload, "shoulder_alignment_thresholds")?,
+    ) {
+        (Some(turtle_neck), Some(shoulder_alignment)) => {
+            PoseAnalyzer::validate_detection_thresholds(turtle_neck, shoulder_alignment)
+                .map_err(|e| format!("캘리브레이션 문자열의 감지 강도가 올바르지 않습니다: {}", e))?;
+            Some(DetectionThresholds { turtle_neck, shoulder_alignment })
+        }
+        (None, None) => None,
+        _ => return Err("캘리브레이션 문자열에 감지 강도가 일부만 들어 있습니다.".to_string()),
+    };
+    let confidence_threshold = optional_field::<f32>(&payload, "confidence_threshold")?;
+    if confidence_threshold.is_some_and(|threshold| !threshold.is_finite()) {
+        return Err("캘리브레이션 문자열의 confidence_threshold 값이 올바르지 않습니다.".to_string());
+    }
+    let score_weights = optional_field::<ScoreWeights>(&payload, "score_weights")?;
+    let detector_toggles = optional_field::<DetectorToggles>(&payload, "detector_toggles")?;
+
+    // 검증 통과 후 적용 및 저장
+    let analyzer = &state.pose_analyzer;
+    analyzer.apply_baseline_json(baseline);
+    analyzer
+        .save_baseline_to_file(&handle)
+        .map_err(|e| format!("기준 자세 저장 실패: {}", e))?;
+    if let Some(saved) = thresholds {
+        analyzer.set_detection_thresholds(saved.turtle_neck, saved.shoulder_alignment);
+        save_setting(&handle, "detectionThresholds", Some(saved));
+    }
+    if let Some(threshold) = confidence_threshold {
+        let applied = analyzer.set_confidence_threshold(threshold);
+        save_setting(&handle, "confidenceThreshold", applied);
+    }
+    if let Some(weights) = score_weights {
+        analyzer.set_score_weights(weights);
+        save_setting(&handle, "scoreWeights", analyzer.score_weights());
+    }
+    if let Some(toggles) = detector_toggles {
+        analyzer.set_detector_toggles(toggles);
+        save_setting(&handle, "detectorToggles", toggles);
+    }
+    clear_active_profile(&handle);
+    analyzer.clear_recent_results();
+    info!("캘리브레이션 문자열 가져오기 완료");
+    Ok(())
+}
+
+// 캘리브레이션 프로필 저장 파일 (이름 → 기준 자세와 감지 강도 묶음)
+fn calibration_profiles_path(app_handle: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_path = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?;
+    Ok(app_data_path.join("calibration_profiles.json"))
+}
+
+fn load_calibration_profiles(app_handle: &AppHandle) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let path = calibration_profiles_path(app_handle)?;
+    if !path.exists() {
+        return Ok(serde_json::Map::new());
+    }
+    let json_str = fs::read_to_string(&path).map_err(|e| format!("프로필 파일을 읽을 수 없습니다: {}", e))?;
+    serde_json::from_str(&json_str).map_err(|e| format!("프로필 파일 형식이 올바르지 않습니다: {}", e))
+}
+
+fn save_calibration_profiles(
+    app_handle: &AppHandle,
+    profiles: &serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    let path = calibration_profiles_path(app_handle)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("앱 데이터 디렉토리 생성 실패: {}", e))?;
+    }
+    let json_str = serde_json::to_string_pretty(profiles).map_err(|e| e.to_string())?;
+    fs::write(&path, json_str).map_err(|e| format!("프로필 파일 저장 실패: {}", e))
+}
+
+// 현재 기준 자세와 감지 강도(거북목/어깨 원시값, 알림/해제 감지 횟수)를 이름 붙인 프로필로 저장합니다.
+// 같은 이름이 있으면 덮어쓰며, 집/사무실처럼 조명이 다른 환경을 한 번에 전환하는 용도입니다.
+#[tauri::command]
+async fn save_calibration_profile(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+    name: String,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("프로필 이름을 입력해주세요.".to_string());
+    }
+    if state.sensitivity_check.lock().is_some() {
+        return Err("감도 확인 모드 중에는 프로필을 저장할 수 없습니다.".to_string());
+    }
+    let analyzer = &state.pose_analyzer;
+    if !analyzer.is_calibrated() {
+        return Err("저장할 기준 자세가 없습니다. 먼저 캘리브레이션을 진행해주세요.".to_string());
+    }
+    let (turtle_neck_thresholds, shoulder_alignment_thresholds) = analyzer.detection_thresholds();
+    let mut profiles = load_calibration_profiles(&handle)?;
+    profiles.insert(
+        name.clone(),
+        serde_json::json!({
+            "baseline": analyzer.baseline_to_json(),
+            "turtle_neck_thresholds": turtle_neck_thresholds,
+            "shoulder_alignment_thresholds": shoulder_alignment_thresholds,
+            "required_detection_count": analyzer.required_detection_count(),
+            "release_threshold_count": analyzer.release_threshold_count(),
+            "saved_at": chrono::Utc::now().timestamp(),
+        }),
+    );
+    save_calibration_profiles(&handle, &profiles)?;
+    save_setting(&handle, "activeCalibrationProfile", &name);
+    info!("캘리브레이션 프로필 저장: {}", name);
+    Ok(())
+}
+
+// 프로필의 기준 자세와 감지 강도를 함께 실시간 분석기에 적용합니다. (진행 중인 감도 확인 모드는 종료)
+#[tauri::command]
+async fn activate_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<serde_json::Value, String> {
+    let profiles = load_calibration_profiles(&app)?;
+    let profile = profiles
+        .get(&name)
+        .ok_or_else(|| format!("프로필을 찾을 수 없습니다: {}", name))?;
+    let baseline = profile
+        .get("baseline")
+        .filter(|v| v.is_object())
+        .ok_or_else(|| format!("프로필에 기준 자세가 없습니다: {}", name))?;
+
+    end_sensitivity_check(&app, &state);
+    let analyzer = &state.pose_analyzer;
+    apply_profile_detection_settings(analyzer, profile);
+    analyzer.apply_baseline_json(baseline);
+    analyzer.clear_recent_results();
+    analyzer
+        .save_baseline_to_file(&app)
+        .map_err(|e| format!("기준 자세 저장 실패: {}", e))?;
+    save_setting(&app, "activeCalibrationProfile", &name);
+    save_setting(&app, "detectionThresholds", None::<DetectionThresholds>);
+    save_setting(&app, "detectionCounts", None::<DetectionCounts>);
+    info!("캘리브레이션 프로필 적용: {}", name);
+    let _ = app.emit("calibration-profile-activated", &serde_json::json!({ "name": name }));
+    Ok(profile.clone())
+}
+
+// 프로필에 저장된 감지 강도와 감지 횟수 기준을 분석기에 적용합니다. (기준 자세는 제외)
+fn apply_profile_detection_settings(analyzer: &PoseAnalyzer, profile: &serde_json::Value) {
+    let thresholds = |key: &str| {
+        profile
+            .get(key)
+            .and_then(|v| serde_json::from_value::<(f32, f32)>(v.clone()).ok())
+    };
+    if let (Some(turtle_neck), Some(shoulder_alignment)) =
+        (thresholds("turtle_neck_thresholds"), thresholds("shoulder_alignment_thresholds"))
+    {
+        analyzer.set_detection_thresholds(turtle_neck, shoulder_alignment);
+    }
+    let count = |key: &str| profile.get(key).and_then(|v| v.as_u64()).map(|v| v as usize);
+    if let (Some(on_count), Some(off_count)) =
+        (count("required_detection_count"), count("release_threshold_count"))
+    {
+        if let Err(e) = analyzer.set_detection_hysteresis(on_count, off_count) {
+            warn!("프로필 감지 횟수 기준 적용 실패: {}", e);
+        }
+    }
+}
+
+// 기준 자세를 새로 측정하거나 가져와 교체하면 더 이상 저장된 프로필을 적용 중인 상태가 아님
+// (그대로 두면 다음 시작 시 프로필의 감지 기준이 새 기준 자세 위에 다시 적용됨)
+fn clear_active_profile(app_handle: &AppHandle) {
+    save_setting(app_handle, "activeCalibrationProfile", None::<String>);
+}
+
+// 앱 시작 시(와 같은 감지 단계가 다시 전송될 때) 마지막으로 적용한 프로필의 감지 강도와 감지 횟수 기준을 다시 적용합니다.
+// 기준 자세는 baseline.json에서 따로 불러오므로 여기서는 적용하지 않습니다.
+fn reapply_active_profile(app_handle: &AppHandle, state: &AppState) {
+    let Some(name) = load_setting::<String>(app_handle, "activeCalibrationProfile") else {
+        return;
+    };
+    let profiles = match load_calibration_profiles(app_handle) {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            warn!("캘리브레이션 프로필을 불러오지 못했습니다: {}", e);
+            return;
+        }
+    };
+    match profiles.get(&name) {
+        Some(profile) => {
+            apply_profile_detection_settings(&state.pose_analyzer, profile);
+            info!("적용 중인 캘리브레이션 프로필 감지 기준 복원: {}", name);
+        }
+        None => warn!("적용 중인 캘리브레이션 프로필을 찾을 수 없습니다: {}", name),
+    }
+}
+
+// 저장된 캘리브레이션 프로필 목록과 현재 적용 중인 프로필 이름을 반환합니다.
+#[tauri::command]
+fn list_calibration_profiles(handle: tauri::AppHandle) -> Result<serde_json::Value, String> {
+    let profiles = load_calibration_profiles(&handle)?;
+    let active: Option<String> = load_setting(&handle, "activeCalibrationProfile");
+    Ok(serde_json::json!({
+        "active": active.filter(|name| profiles.contains_key(name)),
+        "profiles": profiles
+            .iter()
+            .map(|(name, profile)| serde_json::json!({
+                "name": name,
+                "saved_at": profile.get("saved_at"),
+                "turtle_neck_thresholds": profile.get("turtle_neck_thresholds"),
+                "shoulder_alignment_thresholds": profile.get("shoulder_alignment_thresholds"),
+                "required_detection_count": profile.get("required_detection_count"),
+            }))
+            .collect::<Vec<_>>(),
+    }))
+}
+
+#[tauri::command]
+fn delete_calibration_profile(handle: tauri::AppHandle, name: String) -> Result<bool, String> {
+    let mut profiles = load_calibration_profiles(&handle)?;
+    if profiles.remove(&name).is_none() {
+        return Ok(false);
+    }
+    save_calibration_profiles(&handle, &profiles)?;
+    info!("캘리브레이션 프로필 삭제: {}", name);
+    Ok(true)
+}
+
+// 주간 리포트 발송 요일(0=월요일 ~ 6=일요일)과 시각(0~23) 설정
+#[tauri::command]
+async fn set_weekly_report_schedule(
+    handle: tauri::AppHandle,
+    weekday: u8,
+    hour: u8,
+) -> Result<(), String> {
+    if weekday > 6 || hour > 23 {
+        return Err("잘못된 주간 리포트 일정입니다.".to_string());
+    }
+    save_setting(&handle, "weeklyReportDay", weekday);
+    save_setting(&handle, "weeklyReportHour", hour);
+    info!("주간 리포트 일정 변경: 요일 {}, {}시", weekday, hour);
+    Ok(())
+}
+
+#[tauri::command]
+async fn restart_app(app: tauri::AppHandle) -> Result<(), String> {
+    info!("앱 재시작 요청");
+    // 현재 실행 파일 경로 가져오기
+    if let Ok(exe_path) = std::env::current_exe() {
+        let exe_dir = exe_path.parent().unwrap_or(&exe_path);
+        let exe_name = exe_path.file_name().unwrap_or_default().to_string_lossy();
+
+        // 새 프로세스로 앱 재시작
+        let _ = std::process::Command::new(&exe_path)
+            .current_dir(exe_dir)
+            .spawn();
+
+        // 현재 앱 종료
+        app.exit(0);
+    } else {
+        return Err("실행 파일 경로를 찾을 수 없습니다.".to_string());
+    }
+    Ok(())
+}
+
+// --- Settings Helpers ---
+
+fn load_setting<T: serde::de::DeserializeOwned>(app_handle: &AppHandle, key: &str) -> Option<T> {
+    let store = app_handle.store(SETTINGS_STORE).ok()?;
+    store.get(key).and_then(|value| serde_json::from_value(value).ok())
+}
+
+fn save_setting<T: serde::Serialize>(app_handle: &AppHandle, key: &str, value: T) {
+    let store = match app_handle.store(SETTINGS_STORE) {
+        Ok(store) => store,
+        Err(e) => {
+            error!("설정 저장소 열기 실패: {}", e);
+            return;
+        }
+    };
+    match serde_json::to_value(value) {
+        Ok(json_value) => store.set(key, json_value),
+        Err(e) => {
+            error!("설정 '{}' 직렬화 실패: {}", key, e);
+            return;
+        }
+    }
+    if let Err(e) = store.save() {
+        error!("설정 저장 실패: {}", e);
+    }
+}
+
+// 설정 저장소 파일을 검사해 읽을 수 없으면 백업한 뒤 비우고(기본값으로 초기화), check_entries이면
+// 백엔드가 읽는 키 중 값 종류가 맞지 않는 항목도 지웁니다. 반환값: (파일 손상 여부, 백업 경로, 초기화한 키)
+fn repair_settings_store(
+    app_handle: &AppHandle,
+    check_entries: bool,
+) -> Result<(bool, Option<std::path::PathBuf>, Vec<String>), String> {
+    let path = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?
+        .join(SETTINGS_STORE);
+    if !path.exists() {
+        return Ok((false, None, Vec::new()));
+    }
+    let contents = fs::read(&path).map_err(|e| format!("설정 파일을 읽을 수 없습니다: {}", e))?;
+    let parsed = serde_json::from_slice::<serde_json::Map<String, serde_json::Value>>(&contents);
+    let bad_keys: Vec<String> = match &parsed {
+        Ok(entries) if check_entries => SETTING_VALUE_KINDS
+            .iter()
+            .filter(|(key, kind)| entries.get(*key).is_some_and(|value| !kind.matches(value)))
+            .map(|(key, _)| key.to_string())
+            .collect(),
+        _ => Vec::new(),
+    };
+    let file_corrupt = parsed.is_err();
+    if !file_corrupt && bad_keys.is_empty() {
+        return Ok((false, None, Vec::new()));
+    }
+
+    let backup_path =
+        path.with_file_name(format!("{}.corrupt-{}", SETTINGS_STORE, chrono::Utc::now().timestamp()));
+    fs::copy(&path, &backup_path).map_err(|e| format!("손상된 설정 파일 백업 실패: {}", e))?;
+    warn!("손상된 설정 파일 백업: {:?}", backup_path);
+
+    let reset_keys = if file_corrupt {
+        // 저장소가 열리지 않으면 파일을 직접 빈 설정으로 덮어씀
+        match app_handle.store(SETTINGS_STORE) {
+            Ok(store) => {
+                store.clear();
+                store.save().map_err(|e| format!("설정 저장 실패: {}", e))?;
+            }
+            Err(_) => fs::write(&path, "{}").map_err(|e| format!("설정 파일 초기화 실패: {}", e))?,
+        }
+        error!("설정 파일을 읽을 수 없어 기본값으로 초기화했습니다");
+        vec!["*".to_string()]
+    } else {
+        let store = app_handle
+            .store(SETTINGS_STORE)
+            .map_err(|e| format!("설정 저장소 열기 실패: {}", e))?;
+        for key in &bad_keys {
+            store.delete(key);
+        }
+        store.save().map_err(|e| format!("설정 저장 실패: {}", e))?;
+        warn!("값이 올바르지 않은 설정 초기화: {:?}", bad_keys);
+        bad_keys
+    };
+    Ok((file_corrupt, Some(backup_path), reset_keys))
+}
+
+// 설정 저장소를 검사해 손상된 파일이나 값 종류가 맞지 않는 항목을 백업 후 기본값으로 되돌리고 다시 적용합니다.
+// reset_keys가 ["*"]이면 파일 전체를 읽을 수 없어 모든 설정을 초기화한 것입니다.
+#[tauri::command]
+async fn repair_settings(
+    state: State<'_, AppState>,
+    handle: tauri::AppHandle,
+) -> Result<serde_json::Value, String> {
+    let (file_corrupt, backup_path, reset_keys) = repair_settings_store(&handle, true)?;
+    if !reset_keys.is_empty() {
+        reload_settings(&handle, &state);
+    }
+    Ok(serde_json::json!({
+        "file_corrupt": file_corrupt,
+        "backup_path": backup_path.map(|p| p.to_string_lossy().into_owned()),
+        "reset_keys": reset_keys,
+    }))
+}
+
+// 저장소에 보관된 설정을 AppState와 분석기에 적용합니다. (시작 시, 설정 가져오기 후)
+fn reload_settings(app_handle: &AppHandle, state: &AppState) {
+    *state.current_language.lock() = load_language_setting(app_handle, &state.translations);
+    *state.privacy_mode.lock() = load_setting(app_handle, "privacyMode").unwrap_or(false);
+    *state.logging_enabled.lock() = load_setting(app_handle, "loggingEnabled").unwrap_or(true);
+    if let Some(level) = load_setting::<u8>(app_handle, "onnxOptimizationLevel") {
+        if level != state.pose_analyzer.optimization_level() {
+            if let Err(e) = state.pose_analyzer.set_optimization_level(level) {
+                error!("저장된 최적화 수준 적용 실패: {}", e);
+            }
+        }
+    }
+    if let Some(weights) = load_setting::<ScoreWeights>(app_handle, "scoreWeights") {
+        state.pose_analyzer.set_score_weights(weights);
+    }
+    state
+        .pose_analyzer
+        .set_detector_toggles(load_setting(app_handle, "detectorToggles").unwrap_or_default());
+    let max_dimension =
+        load_setting::<u32>(app_handle, "maxFrameDimension").unwrap_or(DEFAULT_MAX_FRAME_DIMENSION);
+    if let Err(e) = state.pose_analyzer.set_max_frame_dimension(max_dimension) {
+        error!("저장된 최대 프레임 크기 적용 실패: {}", e);
+    }
+    let input_size =
+        load_setting::<u32>(app_handle, "modelInputSize").unwrap_or(DEFAULT_MODEL_INPUT_SIZE);
+    if let Err(e) = state.pose_analyzer.set_model_input_size(input_size) {
+        error!("저장된 모델 입력 크기 적용 실패: {}", e);
+    }
+    *state.low_light_threshold.lock() =
+        load_setting(app_handle, "lowLightThreshold").unwrap_or(DEFAULT_LOW_LIGHT_THRESHOLD);
+    *state.image_quality.lock() = load_setting::<u8>(app_handle, "imageQuality")
+        .unwrap_or(DEFAULT_IMAGE_QUALITY)
+        .clamp(1, 100);
+    *state.sitting_limit_mins.lock() = load_setting::<u64>(app_handle, "sittingLimitMins")
+        .unwrap_or(DEFAULT_SITTING_LIMIT_MINS)
+        .min(MAX_SITTING_LIMIT_MINS);
+    *state.sitting_grace_secs.lock() = load_setting::<u64>(app_handle, "sittingGraceSecs")
+        .unwrap_or(DEFAULT_SITTING_GRACE_SECS)
+        .clamp(1, MAX_SITTING_GRACE_SECS);
+    *state.startup_grace_secs.lock() = load_setting::<u64>(app_handle, "startupGraceSecs")
+        .unwrap_or(DEFAULT_STARTUP_GRACE_SECS)
+        .min(MAX_STARTUP_GRACE_SECS);
+    *state.camera_warmup_ms.lock() = (
+        load_setting::<u64>(app_handle, "cameraWarmupMs")
+            .unwrap_or(DEFAULT_CAMERA_WARMUP_MS)
+            .min(MAX_CAMERA_WARMUP_MS),
+        load_setting::<u64>(app_handle, "cameraDropDelayMs")
+            .unwrap_or(DEFAULT_CAMERA_DROP_DELAY_MS)
+            .min(MAX_CAMERA_DROP_DELAY_MS),
+    );
+    *state.alert_tiers.lock() = load_setting(app_handle, "alertTiers").unwrap_or_default();
+    *state.low_power_inference.lock() = load_setting(app_handle, "lowPowerInference").unwrap_or(false);
+    *state.webhook_url.lock() = load_setting::<Option<String>>(app_handle, "webhookUrl").flatten();
+    if let Some(indices) = load_setting::<Vec<u32>>(app_handle, "cameraPool") {
+        let keep_open = load_setting(app_handle, "cameraPoolKeepOpen").unwrap_or(false);
+        apply_camera_pool(state, indices, keep_open);
+    }
+    if let Some(normalization) = load_setting::<String>(app_handle, "imageNormalization")
+        .and_then(|mode| ImageNormalization::from_name(&mode))
+    {
+        state.pose_analyzer.set_image_normalization(normalization);
+    }
+    if let Some(filter) = load_setting::<String>(app_handle, "resizeFilter")
+        .and_then(|filter| ResizeFilter::from_name(&filter))
+    {
+        state.pose_analyzer.set_resize_filter(filter);
+    }
+    if let Some(fraction) = load_setting::<f32>(app_handle, "minDetectionRegion") {
+        if let Err(e) = state.pose_analyzer.set_min_detection_area(fraction) {
+            warn!("저장된 최소 감지 영역을 적용하지 못했습니다: {}", e);
+        }
+    }
+    state.pose_analyzer.set_confidence_threshold(
+        load_setting(app_handle, "confidenceThreshold").unwrap_or(DEFAULT_CONFIDENCE_THRESHOLD),
+    );
+    *state.motion_gating_threshold.lock() = load_setting::<bool>(app_handle, "motionGating")
+        .unwrap_or(false)
+        .then(|| load_setting(app_handle, "motionGatingThreshold").unwrap_or(DEFAULT_MOTION_GATING_THRESHOLD));
+    *state.recalibration_nudge.lock() = load_setting(app_handle, "recalibrationNudge").unwrap_or(true);
+    *state.current_activity.lock() = load_setting::<Option<String>>(app_handle, "currentActivity").flatten();
+    apply_low_power_inference(state);
+    let rotation = load_setting::<u16>(app_handle, "cameraRotation").unwrap_or(0);
+    if let Err(e) = state.pose_analyzer.set_camera_rotation(rotation) {
+        error!("저장된 카메라 회전 각도 적용 실패: {}", e);
+    }
+    apply_saved_detection_thresholds(app_handle, &state.pose_analyzer);
+    apply_saved_detection_counts(app_handle, &state.pose_analyzer);
+    reapply_active_profile(app_handle, state);
+    apply_metrics_server_setting(app_handle, state);
+    info!("저장된 설정 적용 완료");
+}
+
+// 저장된 언어를 불러옵니다. 처음 실행이면 시스템 로케일로 정하고 저장합니다.
+fn load_language_setting(app_handle: &AppHandle, translations: &Translations) -> String {
+    if let Some(lang) = load_setting::<String>(app_handle, "currentLanguage")
+        .as_deref()
+        .and_then(|lang| translations.resolve_language(lang))
+    {
+        return lang;
+    }
+    let system_locale = tauri_plugin_os::locale();
+    let lang = system_locale
+        .as_deref()
+        .and_then(|locale| translations.resolve_language(locale))
+        .unwrap_or_else(|| FALLBACK_LANGUAGE.to_string());
+    info!("시스템 로케일 {:?} → 언어 {}", system_locale, lang);
+    save_setting(app_handle, "currentLanguage", &lang);
+    lang
+}
+
+// 저장된 설정에 맞춰 메트릭 서버를 (재)시작하거나 중지합니다. 기본값은 꺼짐입니다.
+fn apply_metrics_server_setting(app_handle: &AppHandle, state: &AppState) {
+    if let Some(server) = state.metrics_server.lock().take() {
+        server.abort();
+        info!("메트릭 서버 중지");
+    }
+    if !load_setting::<bool>(app_handle, "metricsEnabled").unwrap_or(false) {
+        return;
+    }
+    let port = load_setting::<u16>(app_handle, "metricsPort").unwrap_or(DEFAULT_METRICS_PORT);
+    let metrics = state.metrics.clone();
+    let server = tauri::async_runtime::spawn(async move {
+        if let Err(e) = metrics::serve(port, metrics).await {
+            error!("메트릭 서버 실행 실패 (포트 {}): {}", port, e);
+        }
+    });
+    *state.metrics_server.lock() = Some(server);
+}
+
+// autolaunch 매니저에 자동 시작 여부를 반영합니다. 이미 같은 상태면 그대로 둡니다.
+fn apply_autostart(app_handle: &AppHandle, enabled: bool) -> Result<(), String> {
+    use tauri_plugin_autostart::ManagerExt;
+
+    let autostart_manager = app_handle.autolaunch();
+    let currently_enabled = autostart_manager.is_enabled().unwrap_or(false);
+    if currently_enabled != enabled {
+        let result = if enabled {
+            autostart_manager.enable()
+        } else {
+            autostart_manager.disable()
+        };
+        result.map_err(|e| e.to_string())?;
+    }
+    info!("registered for autostart? {}", autostart_manager.is_enabled().unwrap_or(false));
+    Ok(())
+}
+
+// 저전력 추론은 배터리 절약 모드이면서 설정이 켜져 있을 때만 사용합니다.
+fn apply_low_power_inference(state: &AppState) {
+    let enabled = *state.battery_saving_mode.lock() && *state.low_power_inference.lock();
+    state.pose_analyzer.set_low_power_inference(enabled);
+}
+
+// 트레이 아이콘 표시 여부를 적용합니다. 숨길 때는 메인 창을 띄워 앱을 조작하거나 종료할 수 있게 합니다.
+fn apply_tray_visibility(app_handle: &AppHandle, state: &AppState, visible: bool) {
+    *state.tray_visible.lock() = visible;
+    if let Some(tray) = state.tray.lock().as_ref() {
+        if let Err(e) = tray.set_visible(visible) {
+            error!("트레이 아이콘 표시 설정 실패: {}", e);
+        }
+    }
+    if !visible {
+        if let Some(window) = app_handle.get_webview_window("main") {
+            let _ = window.show();
+        }
+    }
+    info!("트레이 아이콘 표시: {}", visible);
+}
+
+// 모니터링을 켜거나 끄고 카메라 스트림과 트레이 아이콘을 맞춥니다. (트레이 메뉴, 전역 단축키)
+fn set_monitoring_active(app: &AppHandle, state: &AppState, active: bool) {
+    *state.monitoring_active.lock() = active;
+    if active {
+        *state.monitoring_started_at.lock() = Some(Instant::now());
+        let battery_saving = *state.battery_saving_mode.lock();
+        if !battery_saving {
+            let mut cam_lock = state.camera.lock();
+            if let Some(cam) = cam_lock.as_mut() {
+                if !cam.is_stream_open() {
+                    if let Err(e) = cam.open_stream() {
+                        error!("기존 웹캠 스트림 시작 실패: {}", e);
+                    } else {
+                        info!("기존 웹캠 스트림 시작됨.");
+                    }
+                }
+            } else {
+                let index = *state.selected_camera_index.lock();
+                info!("선택된 인덱스 {}번 카메라로 초기화 시도", index);
+                match open_camera(state, index) {
+                    Ok(cam) => {
+                        info!("새 웹캠 스트림 시작됨.");
+                        *cam_lock = Some(cam);
+                    }
+                    Err(e) => {
+                        error!("인덱스 {}번 웹캠 초기화 실패: {}", index, e);
+                    }
+                }
+            }
+        }
+        if let Some(tray) = state.tray.lock().as_ref() {
+            if let Err(e) = tray.set_icon(Some(app.default_window_icon().unwrap().clone())) {
+                error!("아이콘 변경 실패: {}", e);
+            }
+        }
+    } else {
+        close_pool_cameras(state);
+        if let Some(cam) = &mut *state.camera.lock() {
+            if cam.is_stream_open() {
+                if let Err(e) = cam.stop_stream() {
+                    error!("웹캠 스트림 중지 실패: {}", e);
+                } else {
+                    info!("웹캠 스트림 중지됨.");
+                }
+            }
+        }
+        if let Some(tray) = state.tray.lock().as_ref() {
+            if let Ok(monitoring_off_icon_path) = app.path().resolve("icons/monitoring_off.png", BaseDirectory::Resource) {
+                if let Ok(bytes) = fs::read(&monitoring_off_icon_path) {
+                    if let Ok(monitoring_off_icon) = Image::from_bytes(&bytes) {
+                        if let Err(e) = tray.set_icon(Some(monitoring_off_icon)) {
+                            error!("아이콘 변경 실패: {}", e);
+                        }
+                    } else {
+                        error!("아이콘 생성 실패");
+                    }
+                } else {
+                    error!("아이콘 파일 읽기 실패");
+                }
+            } else {
+                error!("아이콘 경로 해결 실패");
+            }
+        }
+    }
+    let _ = app.emit("monitoring-state-changed", &serde_json::json!({ "active": active }));
+}
+
+// 기존 단축키를 해제하고 새 단축키를 등록합니다. 등록에 실패하면 기존 단축키를 되살립니다.
+fn apply_monitoring_shortcut(app_handle: &AppHandle, state: &AppState, shortcut: &str) -> Result<(), String> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+    let shortcut = shortcut.trim();
+    let new_shortcut = if shortcut.is_empty() {
+        None
+    } else {
+        Some(
+            shortcut
+                .parse::<Shortcut>()
+                .map_err(|e| format!("단축키 형식이 올바르지 않습니다 ({}): {}", shortcut, e))?,
+        )
+    };
+
+    let global_shortcut = app_handle.global_shortcut();
+    let mut current = state.monitoring_shortcut.lock();
+    let previous = current.as_deref().and_then(|s| s.parse::<Shortcut>().ok());
+    if previous == new_shortcut {
+        *current = new_shortcut.map(|_| shortcut.to_string());
+        return Ok(());
+    }
+    if let Some(previous) = previous {
+        if let Err(e) = global_shortcut.unregister(previous) {
+            warn!("기존 단축키 해제 실패: {}", e);
+        }
+    }
+    if let Some(new_shortcut) = new_shortcut {
+        if let Err(e) = global_shortcut.register(new_shortcut) {
+            if let Some(previous) = previous {
+                if let Err(e) = global_shortcut.register(previous) {
+                    error!("기존 단축키 복구 실패: {}", e);
+                }
+            }
+            return Err(format!(
+                "단축키 {}를 등록할 수 없습니다. 다른 프로그램에서 이미 사용 중일 수 있습니다: {}",
+                shortcut, e
+            ));
+        }
+    }
+    *current = new_shortcut.map(|_| shortcut.to_string());
+    info!("모니터링 단축키: {:?}", *current);
+    Ok(())
+}
+
+// 전역 단축키로 모니터링 상태를 뒤집고 바뀐 상태를 알림으로 보여줍니다.
+fn toggle_monitoring_from_shortcut(app_handle: &AppHandle) {
+    let state = app_handle.state::<AppState>();
+    let active = !*state.monitoring_active.lock();
+    info!("전역 단축키로 모니터링 {}", if active { "시작" } else { "중지" });
+    set_monitoring_active(app_handle, &state, active);
+
+    let lang = state.current_language.lock().clone();
+    let key = if active { "shortcut_monitoring_on" } else { "shortcut_monitoring_off" };
+    if let Err(e) = send_system_notification(app_handle, "Pose Nudge", &state.translations.get(&lang, key)) {
+        error!("단축키 알림 전송 실패: {}", e);
+    }
+}
+
+// --- Calibration Image Helpers ---
+
+const CALIBRATION_IMAGE_FILE: &str = "calibrated_pose.jpeg";
+
+fn remove_calibration_image(handle: &AppHandle) -> Result<bool, String> {
+    let app_data_path = handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?;
+    let file_path = app_data_path
+        .join("calibration_images")
+        .join(CALIBRATION_IMAGE_FILE);
+
+    // 프론트엔드가 기억하는 이미지 경로도 함께 제거
+    if let Ok(store) = handle.store(SETTINGS_STORE) {
+        if store.delete("calibratedImagePath") {
+            let _ = store.save();
+        }
+    }
+
+    if !file_path.exists() {
+        return Ok(false);
+    }
+    fs::remove_file(&file_path).map_err(|e| format!("캘리브레이션 이미지 삭제 실패: {}", e))?;
+    info!("캘리브레이션 이미지 삭제 완료: {:?}", file_path);
+    Ok(true)
+}
+
+// --- Database Helpers ---
+
+async fn get_sqlite_pool(app_handle: &AppHandle) -> Option<sqlx::SqlitePool> {
+    let instances = app_handle.state::<DbInstances>();
+    let db_map = instances.0.read().await;
+    match db_map.get(DB_URL) {
+        Some(tauri_plugin_sql::DbPool::Sqlite(sqlite_pool)) => Some(sqlite_pool.clone()),
+        _ => None,
+    }
+}
+
+// SQL 플러그인이 DB_URL의 sqlite 파일을 만드는 위치 (앱 설정 디렉토리 기준)
+fn database_file_path(app_handle: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let file_name = DB_URL.trim_start_matches("sqlite:");
+    app_handle
+        .path()
+        .app_config_dir()
+        .map(|dir| dir.join(file_name))
+        .map_err(|e| format!("앱 설정 디렉토리를 찾을 수 없습니다: {}", e))
+}
+
+// 원본 로그를 시간 단위로 집계해 posture_hourly에 기록합니다.
+// 마지막으로 집계된 시간대부터 다시 계산해 덮어쓰므로 여러 번 실행해도 중복 집계되지 않습니다.
+// trigger_test_detection으로 만든 테스트 기록(is_test)은 통계에서 제외합니다.
+async fn rollup_hourly_stats(pool: &sqlx::SqlitePool) -> Result<u64, sqlx::Error> {
+    let last_hour: Option<i64> = sqlx::query_scalar("SELECT MAX(hour_start) FROM posture_hourly")
+        .fetch_one(pool)
+        .await?;
+    let since = last_hour.unwrap_or(0);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let query = format!(
+        "INSERT OR REPLACE INTO posture_hourly (hour_start, sample_count, avg_score, turtle_neck_count, shoulder_misaligned_count, updated_at) \
+         SELECT ({ts} / 3600000) * 3600 AS hour_start, COUNT(*), AVG(score), SUM(is_turtle_neck), SUM(is_shoulder_misaligned), ? \
+         FROM posture_log WHERE is_test = 0 AND {ts} >= ? GROUP BY hour_start",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let result = sqlx::query(&query)
+    .bind(now)
+    .bind(since * 1000)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+// --- Alert Helpers ---
+
+fn send_system_notification(
+    app_handle: &AppHandle,
+    title: &str,
+    body: &str,
+) -> Result<(), tauri_plugin_notification::Error> {
+    send_system_notification_with_icon(app_handle, title, body, "icons/icon.png")
+}
+
+fn send_system_notification_with_icon(
+    app_handle: &AppHandle,
+    title: &str,
+    body: &str,
+    icon: &str,
+) -> Result<(), tauri_plugin_notification::Error> {
+    // ✨ 이것이 Tauri v2의 표준적인 알림 호출 방식입니다.
+    app_handle
+        .notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .icon(icon.to_string())
+        .show()
+}
+
+// 연속된 동일 메시지를 "(xN)" 형태로 합치고 본문 길이를 제한합니다.
+fn coalesce_alert_messages(messages: &[String]) -> String {
+    let mut lines: Vec<(String, usize)> = Vec::new();
+    for message in messages.iter().filter(|m| !m.is_empty()) {
+        match lines.last_mut() {
+            Some((last, count)) if last == message => *count += 1,
+            _ => lines.push((message.clone(), 1)),
+        }
+    }
+
+    let body = lines
+        .into_iter()
+        .map(|(message, count)| {
+            if count > 1 {
+                format!("{} (x{})", message, count)
+            } else {
+                message
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if body.chars().count() > MAX_NOTIFICATION_BODY_CHARS {
+        let truncated: String = body.chars().take(MAX_NOTIFICATION_BODY_CHARS - 1).collect();
+        format!("{}…", truncated)
+    } else {
+        body
+    }
+}
+
+// --- Weekly Report ---
+
+// 설정된 요일(0=월요일)과 시각 기준으로 가장 최근에 도래한 리포트 발송 시점
+fn last_scheduled_report_time(
+    now: chrono::DateTime<chrono::Local>,
+    weekday: u8,
+    hour: u8,
+) -> Option<chrono::DateTime<chrono::Local>> {
+    use chrono::Datelike;
+    let days_since = (now.weekday().num_days_from_monday() as i64 - weekday as i64).rem_euclid(7);
+    let date = now.date_naive() - chrono::Duration::days(days_since);
+    let scheduled = date
+        .and_hms_opt(hour as u32, 0, 0)?
+        .and_local_timezone(chrono::Local)
+        .earliest()?;
+    if scheduled > now {
+        Some(scheduled - chrono::Duration::days(7))
+    } else {
+        Some(scheduled)
+    }
+}
+
+// 최근 7일과 그 이전 7일을 비교한 주간 요약. 이번 주 데이터가 없으면 None
+async fn build_weekly_report(
+    pool: &sqlx::SqlitePool,
+    now: i64,
+) -> Result<Option<serde_json::Value>, sqlx::Error> {
+    const WEEK_SECS: i64 = 7 * 24 * 3600;
+    let query = format!(
+        "SELECT COUNT(*), AVG(score), SUM(is_turtle_neck), SUM(is_shoulder_misaligned) FROM posture_log WHERE is_test = 0 AND {ts} >= ? AND {ts} < ?",
+        ts = TIMESTAMP_MS_SQL
+    );
+    let (count, average, turtle, shoulder) =
+        sqlx::query_as::<_, (i64, Option<f64>, Option<i64>, Option<i64>)>(&query)
+            .bind((now - WEEK_SECS) * 1000)
+            .bind(now * 1000)
+            .fetch_one(pool)
+            .await?;
+    let Some(average) = average.filter(|_| count > 0) else {
+        return Ok(None);
+    };
+    let (_, previous_average, _, _) =
+        sqlx::query_as::<_, (i64, Option<f64>, Option<i64>, Option<i64>)>(&query)
+            .bind((now - 2 * WEEK_SECS) * 1000)
+            .bind((now - WEEK_SECS) * 1000)
+            .fetch_one(pool)
+            .await?;
+
+    let (turtle, shoulder) = (turtle.unwrap_or(0), shoulder.unwrap_or(0));
+    let most_common_problem = if turtle == 0 && shoulder == 0 {
+        None
+    } else if turtle >= shoulder {
+        Some("turtle_neck")
+    } else {
+        Some("shoulder_misalignment")
+    };
+
+    Ok(Some(serde_json::json!({
+        "sample_count": count,
+        "average_score": average,
+        "previous_average_score": previous_average,
+        "trend": previous_average.map(|previous| average - previous),
+        "most_common_problem": most_common_problem,
+        "turtle_neck_count": turtle,
+        "shoulder_misaligned_count": shoulder,
+    })))
+}
+
+fn format_weekly_report(state: &AppState, report: &serde_json::Value) -> String {
+    let lang = state.current_language.lock().clone();
+    let translations = &state.translations;
+    let average = report["average_score"].as_f64().unwrap_or(0.0);
+    let trend = match report["trend"].as_f64() {
+        Some(diff) if diff >= 1.0 => translations
+            .get(&lang, "weekly_trend_up")
+            .replace("{diff}", &format!("{:.0}", diff)),
+        Some(diff) if diff <= -1.0 => translations
+            .get(&lang, "weekly_trend_down")
+            .replace("{diff}", &format!("{:.0}", diff.abs())),
+        Some(_) => translations.get(&lang, "weekly_trend_same"),
+        None => translations.get(&lang, "weekly_trend_none"),
+    };
+    let problem_key = match report["most_common_problem"].as_str() {
+        Some("turtle_neck") => "weekly_problem_turtle",
+        Some("shoulder_misalignment") => "weekly_problem_shoulder",
+        _ => "weekly_problem_none",
+    };
+    translations
+        .get(&lang, "weekly_report_body")
+        .replace("{score}", &format!("{:.0}", average))
+        .replace("{trend}", &trend)
+        .replace("{problem}", &translations.get(&lang, problem_key))
+}
+
+// --- Background Tasks ---
+
+async fn background_weekly_report_task(app_handle: AppHandle, state: AppState) {
+    let mut interval = tokio::time::interval(Duration::from_secs(WEEKLY_REPORT_CHECK_INTERVAL_SECS));
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = state.shutdown_requested() => break,
+        }
+        let now = chrono::Local::now();
+        let weekday = load_setting::<u8>(&app_handle, "weeklyReportDay").unwrap_or(0);
+        let hour = load_setting::<u8>(&app_handle, "weeklyReportHour").unwrap_or(9);
+        let Some(last_sent) = load_setting::<i64>(&app_handle, "weeklyReportLastSent") else {
+            // 첫 실행 시에는 기준 시점만 기록하고 다음 예정 시각부터 발송
+            save_setting(&app_handle, "weeklyReportLastSent", now.timestamp());
+            continue;
+        };
+        let Some(scheduled) = last_scheduled_report_time(now, weekday, hour) else {
+            continue;
+        };
+        if last_sent >= scheduled.timestamp() {
+            continue;
+        }
+        let Some(pool) = get_sqlite_pool(&app_handle).await else {
+            continue;
+        };
+
+        match build_weekly_report(&pool, now.timestamp()).await {
+            Ok(Some(report)) => {
+                let message = format_weekly_report(&state, &report);
+                info!("주간 리포트 발송: {}", message);
+                let lang = state.current_language.lock().clone();
+                let title = state.translations.get(&lang, "weekly_report_title");
+                if let Err(e) = send_system_notification(&app_handle, &title, &message) {
+                    error!("주간 리포트 알림 전송 실패: {}", e);
+                }
+                let _ = app_handle.emit(
+                    "weekly-report",
+                    &serde_json::json!({ "report": report, "message": message }),
+                );
+            }
+            Ok(None) => info!("주간 리포트: 이번 주 기록이 없어 발송하지 않습니다."),
+            Err(e) => {
+                error!("주간 리포트 생성 실패: {}", e);
+                continue;
+            }
+        }
+        save_setting(&app_handle, "weeklyReportLastSent", now.timestamp());
+    }
+}
+
+async fn background_rollup_task(app_handle: AppHandle, state: AppState) {
+    let mut interval = tokio::time::interval(Duration::from_secs(HOURLY_ROLLUP_INTERVAL_SECS));
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = state.shutdown_requested() => break,
+        }
+        let Some(pool) = get_sqlite_pool(&app_handle).await else {
+            continue;
+        };
+        match rollup_hourly_stats(&pool).await {
+            Ok(rows) => info!("시간별 자세 통계 집계 완료: {}개 시간대 갱신", rows),
+            Err(e) => error!("시간별 자세 통계 집계 실패: {}", e),
+        }
+    }
+}
+
+async fn background_alert_task(app_handle: AppHandle, state: AppState) {
+    loop {
+        // 주기가 바뀌면 interval을 새로 만들어 다음 틱부터 바로 반영
+        let period = effective_alert_flush_interval(&state);
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = state.alert_interval_changed.notified() => break,
+                _ = state.shutdown_requested() => {
+                    // 종료 직전에 쌓여 있던 알림도 보냄
+                    flush_alert_messages(&app_handle, &state);
+                    return;
+                }
+            }
+            flush_alert_messages(&app_handle, &state);
+        }
+    }
+}
+
+// 모델 세션 생성이 멈추거나 실패하는 경우(백신 검사, 느린 디스크 등)를 대비해 시간 제한을 두고 재시도합니다.
+// 진행 상황은 model-loading / model-ready / model-failed 이벤트로 알리고, 성공 시 시도 횟수를 반환합니다.
+async fn initialize_model_with_retry(app_handle: &AppHandle, state: &AppState) -> Result<u32, String> {
+    let mut last_error = String::new();
+    for attempt in 1..=MODEL_INIT_MAX_ATTEMPTS {
+        let _ = app_handle.emit(
+            "model-loading",
+            &serde_json::json!({ "attempt": attempt, "max_attempts": MODEL_INIT_MAX_ATTEMPTS }),
+        );
+        match load_model_with_timeout(app_handle, state).await {
+            Ok(()) => {
+                info!("모델 초기화 성공 ({}번째 시도)", attempt);
+                let _ = app_handle.emit("model-ready", &serde_json::json!({ "attempts": attempt }));
+                return Ok(attempt);
+            }
+            Err(e) => {
+                warn!("모델 초기화 실패 ({}/{}): {}", attempt, MODEL_INIT_MAX_ATTEMPTS, e);
+                last_error = e;
+            }
+        }
+        if attempt < MODEL_INIT_MAX_ATTEMPTS {
+            sleep(Duration::from_secs(MODEL_INIT_RETRY_DELAY_SECS * attempt as u64)).await;
+        }
+    }
+    error!("모델 초기화 최종 실패: {}", last_error);
+    let _ = app_handle.emit(
+        "model-failed",
+        &serde_json::json!({ "attempts": MODEL_INIT_MAX_ATTEMPTS, "error": last_error }),
+    );
+    Err(last_error)
+}
+
+// 세션 생성은 블로킹 작업이므로 별도 스레드에서 실행하고 제한 시간을 넘기면 실패로 처리합니다.
+async fn load_model_with_timeout(app_handle: &AppHandle, state: &AppState) -> Result<(), String> {
+    let model_path = state
+        .pose_analyzer
+        .download_verified_yolo_model(app_handle.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    // 제한 시간을 넘겨 버려진 이전 시도가 나중에 끝나도 세션을 바꾸지 못하도록 시도 번호를 새로 받음
+    let generation = state.pose_analyzer.begin_session_load();
+    let analyzer = state.pose_analyzer.clone();
+    let load = tauri::async_runtime::spawn_blocking(move || analyzer.load_session(&model_path, generation));
+    match tokio::time::timeout(Duration::from_secs(MODEL_INIT_TIMEOUT_SECS), load).await {
+        Ok(Ok(result)) => result.map_err(|e| e.to_string()),
+        Ok(Err(e)) => Err(format!("모델 로드 작업이 비정상 종료되었습니다: {}", e)),
+        Err(_) => Err(format!("{}초 안에 모델을 불러오지 못했습니다", MODEL_INIT_TIMEOUT_SECS)),
+    }
+}
+
+// 다음 알림 주기에 보낼 메시지를 대기열에 넣고 알림 기록에도 남깁니다. 웹훅이 설정되어 있으면 함께 보냅니다.
+fn queue_alert(state: &AppState, kind: AlertKind, message: String, score: Option<i64>) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    {
+        let mut history = state.alert_history.lock();
+        while history.len() >= ALERT_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(AlertHistoryEntry { timestamp, kind, message: message.clone() });
+    }
+    state.alert_messages.lock().push(PendingAlert { kind, message });
+    send_alert_webhook(state, kind, score, timestamp);
+}
+
+// 웹훅 주소로 알림 정보를 보냅니다. 별도 작업에서 제한 시간 안에 보내고, 실패해도 로그만 남깁니다.
+fn send_alert_webhook(state: &AppState, kind: AlertKind, score: Option<i64>, timestamp: i64) {
+    let Some(url) = state.webhook_url.lock().clone() else {
+        return;
+    };
+    let payload = serde_json::json!({
+        "type": kind,
+        "score": score,
+        "timestamp": timestamp,
+    });
+    tauri::async_runtime::spawn(async move {
+        let client = match reqwest::Client::builder()
+            .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                warn!("웹훅 클라이언트 생성 실패: {}", e);
+                return;
+            }
+        };
+        match client.post(&url).json(&payload).send().await {
+            Ok(response) if !response.status().is_success() => {
+                warn!("웹훅 응답 오류: {}", response.status());
+            }
+            Ok(_) => {}
+            Err(e) => warn!("웹훅 전송 실패: {}", e),
+        }
+    });
+}
+
+// 쌓인 알림 메시지를 종류별로 합쳐 종류에 맞는 제목과 아이콘의 시스템 알림으로 보냅니다.
+fn flush_alert_messages(app_handle: &AppHandle, state: &AppState) {
+    let pending = state.alert_messages.lock().drain(..).collect::<Vec<_>>();
+
+    // 처음 등장한 순서대로 종류별 메시지를 묶음
+    let mut grouped: Vec<(AlertKind, Vec<String>)> = Vec::new();
+    for alert in pending {
+        match grouped.iter_mut().find(|(kind, _)| *kind == alert.kind) {
+            Some((_, messages)) => messages.push(alert.message),
+            None => grouped.push((alert.kind, vec![alert.message])),
+        }
+    }
+
+    for (kind, messages) in grouped {
+        let message = coalesce_alert_messages(&messages);
+        if message.is_empty() {
+            continue;
+        }
+
+        info!("시스템 알림 발생: {}", &message);
+
+        if let Err(e) = send_system_notification_with_icon(app_handle, kind.title(), &message, kind.icon()) {
+            error!("시스템 알림을 보내는 데 실패했습니다: {}", e);
+        }
+    }
+}
+
+// 모니터링 한 주기의 결과 (하트비트 이벤트용)
+struct MonitoringCycle {
+    frame_captured: bool,
+    confidence: Option<f64>,
+}
+
+async fn background_monitoring_task(app_handle: AppHandle, state: AppState) {
+    let mut last_confidence: Option<f64> = None;
+    loop {
+        if expire_focus_mode(&state) {
+            info!("집중 모드 종료: 기존 모니터링 주기로 복귀");
+            state.alert_interval_changed.notify_one();
+            let _ = app_handle.emit("focus-mode-ended", &focus_mode_status(&state));
+        }
+        let interval_duration = effective_monitoring_interval(&state);
+        tokio::select! {
+            _ = sleep(interval_duration) => {}
+            _ = state.interval_changed.notified() => continue,
+            _ = state.shutdown_requested() => break,
+        }
+
+        if !*state.monitoring_active.lock() || state.auto_paused.lock().is_some() {
+            continue;
+        }
+
+        let cycle = run_monitoring_cycle(&app_handle, &state).await;
+        if cycle.confidence.is_some() {
+            last_confidence = cycle.confidence;
+        }
+
+        // 프론트엔드가 모니터링 루프의 동작 여부를 확인할 수 있도록 매 주기 하트비트 전송
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        let _ = app_handle.emit(
+            "monitoring-heartbeat",
+            &serde_json::json!({
+                "timestamp": timestamp,
+                "frame_captured": cycle.frame_captured,
+                "confidence": last_confidence,
+                "interval_secs": effective_monitoring_interval(&state).as_secs(),
+            }),
+        );
+    }
+}
+
+// --- Monitoring Interval Helpers ---
+
+// 감도 확인, 집중 모드, 적응형 주기 등 임시 설정을 반영한 실제 모니터링 주기
+// 감도 확인 중에는 결과를 빨리 볼 수 있도록 주기를 줄이고, 그다음 집중 모드가 우선하며
+// 배터리 절약 모드에서는 적응형 주기가 기본 주기보다 짧아지지 않음 (카메라를 매번 켜고 끄므로 짧은 주기는 적용하지 않음)
+fn effective_monitoring_interval(state: &AppState) -> Duration {
+    let base_secs = *state.monitoring_interval_secs.lock();
+    let battery_saving = *state.battery_saving_mode.lock();
+    if !battery_saving && state.sensitivity_check.lock().is_some() {
+        return Duration::from_secs(SENSITIVITY_CHECK_INTERVAL_SECS.min(base_secs.max(1)));
+    }
+    let focus_mode = *state.focus_mode.lock();
+    match focus_mode {
+        Some(focus) if !battery_saving && Instant::now() < focus.ends_at => {
+            Duration::from_secs(focus.interval_secs)
+        }
+        _ => match adaptive_interval_secs(state) {
+            Some(secs) if battery_saving => Duration::from_secs(secs.max(base_secs)),
+            Some(secs) => Duration::from_secs(secs),
+            None => Duration::from_secs(base_secs),
+        },
+    }
+}
+
+// 자리 비움으로 판단하기까지의 실제 유예 시간
+// 배터리 절약 모드처럼 주기가 길면 한두 번 확인을 놓친 것만으로 자리 비움이 되지 않도록 주기의 두 배 이상으로 늘림
+fn effective_away_grace(state: &AppState) -> Duration {
+    let grace = Duration::from_secs(*state.sitting_grace_secs.lock());
+    grace.max(effective_monitoring_interval(state) * 2)
+}
+
+// 최근 점수의 평균과 변동폭으로 적응형 주기를 계산합니다. 꺼져 있으면 None
+// 점수가 높고 안정적일수록 max_secs에, 낮거나 흔들릴수록 min_secs에 가까워집니다.
+fn adaptive_interval_secs(state: &AppState) -> Option<u64> {
+    let adaptive = (*state.adaptive_interval.lock())?;
+    let scores = state.recent_scores.lock();
+    if scores.len() < ADAPTIVE_MIN_SAMPLES {
+        let base_secs = *state.monitoring_interval_secs.lock();
+        return Some(base_secs.clamp(adaptive.min_secs, adaptive.max_secs));
+    }
+    let mean = scores.iter().sum::<f64>() / scores.len() as f64;
+    let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / scores.len() as f64;
+    // 표준편차 20점 이상이면 완전히 불안정한 것으로 봄
+    let stability = 1.0 - (variance.sqrt() / 20.0).min(1.0);
+    let factor = (mean / 100.0).clamp(0.0, 1.0) * stability;
+    let range = (adaptive.max_secs - adaptive.min_secs) as f64;
+    Some(adaptive.min_secs + (range * factor).round() as u64)
+}
+
+// 적응형 주기 계산을 위해 최근 자세 점수를 기록합니다.
+fn record_recent_score(state: &AppState, score: i64) {
+    let mut scores = state.recent_scores.lock();
+    while scores.len() >= ADAPTIVE_SCORE_WINDOW {
+        scores.pop_front();
+    }
+    scores.push_back(score as f64);
+}
+
+// 알림 묶음 전송 주기 (별도 설정이 없으면 짧은 기본 주기를 사용해 긴 캡처 주기에서도 알림이 늦지 않게 함)
+// interval은 0초를 허용하지 않음
+fn effective_alert_flush_interval(state: &AppState) -> Duration {
+    let secs = state
+        .alert_flush_interval_secs
+        .lock()
+        .unwrap_or(DEFAULT_ALERT_FLUSH_INTERVAL_SECS);
+    Duration::from_secs(secs.max(1))
+}
+
+// 모니터링 루프와 알림 루프에 주기 변경을 알립니다.
+fn notify_interval_changed(state: &AppState) {
+    state.interval_changed.notify_one();
+    state.alert_interval_changed.notify_one();
+}
+
+// 종료 시각이 지난 집중 모드를 정리합니다. 방금 종료되었으면 true
+fn expire_focus_mode(state: &AppState) -> bool {
+    let mut focus_mode = state.focus_mode.lock();
+    match *focus_mode {
+        Some(focus) if Instant::now() >= focus.ends_at => {
+            *focus_mode = None;
+            true
+        }
+        _ => false,
+    }
+}
+
+fn focus_mode_status(state: &AppState) -> serde_json::Value {
+    let focus_mode = *state.focus_mode.lock();
+    let battery_saving = *state.battery_saving_mode.lock();
+    serde_json::json!({
+        "active": focus_mode.is_some(),
+        "remaining_secs": focus_mode.map(|f| f.ends_at.saturating_duration_since(Instant::now()).as_secs()),
+        "interval_secs": focus_mode.map(|f| f.interval_secs),
+        "base_interval_secs": *state.monitoring_interval_secs.lock(),
+        "effective_interval_secs": effective_monitoring_interval(state).as_secs(),
+        "adaptive_interval": state.adaptive_interval.lock().is_some(),
+        "suspended_by_battery_saving": focus_mode.is_some() && battery_saving,
+    })
+}
+
+// --- Camera Helpers ---
+
+// 플랫폼별로 시도할 카메라 백엔드 순서. 일부 Windows 환경에서 Auto가 회색 화면만 반환하는 문제가 있어
+// 명시적인 백엔드를 먼저 시도합니다. (nokhwa의 네이티브 입력은 DirectShow를 제공하지 않으므로 Windows는 MSMF → Auto)
+fn camera_backend_chain() -> Vec<ApiBackend> {
+    if cfg!(target_os = "windows") {
+        vec![ApiBackend::MediaFoundation, ApiBackend::Auto]
+    } else if cfg!(target_os = "macos") {
+        vec![ApiBackend::AVFoundation, ApiBackend::Auto]
+    } else if cfg!(target_os = "linux") {
+        vec![ApiBackend::Video4Linux, ApiBackend::Auto]
+    } else {
+        vec![ApiBackend::Auto]
+    }
+}
+
+// 한 가지 색으로만 채워진(회색/검은 화면) 프레임인지 확인
+fn is_blank_frame(image: &ImageBuffer<Rgb<u8>, Vec<u8>>) -> bool {
+    const MIN_STD_DEV: f64 = 4.0;
+    match frame_luma_stats(image) {
+        Some((_, std_dev)) => std_dev < MIN_STD_DEV,
+        None => true,
+    }
+}
+
+// 일부 픽셀만 샘플링한 밝기(luma)의 평균과 표준편차 (0~255)
+fn frame_luma_stats(image: &ImageBuffer<Rgb<u8>, Vec<u8>>) -> Option<(f64, f64)> {
+    const STEP: usize = 16;
+    let luma: Vec<f64> = image
+        .pixels()
+        .step_by(STEP)
+        .map(|p| 0.299 * p.0[0] as f64 + 0.587 * p.0[1] as f64 + 0.114 * p.0[2] as f64)
+        .collect();
+    if luma.is_empty() {
+        return None;
+    }
+    let mean = luma.iter().sum::<f64>() / luma.len() as f64;
+    let variance = luma.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / luma.len() as f64;
+    Some((mean, variance.sqrt()))
+}
+
+// 평균 밝기가 기준보다 낮은 프레임이 연속되면 low_light 이벤트와 안내 문구를 보냅니다. (한 번 어두워질 때마다 한 번)
+fn check_low_light(app_handle: &AppHandle, state: &AppState, brightness: f64) {
+    let threshold = *state.low_light_threshold.lock();
+    let mut streak = state.low_light_streak.lock();
+    if brightness >= threshold {
+        *streak = 0;
+        return;
+    }
+    *streak += 1;
+    if *streak != LOW_LIGHT_FRAME_COUNT {
+        return;
+    }
+    warn!("조명이 어둡습니다: 평균 밝기 {:.1} (기준 {:.1})", brightness, threshold);
+    let lang = state.current_language.lock().clone();
+    let _ = app_handle.emit(
+        "low_light",
+        &serde_json::json!({
+            "brightness": brightness,
+            "threshold": threshold,
+            "tip": state.translations.get(&lang, "low_light_tip"),
+        }),
+    );
+}
+
+// 요청할 카메라 포맷 순서. 최고 프레임레이트 협상이 실패하거나 빈 화면을 주는 가상 카메라(OBS 등)를 위해
+// 흔히 지원되는 MJPEG/YUYV/NV12 포맷과 해상도를 차례로 시도합니다.
+fn camera_format_chain() -> Vec<RequestedFormatType> {
+    let mut chain = vec![RequestedFormatType::AbsoluteHighestFrameRate];
+    for (width, height) in [(1280, 720), (640, 480)] {
+        for frame_format in [FrameFormat::MJPEG, FrameFormat::YUYV, FrameFormat::NV12] {
+            chain.push(RequestedFormatType::Closest(CameraFormat::new(
+                Resolution::new(width, height),
+                frame_format,
+                30,
+            )));
+        }
+    }
+    chain
+}
+
+fn open_camera_with_backend(index: u32, backend: ApiBackend) -> Result<Camera, String> {
+    let mut last_error = String::new();
+    for format_type in camera_format_chain() {
+        match open_camera_with_format(index, backend, format_type) {
+            Ok(cam) => {
+                info!("카메라 포맷 협상 결과: {:?} (요청 {:?})", cam.camera_format(), format_type);
+                return Ok(cam);
+            }
+            Err(e) => {
+                warn!("카메라 포맷 {:?} 사용 실패: {}", format_type, e);
+                last_error = e;
+            }
+        }
+    }
+    Err(last_error)
+}
+
+fn open_camera_with_format(
+    index: u32,
+    backend: ApiBackend,
+    format_type: RequestedFormatType,
+) -> Result<Camera, String> {
+    const PROBE_FRAMES: usize = 10;
+    let requested = RequestedFormat::new::<RgbFormat>(format_type);
+    let mut cam = Camera::with_backend(CameraIndex::Index(index), requested, backend)
+        .map_err(|e| e.to_string())?;
+    cam.open_stream().map_err(|e| e.to_string())?;
+
+    // 초기 프레임은 검게 나올 수 있으므로 몇 장 중 하나라도 정상이면 사용
+    let has_valid_frame = (0..PROBE_FRAMES).any(|_| {
+        cam.frame()
+            .ok()
+            .and_then(|buffer| decode_frame(&buffer))
+            .map_or(false, |image| !is_blank_frame(&image))
+    });
+    if !has_valid_frame {
+        let _ = cam.stop_stream();
+        return Err("빈 프레임만 수신됨".to_string());
+    }
+    Ok(cam)
+}
+
+fn open_camera(state: &AppState, index: u32) -> Result<Camera, String> {
+    let cam = open_camera_any_backend(state, index)?;
+    note_camera_source(state, index, cam.camera_format());
+    Ok(cam)
+}
+
+// 백엔드를 차례로 시도해 카메라를 엽니다. (분석 기록 초기화 없이 여는 카메라 풀 비교용으로도 사용)
+fn open_camera_any_backend(state: &AppState, index: u32) -> Result<Camera, String> {
+    if *state.no_camera.lock() {
+        return Err("연결된 카메라가 없습니다.".to_string());
+    }
+    // 이전에 성공한 백엔드가 있으면 먼저 시도
+    let preferred = *state.preferred_camera_backend.lock();
+    let mut backends = camera_backend_chain();
+    if let Some(preferred) = preferred {
+        backends.retain(|b| *b != preferred);
+        backends.insert(0, preferred);
+    }
+
+    let mut last_error = String::new();
+    for backend in backends {
+        match open_camera_with_backend(index, backend) {
+            Ok(cam) => {
+                info!(
+                    "인덱스 {}번 카메라 열기 성공: {} (백엔드 {:?})",
+                    index,
+                    cam.info().human_name(),
+                    backend
+                );
+                *state.preferred_camera_backend.lock() = Some(backend);
+                return Ok(cam);
+            }
+            Err(e) => {
+                warn!("인덱스 {}번 카메라를 {:?} 백엔드로 열기 실패: {}", index, backend, e);
+                last_error = e;
+            }
+        }
+    }
+    error!("인덱스 {}번 카메라 초기화 실패: {}", index, last_error);
+    Err(last_error)
+}
+
+// 카메라 장치나 포맷이 이전과 달라졌으면 이전 화면 기준의 감지 이력과 키포인트 스무딩 기록을 비웁니다.
+// (절약 모드처럼 같은 카메라를 매번 다시 여는 경우에는 유지)
+fn note_camera_source(state: &AppState, index: u32, format: CameraFormat) {
+    let mut source = state.active_camera_source.lock();
+    if *source != Some((index, format)) {
+        if source.is_some() {
+            info!("카메라 소스 변경 감지 ({:?} → {:?}), 최근 분석 기록 초기화", *source, (index, format));
+            state.pose_analyzer.reset_for_source_change();
+        }
+        *source = Some((index, format));
+    }
+}
+
+// 재시도 대기 중이면 시도하지 않고, 실패가 반복되면 재시도 간격을 늘리며 camera-error 이벤트를 보냅니다.
+fn open_camera_with_backoff(app_handle: &AppHandle, state: &AppState, index: u32) -> Option<Camera> {
+    if !state.camera_backoff.lock().can_retry() {
+        return None;
+    }
+    match open_camera(state, index) {
+        Ok(cam) => {
+            state.camera_backoff.lock().reset();
+            Some(cam)
+        }
+        Err(e) => {
+            let (failures, delay) = {
+                let mut backoff = state.camera_backoff.lock();
+                let delay = backoff.record_failure();
+                (backoff.consecutive_failures, delay)
+            };
+            warn!(
+                "카메라 초기화 {}회 연속 실패, {}초 후 재시도",
+                failures,
+                delay.as_secs()
+            );
+            if failures >= CAMERA_ERROR_EVENT_THRESHOLD {
+                let _ = app_handle.emit(
+                    "camera-error",
+                    &serde_json::json!({
+                        "index": index,
+                        "consecutive_failures": failures,
+                        "retry_in_secs": delay.as_secs(),
+                        "error": e,
+                    }),
+                );
+            }
+            None
+        }
+    }
+}
+
+// 카메라 장치 유무가 바뀌었을 때 상태를 갱신하고 camera-availability-changed 이벤트를 보냅니다.
+// 장치가 다시 발견되면 재시도 대기 상태를 초기화하고, 모니터링 중이면 바로 카메라를 엽니다.
+fn set_camera_availability(app_handle: &AppHandle, state: &AppState, available: bool) {
+    let was_missing = std::mem::replace(&mut *state.no_camera.lock(), !available);
+    if was_missing == !available {
+        return;
+    }
+    let _ = app_handle.emit(
+        "camera-availability-changed",
+        &serde_json::json!({ "available": available }),
+    );
+    if !available {
+        warn!("연결된 카메라가 없습니다. 장치가 연결될 때까지 캡처를 건너뜁니다.");
+        return;
+    }
+
+    info!("카메라 장치가 발견되어 캡처를 재개합니다.");
+    state.camera_backoff.lock().reset();
+    let monitoring = *state.monitoring_active.lock() && state.auto_paused.lock().is_none();
+    let battery_saving = *state.battery_saving_mode.lock();
+    if monitoring && !battery_saving {
+        let mut cam_lock = state.camera.lock();
+        if cam_lock.is_none() {
+            let index = *state.selected_camera_index.lock();
+            *cam_lock = open_camera_with_backoff(app_handle, state, index);
+        }
+    }
+}
+
+// 시작 시 카메라 장치 유무를 확인하고, 장치가 없는 동안 주기적으로 다시 확인합니다.
+async fn background_camera_scan_task(app_handle: AppHandle, state: AppState) {
+    let mut first_scan = true;
+    loop {
+        if first_scan || *state.no_camera.lock() {
+            match query_available_cameras() {
+                Ok(cameras) => set_camera_availability(&app_handle, &state, !cameras.is_empty()),
+                Err(e) => warn!("카메라 목록 재확인 실패: {}", e),
+            }
+            first_scan = false;
+        }
+        tokio::select! {
+            _ = sleep(Duration::from_secs(CAMERA_RESCAN_INTERVAL_SECS)) => {}
+            _ = state.shutdown_requested() => break,
+        }
+    }
+}
+
+// --- System Sleep / Lock Helpers ---
+
+// 화면이 잠겨 있는지 확인합니다. 확인할 수 없는 플랫폼이면 None
+#[cfg(target_os = "linux")]
+fn is_screen_locked() -> Option<bool> {
+    let session_id = std::env::var("XDG_SESSION_ID").ok()?;
+    let output = std::process::Command::new("loginctl")
+        .args(["show-session", &session_id, "-p", "LockedHint", "--value"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim() == "yes")
+}
+
+#[cfg(target_os = "macos")]
+fn is_screen_locked() -> Option<bool> {
+    // CGSession 정보에 잠금 키가 있으면 잠긴 상태
+    let output = std::process::Command::new("ioreg")
+        .args(["-n", "Root", "-d1"])
+        .output()
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).contains("\"CGSSessionScreenIsLocked\"=Yes"))
+}
+
+#[cfg(target_os = "windows")]
+fn is_screen_locked() -> Option<bool> {
+    // 잠금 화면(세션 잠금)이 떠 있는 동안에는 LogonUI.exe가 실행됨
+    let mut system = sysinfo::System::new();
+    system.refresh_processes();
+    let locked = system.processes_by_exact_name("LogonUI.exe").next().is_some();
+    Some(locked)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn is_screen_locked() -> Option<bool> {
+    None
+}
+
+// 모니터링 루프를 멈추고 카메라를 놓아 줍니다. (사용자의 모니터링 설정은 그대로 유지)
+fn auto_pause_monitoring(app_handle: &AppHandle, state: &AppState, reason: &'static str) {
+    {
+        let mut paused = state.auto_paused.lock();
+        if paused.is_some() {
+            return;
+        }
+        *paused = Some(reason);
+    }
+    info!("모니터링 자동 일시정지: {}", reason);
+    close_pool_cameras(state);
+    if let Some(mut cam) = state.camera.lock().take() {
+        if cam.is_stream_open() {
+            let _ = cam.stop_stream();
+        }
+    }
+    let _ = app_handle.emit("monitoring-auto-paused", &serde_json::json!({ "reason": reason }));
+}
+
+// 자동 일시정지를 풀고, 모니터링 중이었다면 카메라를 다시 엽니다.
+fn auto_resume_monitoring(app_handle: &AppHandle, state: &AppState, reason: &'static str) {
+    let Some(paused_by) = state.auto_paused.lock().take() else {
+        return;
+    };
+    info!("모니터링 자동 재개: {} (일시정지 이유: {})", reason, paused_by);
+    // 자리를 비운 동안의 감지 이력이 재개 직후 판정에 섞이지 않도록 비움
+    state.pose_analyzer.clear_recent_results();
+    if *state.monitoring_active.lock() {
+        reconnect_camera(app_handle, state);
+    }
+    let _ = app_handle.emit(
+        "monitoring-auto-resumed",
+        &serde_json::json!({ "reason": reason, "paused_by": paused_by }),
+    );
+}
+
+// 화면 잠금과 시스템 절전을 주기적으로 확인해 모니터링을 자동으로 멈추고 재개합니다.
+// 절전은 직접 알 수 없으므로 확인 주기보다 벽시계가 훨씬 많이 흘렀으면 절전에서 깨어난 것으로 봅니다.
+async fn background_system_state_task(app_handle: AppHandle, state: AppState) {
+    let poll = Duration::from_secs(SYSTEM_STATE_POLL_SECS);
+    let mut last_wall_clock = SystemTime::now();
+    loop {
+        tokio::select! {
+            _ = sleep(poll) => {}
+            _ = state.shutdown_requested() => break,
+        }
+
+        let now = SystemTime::now();
+        let elapsed = now.duration_since(last_wall_clock).unwrap_or_default();
+        last_wall_clock = now;
+        let already_paused = state.auto_paused.lock().is_some();
+        if !already_paused && elapsed > poll + Duration::from_secs(SYSTEM_SLEEP_GAP_SECS) {
+            info!("시스템 절전에서 복귀한 것으로 보입니다 ({}초 경과)", elapsed.as_secs());
+            // 절전 전 열려 있던 카메라 핸들은 깨어난 뒤 쓸 수 없는 경우가 많아 새로 엶
+            auto_pause_monitoring(&app_handle, &state, "system_sleep");
+            auto_resume_monitoring(&app_handle, &state, "system_wake");
+        }
+
+        // 모니터링 중이거나 화면 잠금으로 멈춘 상태일 때만 확인 (외부 명령 실행은 블로킹 스레드에서)
+        let paused_by_lock = *state.auto_paused.lock() == Some("screen_locked");
+        if !*state.monitoring_active.lock() && !paused_by_lock {
+            continue;
+        }
+        let locked = tauri::async_runtime::spawn_blocking(is_screen_locked)
+            .await
+            .ok()
+            .flatten();
+        match locked {
+            Some(true) => auto_pause_monitoring(&app_handle, &state, "screen_locked"),
+            Some(false) if *state.auto_paused.lock() == Some("screen_locked") => {
+                auto_resume_monitoring(&app_handle, &state, "screen_unlocked")
+            }
+            _ => {}
+        }
+    }
 }
 
-#[tauri::command]
-async fn get_available_cameras() -> Result<Vec<CameraDetail>, String> {
-    match nokhwa::query(ApiBackend::Auto) {
-        Ok(cameras) => {
-            info!("사용 가능한 카메라 {}개 발견", cameras.len());
-            let camera_details = cameras
-                .into_iter()
-                .map(|cam: CameraInfo| CameraDetail {
-                    index: cam.index().as_index().unwrap_or(0) as u32,
-                    name: cam.human_name(),
-                })
-                .collect();
-            Ok(camera_details)
-        }
-        Err(e) => {
-            error!("카메라 목록 조회 실패: {}", e);
-            Err(e.to_string())
+// 현재 카메라를 닫고 백엔드 탐색부터 다시 시작해 엽니다. (절약 모드에서는 다음 캡처 때 새로 열림)
+fn reconnect_camera(app_handle: &AppHandle, state: &AppState) {
+    *state.preferred_camera_backend.lock() = None;
+    if *state.battery_saving_mode.lock() {
+        return;
+    }
+    let mut cam_lock = state.camera.lock();
+    if let Some(mut cam) = cam_lock.take() {
+        if cam.is_stream_open() {
+            let _ = cam.stop_stream();
         }
     }
+    let index = *state.selected_camera_index.lock();
+    if let Some(cam) = open_camera_with_backoff(app_handle, state, index) {
+        info!("카메라 재연결 성공");
+        *cam_lock = Some(cam);
+    }
 }
 
-#[tauri::command]
-async fn set_selected_camera(state: State<'_, AppState>, index: u32) -> Result<(), String> {
-    info!("선택된 카메라 변경: index {}", index);
-    let mut current_cam_lock = state.camera.lock().unwrap();
+// 카메라 버퍼를 분석용 RGB 이미지로 변환
+fn decode_frame(buffer: &Buffer) -> Option<ImageBuffer<Rgb<u8>, Vec<u8>>> {
+    let decoded_image = buffer.decode_image::<RgbFormat>().ok()?;
+    ImageBuffer::<Rgb<u8>, _>::from_raw(
+        decoded_image.width(),
+        decoded_image.height(),
+        decoded_image.into_raw(),
+    )
+}
 
-    if *state.monitoring_active.lock().unwrap() && current_cam_lock.is_some() {
-        info!("모니터링 중 카메라 변경 시도...");
-        if let Some(mut cam) = current_cam_lock.take() {
+// 선택된 카메라에서 프레임 한 장을 가져옵니다.
+async fn capture_frame(app_handle: &AppHandle, state: &AppState) -> Option<Buffer> {
+    // 카메라가 없거나 종료 중이면 카메라를 새로 열지 않음
+    if *state.no_camera.lock() || state.is_shutting_down() {
+        return None;
+    }
+    let battery_saving = *state.battery_saving_mode.lock();
+    let selected_index = *state.selected_camera_index.lock();
+    if battery_saving {
+        info!("절약 모드: 카메라 캡처 시도, 인덱스 {}", selected_index);
+        // 절약 모드: 모니터링할 때만 카메라 켜고 끄기
+        let mut cam = open_camera_with_backoff(app_handle, state, selected_index)?;
+        let (warmup_ms, drop_delay_ms) = *state.camera_warmup_ms.lock();
+        info!("절약 모드: 카메라 스트림 열림 (준비 {}ms, 프레임 폐기 후 {}ms 대기)", warmup_ms, drop_delay_ms);
+        // 카메라 로딩을 위해 잠시 대기
+        tokio::time::sleep(Duration::from_millis(warmup_ms)).await;
+        // 첫 프레임을 버려서 최신 프레임을 얻음
+        let _ = cam.frame();
+        tokio::time::sleep(Duration::from_millis(drop_delay_ms)).await;
+        let buffer = cam.frame();
+        let result = if let Ok(buf) = buffer {
+            info!("절약 모드: 카메라 캡처 성공");
+            Some(buf)
+        } else {
+            error!("절약 모드: 카메라 캡처 실패: {:?}", buffer.err());
+            None
+        };
+        if let Err(e) = cam.stop_stream() {
+            error!("카메라 스트림 닫기 실패: {}", e);
+        } else {
+            info!("절약 모드: 카메라 스트림 닫음");
+        }
+        result
+    } else {
+        // 일반 모드: 기존 로직
+        let mut cam_lock = state.camera.lock();
+        if let Some(cam) = cam_lock.as_mut() {
             if cam.is_stream_open() {
-                let _ = cam.stop_stream();
+                cam.frame().ok()
+            } else {
+                None
             }
+        } else {
+            None
         }
+    }
+}
 
-        let requested =
-            RequestedFormat::new::<RgbFormat>(RequestedFormatType::AbsoluteHighestFrameRate);
-        match Camera::new(CameraIndex::Index(index), requested) {
-            Ok(mut new_cam) => {
-                info!("새 카메라 초기화 성공: {}", new_cam.info().human_name());
-                if let Err(e) = new_cam.open_stream() {
-                    error!("새 카메라 스트림 시작 실패: {}", e);
-                } else {
-                    info!("새 카메라 스트림 시작됨.");
-                    *current_cam_lock = Some(new_cam);
+async fn run_monitoring_cycle(app_handle: &AppHandle, state: &AppState) -> MonitoringCycle {
+    probe_camera_pool(app_handle, state);
+    let Some(buffer) = capture_frame(app_handle, state).await else {
+        return MonitoringCycle { frame_captured: false, confidence: None };
+    };
+    // 디코딩/추론 전에 캡처 순번을 받아 감지 이력의 순서 판단에 사용
+    let capture_sequence = state.pose_analyzer.next_capture_sequence();
+    let mut cycle = MonitoringCycle { frame_captured: true, confidence: None };
+
+    let Some(rgb_image) = decode_frame(&buffer) else {
+        return cycle;
+    };
+    info!("이미지 디코딩 성공");
+
+    // 회색/검은 화면은 모델에 넣지 않고 캡처 실패로 처리
+    if is_blank_frame(&rgb_image) {
+        let index = *state.selected_camera_index.lock();
+        warn!("인덱스 {}번 카메라에서 빈 프레임 수신, 재연결 시도", index);
+        let _ = app_handle.emit("camera-blank-frame", &serde_json::json!({ "index": index }));
+        reconnect_camera(app_handle, state);
+        return MonitoringCycle { frame_captured: false, confidence: None };
+    }
+    if let Some((brightness, _)) = frame_luma_stats(&rgb_image) {
+        check_low_light(app_handle, state, brightness);
+    }
+    let reused = reuse_result_if_static(state, &rgb_image);
+    let frame_skipped = reused.is_some();
+    let result_str = match reused {
+        Some(previous) => {
+            info!("직전 프레임과 거의 같아 추론을 건너뜀");
+            state.metrics.record_skipped_frame();
+            previous
+        }
+        None => {
+            let Ok(result_str) = state
+                .pose_analyzer
+                .analyze_image_buffer_at(&rgb_image, capture_sequence)
+            else {
+                update_presence(app_handle, state, false);
+                state.motion_gate.lock().previous_result = None;
+                return cycle;
+            };
+            info!("자세 분석 성공");
+            state.motion_gate.lock().previous_result = Some(result_str.clone());
+            result_str
+        }
+    };
+    let Ok(result_json) = serde_json::from_str::<Value>(&result_str) else {
+        return cycle;
+    };
+    cycle.confidence = result_json.get("confidence").and_then(|v| v.as_f64());
+    if result_json.get("status").and_then(|v| v.as_str()) == Some("yolo_analysis_success") {
+        update_presence(app_handle, state, true);
+    }
+    // 재사용한 결과는 추론 시간과 감지 횟수에 다시 반영하지 않음
+    if !frame_skipped {
+        state.metrics.record_analysis(&result_json);
+    }
+    check_calibration_drift(app_handle, state);
+
+    let _ = handle_analysis_result(app_handle, state, &result_json, Some(&rgb_image)).await;
+    cycle
+}
+
+// 카메라 풀의 각 카메라에서 프레임을 받아 사람이 가장 잘 보이는 카메라로 전환합니다.
+// 배터리 절약 모드에서는 캡처 때마다 카메라를 새로 열기 때문에 비교하지 않습니다.
+fn probe_camera_pool(app_handle: &AppHandle, state: &AppState) {
+    if *state.battery_saving_mode.lock() || *state.no_camera.lock() {
+        return;
+    }
+    let (indices, keep_open) = {
+        let mut pool = state.camera_pool.lock();
+        if pool.indices.len() < 2 {
+            return;
+        }
+        let due = pool.keep_open
+            || pool.last_probe.map_or(true, |last| {
+                last.elapsed() >= Duration::from_secs(CAMERA_POOL_PROBE_INTERVAL_SECS)
+            });
+        if !due {
+            return;
+        }
+        pool.last_probe = Some(Instant::now());
+        (pool.indices.clone(), pool.keep_open)
+    };
+
+    let current = *state.selected_camera_index.lock();
+    let mut qualities: Vec<(u32, f32)> = Vec::new();
+    for index in indices {
+        let frame = if index == current {
+            state
+                .camera
+                .lock()
+                .as_mut()
+                .filter(|cam| cam.is_stream_open())
+                .and_then(|cam| cam.frame().ok())
+        } else if keep_open {
+            let mut pool = state.camera_pool.lock();
+            if !pool.open_cameras.contains_key(&index) {
+                match open_camera_any_backend(state, index) {
+                    Ok(cam) => {
+                        pool.open_cameras.insert(index, cam);
+                    }
+                    Err(e) => warn!("카메라 풀: 인덱스 {}번 카메라 열기 실패: {}", index, e),
                 }
             }
-            Err(e) => {
-                error!("인덱스 {}번 새 카메라 초기화 실패: {}", index, e);
+            pool.open_cameras.get_mut(&index).and_then(|cam| cam.frame().ok())
+        } else {
+            match open_camera_any_backend(state, index) {
+                Ok(mut cam) => {
+                    let frame = cam.frame().ok();
+                    let _ = cam.stop_stream();
+                    frame
+                }
+                Err(e) => {
+                    warn!("카메라 풀: 인덱스 {}번 카메라 열기 실패: {}", index, e);
+                    None
+                }
             }
+        };
+        let quality = frame
+            .as_ref()
+            .and_then(decode_frame)
+            .filter(|image| !is_blank_frame(image))
+            .and_then(|image| state.pose_analyzer.view_quality(&image))
+            .unwrap_or(0.0);
+        qualities.push((index, quality));
+    }
+
+    let current_quality = qualities
+        .iter()
+        .find(|(index, _)| *index == current)
+        .map_or(0.0, |(_, quality)| *quality);
+    let Some(&(best, best_quality)) = qualities.iter().max_by(|a, b| a.1.total_cmp(&b.1)) else {
+        return;
+    };
+    if best == current || best_quality < current_quality + CAMERA_POOL_SWITCH_MARGIN {
+        return;
+    }
+
+    info!(
+        "카메라 풀: 인덱스 {}번({:.2}) → {}번({:.2})으로 전환",
+        current, current_quality, best, best_quality
+    );
+    let mut cam_lock = state.camera.lock();
+    let mut pool = state.camera_pool.lock();
+    if let Some(mut previous) = cam_lock.take() {
+        if keep_open {
+            pool.open_cameras.insert(current, previous);
+        } else if previous.is_stream_open() {
+            let _ = previous.stop_stream();
         }
     }
+    let next = match pool.open_cameras.remove(&best) {
+        Some(cam) => {
+            note_camera_source(state, best, cam.camera_format());
+            Some(cam)
+        }
+        None => open_camera_with_backoff(app_handle, state, best),
+    };
+    *cam_lock = next;
+    *state.selected_camera_index.lock() = best;
+    let _ = app_handle.emit(
+        "camera-pool-switched",
+        &serde_json::json!({ "from": current, "to": best, "quality": best_quality }),
+    );
+}
 
-    *state.selected_camera_index.lock().unwrap() = index;
-    Ok(())
+// 열어 둔 카메라 풀 후보 카메라를 모두 닫습니다.
+fn close_pool_cameras(state: &AppState) {
+    let cameras: Vec<Camera> = state.camera_pool.lock().open_cameras.drain().map(|(_, cam)| cam).collect();
+    for mut cam in cameras {
+        if cam.is_stream_open() {
+            let _ = cam.stop_stream();
+        }
+    }
 }
 
-#[tauri::command]
-async fn set_detection_settings(
-    state: State<'_, AppState>,
-    frequency: u8,
-    turtle_sensitivity: u8,
-    shoulder_sensitivity: u8,
-) -> Result<(), String> {
-    state.pose_analyzer.set_notification_frequency(frequency);
-    state
-        .pose_analyzer
-        .set_turtle_neck_sensitivity(turtle_sensitivity);
-    state
-        .pose_analyzer
-        .set_shoulder_sensitivity(shoulder_sensitivity);
-    Ok(())
+// 움직임 감지 생략이 켜져 있고 직전 프레임과의 평균 픽셀 차이가 기준보다 작으면 직전 결과를 반환합니다.
+// 너무 오래 같은 결과가 쓰이지 않도록 연속 재사용 횟수에 상한을 둡니다.
+fn reuse_result_if_static(state: &AppState, frame: &ImageBuffer<Rgb<u8>, Vec<u8>>) -> Option<String> {
+    let mut gate = state.motion_gate.lock();
+    let Some(threshold) = *state.motion_gating_threshold.lock() else {
+        *gate = MotionGate::default();
+        return None;
+    };
+    let small = image::imageops::grayscale(&image::imageops::thumbnail(
+        frame,
+        MOTION_GATING_FRAME_SIZE,
+        MOTION_GATING_FRAME_SIZE,
+    ))
+    .into_raw();
+    let diff = gate
+        .previous_frame
+        .as_ref()
+        .filter(|previous| previous.len() == small.len())
+        .map(|previous| {
+            previous
+                .iter()
+                .zip(small.iter())
+                .map(|(a, b)| a.abs_diff(*b) as u64)
+                .sum::<u64>() as f32
+                / small.len().max(1) as f32
+        });
+    gate.previous_frame = Some(small);
+
+    let reusable = gate.consecutive_skips < MOTION_GATING_MAX_CONSECUTIVE_SKIPS
+        && diff.is_some_and(|diff| diff < threshold);
+    match gate.previous_result.clone() {
+        Some(result) if reusable => {
+            gate.consecutive_skips += 1;
+            Some(result)
+        }
+        _ => {
+            gate.consecutive_skips = 0;
+            None
+        }
+    }
 }
 
-#[tauri::command]
-async fn set_monitoring_interval(
-    state: State<'_, AppState>,
-    interval_secs: Option<u64>,
-    interval_mins: Option<u64>,
-) -> Result<(), String> {
-    let interval_secs_final = if let Some(secs) = interval_secs {
-        secs
-    } else if let Some(mins) = interval_mins {
-        mins * 60
-    } else {
-        3
+// 측정 비율이 기준 자세에서 오랫동안 벗어나 있으면 recalibration_suggested 이벤트를 한 번 보냅니다.
+fn check_calibration_drift(app_handle: &AppHandle, state: &AppState) {
+    if !*state.recalibration_nudge.lock() {
+        return;
+    }
+    if let Some((median, baseline)) = state.pose_analyzer.take_recalibration_suggestion() {
+        info!("캘리브레이션 드리프트 감지: 기준 {:.3}, 최근 중앙값 {:.3}", baseline, median);
+        let _ = app_handle.emit(
+            "recalibration_suggested",
+            &serde_json::json!({ "baseline": baseline, "median": median }),
+        );
+    }
+}
+
+// 기록된 샘플의 썸네일을 최근 프레임 캐시에 보관합니다. (개인정보 보호 모드에서는 보관하지 않음)
+fn cache_recent_frame(state: &AppState, timestamp_ms: i64, frame: &ImageBuffer<Rgb<u8>, Vec<u8>>) {
+    if *state.privacy_mode.lock() {
+        return;
+    }
+    let quality = *state.image_quality.lock();
+    let Some(jpeg) = encode_thumbnail(frame, quality) else {
+        return;
     };
-    info!("모니터링 주기 변경: {}초", interval_secs_final);
-    *state.monitoring_interval_secs.lock().unwrap() = interval_secs_final;
-    Ok(())
+    let mut frames = state.recent_frames.lock();
+    while frames.len() >= RECENT_FRAME_CACHE_SIZE {
+        frames.pop_front();
+    }
+    frames.push_back(CachedFrame { timestamp_ms, jpeg });
 }
 
-#[tauri::command]
-async fn set_battery_saving_mode(state: State<'_, AppState>, mode: bool) -> Result<(), String> {
-    *state.battery_saving_mode.lock().unwrap() = mode;
-    info!("배터리 절약 모드 설정: {}", mode);
+fn encode_thumbnail(frame: &ImageBuffer<Rgb<u8>, Vec<u8>>, quality: u8) -> Option<Vec<u8>> {
+    let height = (frame.height() as u64 * THUMBNAIL_WIDTH as u64 / frame.width().max(1) as u64).max(1) as u32;
+    let thumbnail = image::imageops::thumbnail(frame, THUMBNAIL_WIDTH, height);
+    match encode_jpeg(&thumbnail, quality) {
+        Ok(jpeg) => Some(jpeg),
+        Err(e) => {
+            warn!("썸네일 인코딩 실패: {}", e);
+            None
+        }
+    }
+}
 
-    if mode {
-        // 절약 모드: 기존 카메라 닫기
-        if let Some(mut cam) = state.camera.lock().unwrap().take() {
-            if cam.is_stream_open() {
-                if let Err(e) = cam.stop_stream() {
-                    error!("절약 모드 전환 시 카메라 스트림 닫기 실패: {}", e);
-                } else {
-                    info!("절약 모드 전환 시 카메라 스트림 닫음.");
-                }
-            }
+fn encode_jpeg(image: &ImageBuffer<Rgb<u8>, Vec<u8>>, quality: u8) -> image::ImageResult<Vec<u8>> {
+    let mut jpeg = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg, quality).encode_image(image)?;
+    Ok(jpeg)
+}
+
+// 사람이 감지되었는지에 따라 연속으로 앉아 있는 시간을 갱신하고, 설정 시간을 넘기면 한 번 경고합니다.
+// 유예 시간보다 오래 감지되지 않으면 자리를 비운 것으로 보고 타이머를 초기화합니다.
+// 한 번도 감지된 적이 없으면 자리 비움 이벤트를 보내지 않습니다.
+fn update_presence(app_handle: &AppHandle, state: &AppState, detected: bool) {
+    let limit = Duration::from_secs(*state.sitting_limit_mins.lock() * 60);
+    let grace = effective_away_grace(state);
+    let now = Instant::now();
+    let mut presence = state.presence.lock();
+
+    let away = presence
+        .last_seen
+        .map(|last_seen| now.duration_since(last_seen) > grace)
+        .unwrap_or(true);
+    if !detected {
+        let seen_before = presence.last_seen.is_some();
+        if away && presence.sitting_since.is_some() {
+            info!("자리 비움 감지: 앉아 있는 시간 초기화");
+            *presence = PresenceTracker::default();
         }
-    } else {
-        // 일반 모드: 모니터링 중이면 카메라 열기
-        if *state.monitoring_active.lock().unwrap() {
-            let mut cam_lock = state.camera.lock().unwrap();
-            if cam_lock.is_none() {
-                let index = *state.selected_camera_index.lock().unwrap();
-                let requested = RequestedFormat::new::<RgbFormat>(RequestedFormatType::AbsoluteHighestFrameRate);
-                match Camera::new(CameraIndex::Index(index), requested) {
-                    Ok(mut cam) => {
-                        if let Err(e) = cam.open_stream() {
-                            error!("일반 모드 전환 시 카메라 스트림 열기 실패: {}", e);
-                        } else {
-                            info!("일반 모드 전환 시 카메라 스트림 열음.");
-                            *cam_lock = Some(cam);
-                        }
-                    }
-                    Err(e) => {
-                        error!("일반 모드 전환 시 카메라 초기화 실패: {}", e);
-                    }
-                }
-            }
+        if away && seen_before && !presence.away {
+            presence.away = true;
+            let _ = app_handle.emit("user-away", &serde_json::json!({ "grace_secs": grace.as_secs() }));
         }
+        return;
+    }
+    if away {
+        presence.sitting_since = Some(now);
+        presence.warned = false;
+    }
+    if presence.away {
+        presence.away = false;
+        info!("사용자 복귀 감지");
+        let _ = app_handle.emit("user-returned", &serde_json::json!({}));
+    }
+    presence.last_seen = Some(now);
+
+    let sitting_for = presence
+        .sitting_since
+        .map(|since| now.duration_since(since))
+        .unwrap_or_default();
+    if limit.is_zero() || presence.warned || sitting_for < limit {
+        return;
+    }
+    presence.warned = true;
+    drop(presence);
+
+    let minutes = sitting_for.as_secs() / 60;
+    info!("{}분 동안 계속 앉아 있음", minutes);
+    let _ = app_handle.emit("sitting_too_long", &serde_json::json!({ "minutes": minutes }));
+    if !*state.demo_mode.lock() {
+        let lang = state.current_language.lock().clone();
+        let message = state
+            .translations
+            .get(&lang, "alert_sitting_too_long")
+            .replace("{minutes}", &minutes.to_string());
+        queue_alert(state, AlertKind::SittingTooLong, message, None);
     }
-    Ok(())
 }
 
-#[tauri::command]
-async fn set_current_language(state: State<'_, AppState>, lang: String) -> Result<(), String> {
-    info!("현재 언어 변경: {}", lang);
-    *state.current_language.lock().unwrap() = lang;
+async fn insert_posture_sample(pool: &sqlx::SqlitePool, sample: &PendingSample) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO posture_log (score, is_turtle_neck, is_shoulder_misaligned, timestamp, activity, is_test) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(sample.score)
+        .bind(sample.is_turtle)
+        .bind(sample.is_shoulder)
+        .bind(sample.timestamp_ms)
+        .bind(&sample.activity)
+        .bind(sample.is_test)
+        .execute(pool)
+        .await?;
     Ok(())
 }
 
-#[tauri::command]
-async fn restart_app(app: tauri::AppHandle) -> Result<(), String> {
-    info!("앱 재시작 요청");
-    // 현재 실행 파일 경로 가져오기
-    if let Ok(exe_path) = std::env::current_exe() {
-        let exe_dir = exe_path.parent().unwrap_or(&exe_path);
-        let exe_name = exe_path.file_name().unwrap_or_default().to_string_lossy();
-
-        // 새 프로세스로 앱 재시작
-        let _ = std::process::Command::new(&exe_path)
-            .current_dir(exe_dir)
-            .spawn();
+// 저장하지 못한 기록을 대기열에 넣습니다. 가득 차면 가장 오래된 기록부터 버립니다.
+fn queue_pending_sample(state: &AppState, sample: PendingSample) {
+    let mut pending = state.pending_samples.lock();
+    if pending.len() >= PENDING_SAMPLE_CAPACITY {
+        pending.pop_front();
+        warn!("저장 대기열이 가득 차 가장 오래된 기록을 버립니다.");
+    }
+    pending.push_back(sample);
+}
 
-        // 현재 앱 종료
-        app.exit(0);
-    } else {
-        return Err("실행 파일 경로를 찾을 수 없습니다.".to_string());
+// 대기 중인 기록을 순서대로 저장합니다. 실패하면 남은 기록을 그대로 두고 다음 주기에 다시 시도합니다.
+async fn flush_pending_samples(state: &AppState, pool: &sqlx::SqlitePool) {
+    let mut pending = std::mem::take(&mut *state.pending_samples.lock());
+    if pending.is_empty() {
+        return;
     }
-    Ok(())
+    let total = pending.len();
+    while let Some(sample) = pending.front() {
+        if let Err(e) = insert_posture_sample(pool, sample).await {
+            warn!("대기 중인 기록 저장 실패 ({}건 남음): {}", pending.len(), e);
+            break;
+        }
+        pending.pop_front();
+    }
+    let flushed = total - pending.len();
+    if flushed > 0 {
+        info!("대기 중이던 기록 {}건 저장 완료", flushed);
+    }
+    // 그 사이 새로 쌓인 기록보다 앞에 오도록 되돌림
+    let mut queue = state.pending_samples.lock();
+    pending.extend(queue.drain(..));
+    while pending.len() > PENDING_SAMPLE_CAPACITY {
+        pending.pop_front();
+    }
+    *queue = pending;
 }
 
-// --- Background Tasks ---
+// 분석 결과를 프론트엔드에 전달하고, 기록 및 알림을 처리합니다. 시스템 알림을 대기열에 넣었으면 true를 반환합니다.
+async fn handle_analysis_result(
+    app_handle: &AppHandle,
+    state: &AppState,
+    result_json: &Value,
+    frame: Option<&ImageBuffer<Rgb<u8>, Vec<u8>>>,
+) -> bool {
+    let _ = app_handle.emit("analysis-update", result_json);
+    // 사람이 확실히 감지된 결과만 기록해, 자리를 비웠거나 모델이 준비되지 않은 시간이 통계를 왜곡하지 않게 함
+    if result_json.get("status").and_then(|v| v.as_str()) != Some("yolo_analysis_success") {
+        return false;
+    }
+    let score = result_json
+        .get("posture_score")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0);
+    let is_turtle = result_json
+        .get("turtle_neck")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let is_shoulder = result_json
+        .get("shoulder_misalignment")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let is_test = result_json.get("test").and_then(|v| v.as_bool()).unwrap_or(false);
+    info!("감지 결과: 거북목 {}, 어깨 {}{}", is_turtle, is_shoulder, if is_test { " (테스트)" } else { "" });
+    // 테스트 감지는 실제 자세가 아니므로 최근 점수와 세션 녹화에 넣지 않음
+    if !is_test {
+        record_recent_score(state, score);
+        record_session_frame(state);
+    }
+    if *state.demo_mode.lock() {
+        return false;
+    }
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64;
 
-async fn background_alert_task(app_handle: AppHandle, state: AppState) {
-    let mut interval = tokio::time::interval(Duration::from_secs(3));
-    loop {
-        interval.tick().await;
-        let messages_to_send = {
-            let mut alert_messages = state.alert_messages.lock().unwrap();
-            if !alert_messages.is_empty() {
-                let message = alert_messages.drain(..).collect::<Vec<_>>().join("\n");
-                Some(message)
-            } else {
-                None
+    let logging_enabled = *state.logging_enabled.lock();
+    if !logging_enabled {
+        info!("자세 기록 저장이 꺼져 있어 DB에 기록하지 않습니다.");
+    } else {
+        let activity = state.current_activity.lock().clone();
+        let sample = PendingSample { score, is_turtle, is_shoulder, timestamp_ms, activity, is_test };
+        match get_sqlite_pool(app_handle).await {
+            Some(sqlite_pool) => {
+                flush_pending_samples(state, &sqlite_pool).await;
+                if let Err(e) = insert_posture_sample(&sqlite_pool, &sample).await {
+                    error!("데이터베이스 저장 실패, 다음 주기에 다시 시도: {}", e);
+                    queue_pending_sample(state, sample);
+                } else if let Some(frame) = frame {
+                    cache_recent_frame(state, timestamp_ms, frame);
+                }
             }
-        };
-
-        if let Some(message) = messages_to_send {
-            if message.is_empty() {
-                continue;
+            None => {
+                warn!("데이터베이스를 아직 사용할 수 없어 기록을 보관합니다.");
+                queue_pending_sample(state, sample);
             }
+        }
+    }
+
+    let confirmed_score = result_json
+        .get("confirmed_posture_score")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(score);
+    let tier = state
+        .alert_tiers
+        .lock()
+        .classify_confirmed(confirmed_score, is_turtle || is_shoulder);
+    let previous_tier = std::mem::replace(&mut *state.posture_tier.lock(), tier);
+    if previous_tier != tier {
+        let _ = app_handle.emit(
+            "posture-tier-changed",
+            &serde_json::json!({ "tier": tier, "previous": previous_tier, "score": confirmed_score }),
+        );
+    }
+
+    // 감도 확인 중에는 감지될 때마다 확인 이벤트만 보내고 시스템 알림은 보내지 않음
+    let sensitivity_check = state.sensitivity_check.lock().is_some();
+    // 모니터링 시작 직후 자리를 잡는 동안에는 자세 알림을 보내지 않음 (테스트 감지는 항상 알림)
+    let in_startup_grace = !is_test && !startup_grace_remaining(state).is_zero();
+    if (is_turtle || is_shoulder) && sensitivity_check && !is_test {
+        let _ = app_handle.emit(
+            "sensitivity-check-triggered",
+            &serde_json::json!({ "score": score, "turtle_neck": is_turtle, "shoulder_misalignment": is_shoulder }),
+        );
+    } else if (is_turtle || is_shoulder) && in_startup_grace {
+        info!("모니터링 시작 후 준비 시간이라 자세 알림을 보내지 않습니다.");
+    } else if (is_turtle || is_shoulder) && tier == "warning" {
+        // 주의 단계: 시스템 알림 없이 앱 안에서만 가볍게 알림
+        let _ = app_handle.emit(
+            "posture-warning",
+            &serde_json::json!({ "score": score, "turtle_neck": is_turtle, "shoulder_misalignment": is_shoulder }),
+        );
+    } else if (is_turtle || is_shoulder) && tier == "bad" {
+        let mut last_alert = state.last_alert_time.lock();
+        if last_alert.elapsed() >= Duration::from_secs(10) {
+            let lang = state.current_language.lock().clone();
+            let translations = &state.translations;
 
-            info!("시스템 알림 발생: {}", &message);
+            let (kind, message_key) = if is_turtle && is_shoulder {
+                (AlertKind::TurtleNeckAndShoulder, "alert_both")
+            } else if is_turtle {
+                (AlertKind::TurtleNeck, "alert_turtle")
+            } else {
+                (AlertKind::ShoulderMisaligned, "alert_shoulder")
+            };
 
-            // ✨ 이것이 Tauri v2의 표준적인 알림 호출 방식입니다.
-            let builder = app_handle.notification().builder();
-            let result = builder
-                .title("🐢")
-                .body(&message)
-                .icon("icons/icon.png".to_string())
-                .show();
+            // 같은 알림이 반복될 때 매번 다른 문구가 나가도록 문구 묶음에서 돌아가며 선택
+            info!("번역 시도: lang='{}', key='{}'", lang, message_key);
+            let variants = translations.variants(&lang, message_key);
+            let index = state
+                .pose_analyzer
+                .next_tip_variant(message_key, variants.len(), Duration::ZERO);
+            let message = variants[index].clone();
+            info!("번역 결과: '{}'", message);
 
-            if let Err(e) = result {
-                error!("시스템 알림을 보내는 데 실패했습니다: {}", e);
+            queue_alert(state, kind, message, Some(score));
+            // 테스트 감지는 쿨다운만 확인하고, 실제 감지 상태(쿨다운 시각, 최근 결과)는 건드리지 않음
+            if !is_test {
+                *last_alert = Instant::now();
+                // 최근 결과 초기화
+                state.pose_analyzer.clear_recent_results();
             }
+            return true;
         }
     }
+    false
 }
 
-async fn background_monitoring_task(app_handle: AppHandle, state: AppState) {
-    loop {
-        let interval_duration = {
-            let secs = *state.monitoring_interval_secs.lock().unwrap();
-            Duration::from_secs(secs)
-        };
-        sleep(interval_duration).await;
+// 백그라운드 루프에 종료를 알리고 진행 중인 분석 주기가 끝나도록 잠시 기다린 뒤,
+// 저장하지 못한 기록을 DB에 쓰고 카메라를 닫습니다. 처음 종료를 요청한 경우에만 true를 반환합니다.
+async fn shutdown_background_tasks(app_handle: &AppHandle, state: &AppState) -> bool {
+    if state.shutdown.send_replace(true) {
+        return false;
+    }
+    info!("백그라운드 작업 종료 요청");
+    if let Some(server) = state.metrics_server.lock().take() {
+        server.abort();
+    }
+    let tasks: Vec<_> = state.background_tasks.lock().drain(..).collect();
+    if join_background_tasks(tasks, Duration::from_secs(SHUTDOWN_TIMEOUT_SECS)).await {
+        info!("백그라운드 작업이 모두 종료되었습니다.");
+    } else {
+        warn!("일부 백그라운드 작업이 {}초 안에 종료되지 않았습니다.", SHUTDOWN_TIMEOUT_SECS);
+    }
 
-        if !*state.monitoring_active.lock().unwrap() {
-            continue;
+    let pending = state.pending_samples.lock().len();
+    if pending > 0 {
+        match get_sqlite_pool(app_handle).await {
+            Some(pool) => flush_pending_samples(state, &pool).await,
+            None => warn!("데이터베이스를 사용할 수 없어 대기 중인 기록 {}건을 저장하지 못했습니다.", pending),
         }
+    }
+    release_camera(state);
+    true
+}
 
-        let battery_saving = *state.battery_saving_mode.lock().unwrap();
-        let selected_index = *state.selected_camera_index.lock().unwrap();
-        let buffer_option = if battery_saving {
-            info!("절약 모드: 카메라 캡처 시도, 인덱스 {}", selected_index);
-            // 절약 모드: 모니터링할 때만 카메라 켜고 끄기
-            let requested = RequestedFormat::new::<RgbFormat>(RequestedFormatType::AbsoluteHighestFrameRate);
-            match Camera::new(CameraIndex::Index(selected_index), requested) {
-                Ok(mut cam) => {
-                    if let Err(e) = cam.open_stream() {
-                        error!("카메라 스트림 열기 실패: {}", e);
-                        None
-                    } else {
-                        info!("절약 모드: 카메라 스트림 열림");
-                        // 카메라 로딩을 위해 잠시 대기
-                        tokio::time::sleep(Duration::from_secs(3)).await;
-                        // 첫 프레임을 버려서 최신 프레임을 얻음
-                        let _ = cam.frame();
-                        tokio::time::sleep(Duration::from_millis(100)).await;
-                        let buffer = cam.frame();
-                        let result = if let Ok(buf) = buffer {
-                            info!("절약 모드: 카메라 캡처 성공");
-                            Some(buf)
-                        } else {
-                            error!("절약 모드: 카메라 캡처 실패: {:?}", buffer.err());
-                            None
-                        };
-                        if let Err(e) = cam.stop_stream() {
-                            error!("카메라 스트림 닫기 실패: {}", e);
-                        } else {
-                            info!("절약 모드: 카메라 스트림 닫음");
-                        }
-                        result
-                    }
-                }
-                Err(e) => {
-                    error!("카메라 초기화 실패: {}", e);
-                    None
-                }
-            }
-        } else {
-            // 일반 모드: 기존 로직
-            let mut cam_lock = state.camera.lock().unwrap();
-            if let Some(cam) = cam_lock.as_mut() {
-                if cam.is_stream_open() {
-                    cam.frame().ok()
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        };
+// 작업이 모두 끝날 때까지 기다립니다. 제한 시간 안에 모두 끝나면 true
+async fn join_background_tasks(tasks: Vec<tauri::async_runtime::JoinHandle<()>>, limit: Duration) -> bool {
+    tokio::time::timeout(limit, async {
+        for task in tasks {
+            let _ = task.await;
+        }
+    })
+    .await
+    .is_ok()
+}
 
-        if let Some(buffer) = buffer_option {
-            info!("절약 모드: 이미지 디코딩 시작");
-            if let Ok(decoded_image) = buffer.decode_image::<RgbFormat>() {
-                info!("절약 모드: 이미지 디코딩 성공");
-                if let Some(rgb_image) = ImageBuffer::<Rgb<u8>, _>::from_raw(
-                    decoded_image.width(),
-                    decoded_image.height(),
-                    decoded_image.into_raw(),
-                ) {
-                    if let Ok(result_str) = state.pose_analyzer.analyze_image_buffer(&rgb_image) {
-                        info!("절약 모드: 자세 분석 성공");
-                        if let Ok(result_json) = serde_json::from_str::<Value>(&result_str) {
-                            let _ = app_handle.emit("analysis-update", &result_json);
-                            let score = result_json
-                                .get("posture_score")
-                                .and_then(|v| v.as_i64())
-                                .unwrap_or(0);
-                            let is_turtle = result_json
-                                .get("turtle_neck")
-                                .and_then(|v| v.as_bool())
-                                .unwrap_or(false);
-                            let is_shoulder = result_json
-                                .get("shoulder_misalignment")
-                                .and_then(|v| v.as_bool())
-                                .unwrap_or(false);
-                            info!("절약 모드: 거북목 {}, 어깨 {}", is_turtle, is_shoulder);
-                            let timestamp = SystemTime::now()
-                                .duration_since(UNIX_EPOCH)
-                                .unwrap()
-                                .as_secs() as i64;
-
-                            let instances = app_handle.state::<DbInstances>();
-                            let db_map = instances.0.read().await;
-
-                            // ✨ 수정: 중첩된 if let을 하나로 합쳐서 경고 제거
-                            if let Some(tauri_plugin_sql::DbPool::Sqlite(sqlite_pool)) =
-                                db_map.get("sqlite:posture_data.db")
-                            {
-                                let query = "INSERT INTO posture_log (score, is_turtle_neck, is_shoulder_misaligned, timestamp) VALUES (?, ?, ?, ?)";
-                                if let Err(e) = sqlx::query(query)
-                                    .bind(score)
-                                    .bind(is_turtle)
-                                    .bind(is_shoulder)
-                                    .bind(timestamp)
-                                    .execute(sqlite_pool)
-                                    .await
-                                {
-                                    error!("데이터베이스 저장 실패: {}", e);
-                                }
-                            }
-
-                            if is_turtle || is_shoulder {
-                                let mut last_alert = state.last_alert_time.lock().unwrap();
-                                if last_alert.elapsed() >= Duration::from_secs(10) {
-                                    let lang = state.current_language.lock().unwrap().clone();
-                                    let translations = &state.translations;
-
-                                    let message_key = if is_turtle && is_shoulder {
-                                        "alert_both"
-                                    } else if is_turtle {
-                                        "alert_turtle"
-                                    } else {
-                                        "alert_shoulder"
-                                    };
-
-                                    info!("번역 시도: lang='{}', key='{}'", lang, message_key);
-                                    let message = translations.get(&lang, message_key);
-                                    info!("번역 결과: '{}'", message);
-
-                                    state.alert_messages.lock().unwrap().push(message);
-                                    *last_alert = Instant::now();
-                                    // 최근 결과 초기화
-                                    state.pose_analyzer.clear_recent_results();
-                                }
-                            }
-                        }
-                    }
-                }
+// 앱 종료 시 열려 있는 웹캠 스트림을 닫습니다.
+fn release_camera(state: &AppState) {
+    let camera_to_stop = state.camera.lock().take();
+    if let Some(mut cam) = camera_to_stop {
+        if cam.is_stream_open() {
+            if let Err(e) = cam.stop_stream() {
+                error!("웹캠 스트림 종료 실패: {}", e);
+            } else {
+                info!("웹캠 스트림을 안전하게 종료했습니다.");
             }
         }
     }
@@ -614,24 +5917,66 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_autostart::Builder::new().build())
+        .plugin(tauri_plugin_global_shortcut::Builder::new()
+            .with_handler(|app, _shortcut, event| {
+                if event.state() == tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                    toggle_monitoring_from_shortcut(app);
+                }
+            })
+            .build())
         .plugin(tauri_plugin_shell::init()) 
         .plugin(tauri_plugin_os::init())
-        .plugin(tauri_plugin_log::Builder::new().targets([Target::new(TargetKind::Stdout), Target::new(TargetKind::Webview)]).level(LevelFilter::Info).build())
+        .plugin(tauri_plugin_log::Builder::new().targets([
+            Target::new(TargetKind::Stdout),
+            Target::new(TargetKind::Webview),
+            // 지원 번들에 최근 로그를 넣을 수 있도록 로그 디렉토리에도 기록
+            Target::new(TargetKind::LogDir { file_name: None }),
+        ]).level(LevelFilter::Info).build())
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_sql::Builder::new()
             .add_migrations(
-                "sqlite:posture_data.db",
-                vec![Migration {
-                    version: 1,
-                    description: "create posture log table",
-                    sql: "CREATE TABLE IF NOT EXISTS posture_log (id INTEGER PRIMARY KEY AUTOINCREMENT, score INTEGER NOT NULL, is_turtle_neck BOOLEAN NOT NULL, is_shoulder_misaligned BOOLEAN NOT NULL, timestamp INTEGER NOT NULL);",
-                    kind: MigrationKind::Up,
-                }],
+                DB_URL,
+                vec![
+                    Migration {
+                        version: 1,
+                        description: "create posture log table",
+                        sql: "CREATE TABLE IF NOT EXISTS posture_log (id INTEGER PRIMARY KEY AUTOINCREMENT, score INTEGER NOT NULL, is_turtle_neck BOOLEAN NOT NULL, is_shoulder_misaligned BOOLEAN NOT NULL, timestamp INTEGER NOT NULL);",
+                        kind: MigrationKind::Up,
+                    },
+                    Migration {
+                        version: 2,
+                        description: "create posture hourly summary table",
+                        sql: "CREATE TABLE IF NOT EXISTS posture_hourly (hour_start INTEGER PRIMARY KEY, sample_count INTEGER NOT NULL, avg_score REAL NOT NULL, turtle_neck_count INTEGER NOT NULL, shoulder_misaligned_count INTEGER NOT NULL, updated_at INTEGER NOT NULL);",
+                        kind: MigrationKind::Up,
+                    },
+                    Migration {
+                        version: 3,
+                        description: "add activity label to posture log",
+                        sql: "ALTER TABLE posture_log ADD COLUMN activity TEXT;",
+                        kind: MigrationKind::Up,
+                    },
+                    Migration {
+                        version: 4,
+                        description: "store posture log timestamps in milliseconds",
+                        sql: "UPDATE posture_log SET timestamp = timestamp * 1000 WHERE timestamp < 100000000000;",
+                        kind: MigrationKind::Up,
+                    },
+                    Migration {
+                        version: 5,
+                        description: "mark test detections in posture log",
+                        sql: "ALTER TABLE posture_log ADD COLUMN is_test BOOLEAN NOT NULL DEFAULT 0;",
+                        kind: MigrationKind::Up,
+                    },
+                ],
             ).build())
         .setup(|app| {
+            // 충돌로 설정 파일이 깨졌어도 시작은 되도록, 설정을 하나라도 읽기 전에 읽을 수 없는 파일만 먼저 초기화
+            if let Err(e) = repair_settings_store(app.handle(), false) {
+                error!("설정 파일 검사 실패: {}", e);
+            }
             let quit = PredefinedMenuItem::quit(app, Some("Quit Pose Nudge"))?;
             let show = MenuItem::with_id(app, "show", "Show App", true, None::<&str>)?;
             let start_monitoring_item = MenuItem::with_id(app, "start_monitoring", "Start Monitoring", true, None::<&str>)?;
@@ -640,56 +5985,117 @@ pub fn run() {
             
             #[cfg(target_os = "macos")]
 			app.set_activation_policy(tauri::ActivationPolicy::Accessory);
-            // 데스크탑에서 autostart(자동 시작) 등록 시도
+            // 데스크탑에서 저장된 자동 시작 설정을 적용 (설정이 없으면 기존처럼 켜짐)
             #[cfg(desktop)]
             {
-                // ManagerExt 트레잇을 로컬 스코프에서 가져와 app.autolaunch()를 사용합니다.
-                use tauri_plugin_autostart::ManagerExt;
-
-                // 일부 환경에서는 플러그인이 이미 빌더 단계에서 등록되어 있으므로
-                // autolaunch 매니저를 통해 활성화 상태를 설정합니다.
-                let autostart_manager = app.autolaunch();
-                // enable() 호출을 시도하고 상태를 로깅
-                let _ = autostart_manager.enable();
-                info!("registered for autostart? {}", autostart_manager.is_enabled().unwrap_or(false));
+                let autostart_enabled = load_setting::<bool>(app.handle(), "autostartEnabled").unwrap_or(true);
+                if let Err(e) = apply_autostart(app.handle(), autostart_enabled) {
+                    error!("자동 시작 설정 적용 실패: {}", e);
+                }
             }
 
             // ✨ 수정: app.path()가 PathResolver를 반환하므로 .resolver() 없이 바로 참조를 넘겨줍니다.
             let translations = Arc::new(Translations::new(&app.path()));
+            // 실행 시 바로 카메라를 켤지 여부 (설정이 없으면 개인정보 보호를 위해 꺼진 상태로 시작)
+            let auto_start_monitoring = load_setting::<bool>(app.handle(), "autoStartMonitoring").unwrap_or(false);
             
             let app_state = AppState {
                 pose_analyzer: Arc::new(PoseAnalyzer::new()),
-                monitoring_active: Arc::new(Mutex::new(true)),
+                monitoring_active: Arc::new(Mutex::new(auto_start_monitoring)),
                 last_alert_time: Arc::new(Mutex::new(Instant::now() - Duration::from_secs(60))),
                 alert_messages: Arc::new(Mutex::new(Vec::new())),
+                alert_history: Arc::new(Mutex::new(std::collections::VecDeque::with_capacity(ALERT_HISTORY_CAPACITY))),
                 camera: Arc::new(Mutex::new(None)),
+                camera_backoff: Arc::new(Mutex::new(CameraBackoff::default())),
+                preferred_camera_backend: Arc::new(Mutex::new(None)),
                 selected_camera_index: Arc::new(Mutex::new(0)),
+                no_camera: Arc::new(Mutex::new(false)),
+                low_light_threshold: Arc::new(Mutex::new(DEFAULT_LOW_LIGHT_THRESHOLD)),
+                image_quality: Arc::new(Mutex::new(DEFAULT_IMAGE_QUALITY)),
+                low_light_streak: Arc::new(Mutex::new(0)),
                 monitoring_interval_secs: Arc::new(Mutex::new(3)),
+                focus_mode: Arc::new(Mutex::new(None)),
+                sensitivity_check: Arc::new(Mutex::new(None)),
+                session_recording: Arc::new(Mutex::new(None)),
+                adaptive_interval: Arc::new(Mutex::new(None)),
+                presence: Arc::new(Mutex::new(PresenceTracker::default())),
+                sitting_limit_mins: Arc::new(Mutex::new(DEFAULT_SITTING_LIMIT_MINS)),
+                sitting_grace_secs: Arc::new(Mutex::new(DEFAULT_SITTING_GRACE_SECS)),
+                monitoring_started_at: Arc::new(Mutex::new(auto_start_monitoring.then(Instant::now))),
+                startup_grace_secs: Arc::new(Mutex::new(DEFAULT_STARTUP_GRACE_SECS)),
+                camera_warmup_ms: Arc::new(Mutex::new((DEFAULT_CAMERA_WARMUP_MS, DEFAULT_CAMERA_DROP_DELAY_MS))),
+                alert_tiers: Arc::new(Mutex::new(AlertTiers::default())),
+                posture_tier: Arc::new(Mutex::new("good")),
+                recent_scores: Arc::new(Mutex::new(std::collections::VecDeque::with_capacity(ADAPTIVE_SCORE_WINDOW))),
+                interval_changed: Arc::new(tokio::sync::Notify::new()),
+                alert_flush_interval_secs: Arc::new(Mutex::new(None)),
+                alert_interval_changed: Arc::new(tokio::sync::Notify::new()),
                 translations: translations,
-                current_language: Arc::new(Mutex::new("ko".to_string())),
+                current_language: Arc::new(Mutex::new(FALLBACK_LANGUAGE.to_string())),
                 battery_saving_mode: Arc::new(Mutex::new(false)),
+                low_power_inference: Arc::new(Mutex::new(false)),
+                recalibration_nudge: Arc::new(Mutex::new(true)),
+                privacy_mode: Arc::new(Mutex::new(false)),
+                logging_enabled: Arc::new(Mutex::new(true)),
+                demo_mode: Arc::new(Mutex::new(false)),
+                recent_frames: Arc::new(Mutex::new(std::collections::VecDeque::with_capacity(RECENT_FRAME_CACHE_SIZE))),
+                pending_samples: Arc::new(Mutex::new(std::collections::VecDeque::new())),
+                current_activity: Arc::new(Mutex::new(None)),
+                metrics: Arc::new(Metrics::default()),
+                metrics_server: Arc::new(Mutex::new(None)),
+                shutdown: Arc::new(tokio::sync::watch::channel(false).0),
+                background_tasks: Arc::new(Mutex::new(Vec::new())),
                 tray: Arc::new(Mutex::new(None)),
+                tray_visible: Arc::new(Mutex::new(true)),
+                active_camera_source: Arc::new(Mutex::new(None)),
+                monitoring_shortcut: Arc::new(Mutex::new(None)),
+                auto_paused: Arc::new(Mutex::new(None)),
+                motion_gating_threshold: Arc::new(Mutex::new(None)),
+                camera_pool: Arc::new(Mutex::new(CameraPool::default())),
+                webhook_url: Arc::new(Mutex::new(None)),
+                motion_gate: Arc::new(Mutex::new(MotionGate::default())),
+                resource_monitor: Arc::new(Mutex::new(None)),
             };
             app.manage(app_state.clone());
+            reload_settings(app.handle(), &app_state);
+
+            let mut background_tasks = Vec::new();
 
             let alert_app_handle = app.handle().clone();
             let alert_state = app_state.clone();
-            tauri::async_runtime::spawn(async move { background_alert_task(alert_app_handle, alert_state).await; });
+            background_tasks.push(tauri::async_runtime::spawn(async move { background_alert_task(alert_app_handle, alert_state).await; }));
 
             let monitor_app_handle = app.handle().clone();
             let monitor_state = app_state.clone();
-            tauri::async_runtime::spawn(async move { background_monitoring_task(monitor_app_handle, monitor_state).await; });
+            background_tasks.push(tauri::async_runtime::spawn(async move { background_monitoring_task(monitor_app_handle, monitor_state).await; }));
+
+            let rollup_app_handle = app.handle().clone();
+            let rollup_state = app_state.clone();
+            background_tasks.push(tauri::async_runtime::spawn(async move { background_rollup_task(rollup_app_handle, rollup_state).await; }));
+
+            let scan_app_handle = app.handle().clone();
+            let scan_state = app_state.clone();
+            background_tasks.push(tauri::async_runtime::spawn(async move { background_camera_scan_task(scan_app_handle, scan_state).await; }));
+
+            let report_app_handle = app.handle().clone();
+            let report_state = app_state.clone();
+            background_tasks.push(tauri::async_runtime::spawn(async move { background_weekly_report_task(report_app_handle, report_state).await; }));
+
+            let system_app_handle = app.handle().clone();
+            let system_state = app_state.clone();
+            background_tasks.push(tauri::async_runtime::spawn(async move { background_system_state_task(system_app_handle, system_state).await; }));
+
+            *app_state.background_tasks.lock() = background_tasks;
 
             // 모델 초기화
             let init_app_handle = app.handle().clone();
             let init_state = app_state.clone();
             tauri::async_runtime::spawn(async move {
-                if let Err(e) = init_state.pose_analyzer.initialize_model(init_app_handle.clone()).await {
-                    error!("모델 초기화 실패: {}", e);
-                } else {
+                if initialize_model_with_retry(&init_app_handle, &init_state).await.is_ok() {
                     if let Err(e) = init_state.pose_analyzer.load_baseline_from_file(&init_app_handle) {
                         error!("베이스라인 로드 실패: {}", e);
                     }
+                    prompt_calibration_if_needed(&init_app_handle, &init_state);
                 }
             });
 
@@ -708,104 +6114,37 @@ pub fn run() {
                         },
                         "start_monitoring" => {
                             info!("'Start Monitoring' 클릭됨");
-                            *state.monitoring_active.lock().unwrap() = true;
-
-                            let battery_saving = *state.battery_saving_mode.lock().unwrap();
-                            if !battery_saving {
-                                let mut cam_lock = state.camera.lock().unwrap();
-                                if let Some(cam) = cam_lock.as_mut() {
-                                    if !cam.is_stream_open() {
-                                        if let Err(e) = cam.open_stream() {
-                                            error!("기존 웹캠 스트림 시작 실패: {}", e);
-                                        } else {
-                                            info!("기존 웹캠 스트림 시작됨.");
-                                        }
-                                    }
-                                } else {
-                                    let index = *state.selected_camera_index.lock().unwrap();
-                                    info!("선택된 인덱스 {}번 카메라로 초기화 시도", index);
-                                    let requested = RequestedFormat::new::<RgbFormat>(RequestedFormatType::AbsoluteHighestFrameRate);
-                                    match Camera::new(CameraIndex::Index(index), requested) {
-                                        Ok(mut cam) => {
-                                            info!("웹캠 초기화 성공: {}", cam.info().human_name());
-                                            if let Err(e) = cam.open_stream() {
-                                                error!("새 웹캠 스트림 시작 실패: {}", e);
-                                            } else {
-                                                info!("새 웹캠 스트림 시작됨.");
-                                                *cam_lock = Some(cam);
-                                            }
-                                        }
-                                        Err(e) => {
-                                            error!("인덱스 {}번 웹캠 초기화 실패: {}", index, e);
-                                        }
-                                    }
-                                }
-                            }
-                            if let Some(tray) = state.tray.lock().unwrap().as_ref() {
-                                if let Err(e) = tray.set_icon(Some(app.default_window_icon().unwrap().clone())) {
-                                    error!("아이콘 변경 실패: {}", e);
-                                }
-                            }
-                            let _ = app.emit("monitoring-state-changed", &serde_json::json!({ "active": true }));
+                            set_monitoring_active(app, &state, true);
                         }
                         "stop_monitoring" => {
                             info!("'Stop Monitoring' 클릭됨");
-                            *state.monitoring_active.lock().unwrap() = false;
-                            if let Some(cam) = &mut *state.camera.lock().unwrap() {
-                                if cam.is_stream_open() {
-                                    if let Err(e) = cam.stop_stream() {
-                                        error!("웹캠 스트림 중지 실패: {}", e);
-                                    } else {
-                                        info!("웹캠 스트림 중지됨.");
-                                    }
-                                }
-                            }
-                            if let Some(tray) = state.tray.lock().unwrap().as_ref() {
-                                if let Ok(monitoring_off_icon_path) = app.path().resolve("icons/monitoring_off.png", BaseDirectory::Resource) {
-                                    if let Ok(bytes) = fs::read(&monitoring_off_icon_path) {
-                                        if let Ok(monitoring_off_icon) = Image::from_bytes(&bytes) {
-                                            if let Err(e) = tray.set_icon(Some(monitoring_off_icon)) {
-                                                error!("아이콘 변경 실패: {}", e);
-                                            }
-                                        } else {
-                                            error!("아이콘 생성 실패");
-                                        }
-                                    } else {
-                                        error!("아이콘 파일 읽기 실패");
-                                    }
-                                } else {
-                                    error!("아이콘 경로 해결 실패");
-                                }
-                            }
-                            let _ = app.emit("monitoring-state-changed", &serde_json::json!({ "active": false }));
+                            set_monitoring_active(app, &state, false);
                         }
                         _ => {}
                     }
                 })
                 .build(app)?;
-            *app_state.tray.lock().unwrap() = Some(tray);
+            *app_state.tray.lock() = Some(tray);
+            let tray_visible = load_setting::<bool>(app.handle(), "trayVisible").unwrap_or(true);
+            apply_tray_visibility(app.handle(), &app_state, tray_visible);
+            if !auto_start_monitoring {
+                // 꺼진 상태로 시작하면 트레이 아이콘도 모니터링 꺼짐 아이콘으로 맞춤
+                set_monitoring_active(app.handle(), &app_state, false);
+            }
+            let shortcut = load_setting::<String>(app.handle(), "monitoringShortcut")
+                .unwrap_or_else(|| DEFAULT_MONITORING_SHORTCUT.to_string());
+            if let Err(e) = apply_monitoring_shortcut(app.handle(), &app_state, &shortcut) {
+                warn!("모니터링 단축키 등록 실패: {}", e);
+            }
             info!("Pose Nudge 애플리케이션 초기화 완료");
             Ok(())
         })
         .on_window_event(|window, event| match event {
             tauri::WindowEvent::CloseRequested { api, .. } => {
-                api.prevent_close();
-                let _ = window.hide();
-            }
-            tauri::WindowEvent::Destroyed => {
-                let camera_to_stop = {
-                    let state = window.state::<AppState>();
-                    let mut guard = state.camera.lock().unwrap();
-                    guard.take()
-                };
-                if let Some(mut cam) = camera_to_stop {
-                    if cam.is_stream_open() {
-                        if let Err(e) = cam.stop_stream() {
-                             error!("웹캠 스트림 종료 실패: {}", e);
-                        } else {
-                            info!("웹캠 스트림을 안전하게 종료했습니다.");
-                        }
-                    }
+                // 트레이 아이콘을 숨긴 경우에는 창을 닫으면 앱을 종료 (다시 열거나 종료할 방법이 없으므로)
+                if *window.state::<AppState>().tray_visible.lock() {
+                    api.prevent_close();
+                    let _ = window.hide();
                 }
             }
             _ => {}
@@ -817,18 +6156,152 @@ pub fn run() {
             analyze_pose_data,
             get_pose_recommendations,
             get_alert_messages,
+            get_alert_history,
             get_monitoring_status,
+            get_resource_usage,
+            send_test_notification,
+            get_calibration_status,
+            save_calibration_profile,
+            activate_profile,
+            list_calibration_profiles,
+            delete_calibration_profile,
+            generate_support_bundle,
+            repair_settings,
+            start_session_recording,
+            stop_session_recording,
+            check_user_framing,
             test_model_status,
+            get_analysis_schema_version,
+            get_lean_direction,
+            export_keypoints_coco,
+            analyze_image_file,
+            trigger_test_detection,
+            is_user_present,
+            get_model_info,
+            set_optimization_level,
+            benchmark_inference,
+            set_low_power_inference,
+            set_motion_gating,
+            set_recalibration_nudge,
+            measure_low_power_tradeoff,
+            measure_resize_filters,
             calibrate_user_posture,
+            calibrate_with_samples,
+            start_guided_calibration,
+            capture_calibration_burst,
+            preview_calibration,
+            get_raw_detection_values,
             save_calibrated_image,
+            delete_calibration_image,
+            set_privacy_mode,
+            get_privacy_mode,
             set_detection_settings,
+            set_notification_threshold_count,
+            set_detection_hysteresis,
+            set_temporal_decay,
+            set_confidence_threshold,
+            set_keypoint_smoothing,
+            set_subject_selection,
+            set_min_detection_region,
+            set_image_normalization,
+            set_resize_filter,
+            set_camera_pool,
+            get_camera_pool,
+            set_camera_rotation,
+            set_max_frame_dimension,
+            set_model_input_size,
+            set_low_light_threshold,
+            set_image_quality,
+            set_score_weights,
+            set_detector_enabled,
+            set_metrics_server,
+            set_autostart,
+            set_tray_visible,
+            set_webhook_url,
+            get_webhook_url,
+            get_tray_visible,
+            set_monitoring_shortcut,
+            get_monitoring_shortcut,
+            set_auto_start_monitoring,
+            get_auto_start_monitoring,
+            get_autostart,
+            get_debug_state,
+            get_detectors,
             get_available_cameras,
             set_selected_camera,
             set_monitoring_interval,
             set_current_language,
+            get_translations,
+            set_logging_enabled,
+            get_logging_enabled,
+            set_demo_mode,
+            get_demo_mode,
             set_battery_saving_mode,
+            start_focus_mode,
+            stop_focus_mode,
+            start_sensitivity_check,
+            stop_sensitivity_check,
+            get_focus_mode_status,
+            set_alert_flush_interval,
+            set_adaptive_interval,
+            set_sitting_limit,
+            set_startup_grace,
+            set_camera_warmup,
+            get_camera_warmup,
+            set_alert_tiers,
+            get_posture_stats,
+            get_posture_stats_by_activity,
+            get_posture_trend,
+            get_posture_heatmap,
+            get_database_info,
+            reset_all_data,
+            set_current_activity,
+            get_current_activity,
+            get_worst_recent_event,
+            get_good_posture_percentage,
+            get_posture_log,
+            set_weekly_report_schedule,
+            export_settings,
+            import_settings,
+            export_calibration_string,
+            import_calibration_string,
             restart_app
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            // 종료 요청을 한 번 미루고 메인 스레드를 막지 않은 채 백그라운드 작업을 정리한 뒤 다시 종료
+            if let tauri::RunEvent::ExitRequested { api, code, .. } = event {
+                if app.state::<AppState>().is_shutting_down() {
+                    return;
+                }
+                api.prevent_exit();
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    shutdown_background_tasks(&app, &app.state::<AppState>()).await;
+                    app.exit(code.unwrap_or(0));
+                });
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 종료 신호를 무시하고 끝나지 않는 작업이 있으면 종료 대기가 제한 시간 후 실패로 끝나는지 확인
+    #[tokio::test]
+    async fn joining_a_stuck_task_times_out() {
+        let finished = tauri::async_runtime::spawn(async {});
+        let stuck = tauri::async_runtime::spawn(std::future::pending::<()>());
+        assert!(!join_background_tasks(vec![finished, stuck], Duration::from_millis(50)).await);
+    }
+
+    #[tokio::test]
+    async fn shutdown_wait_returns_when_already_signaled() {
+        let shutdown = tokio::sync::watch::channel(false).0;
+        shutdown.send_replace(true);
+        let waited = tokio::time::timeout(Duration::from_secs(1), wait_for_shutdown(&shutdown)).await;
+        assert!(waited.is_ok());
+    }
 }
@@ -0,0 +1,145 @@
+use log::{info, warn};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+// 메트릭 서버 기본 포트 (설정에서 변경 가능)
+pub const DEFAULT_METRICS_PORT: u16 = 9464;
+
+// 모니터링 루프에서 집계하는 Prometheus 형식 메트릭
+#[derive(Default)]
+pub struct Metrics {
+    frames_processed: AtomicU64,
+    frames_skipped: AtomicU64,
+    turtle_neck_detections: AtomicU64,
+    shoulder_misalignment_detections: AtomicU64,
+    hand_on_face_detections: AtomicU64,
+    head_dropped_detections: AtomicU64,
+    shoulder_tension_detections: AtomicU64,
+    current_score: AtomicU64,
+    last_inference_us: AtomicU64,
+    inference_us_sum: AtomicU64,
+}
+
+impl Metrics {
+    // 분석 결과 JSON 하나를 메트릭에 반영
+    pub fn record_analysis(&self, result: &serde_json::Value) {
+        let detected = |key: &str| result.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+        self.frames_processed.fetch_add(1, Ordering::Relaxed);
+        if detected("turtle_neck") {
+            self.turtle_neck_detections.fetch_add(1, Ordering::Relaxed);
+        }
+        if detected("shoulder_misalignment") {
+            self.shoulder_misalignment_detections.fetch_add(1, Ordering::Relaxed);
+        }
+        if detected("hand_on_face") {
+            self.hand_on_face_detections.fetch_add(1, Ordering::Relaxed);
+        }
+        if detected("head_dropped") {
+            self.head_dropped_detections.fetch_add(1, Ordering::Relaxed);
+        }
+        if detected("shoulder_tension") {
+            self.shoulder_tension_detections.fetch_add(1, Ordering::Relaxed);
+        }
+        if let Some(score) = result.get("posture_score").and_then(|v| v.as_u64()) {
+            self.current_score.store(score, Ordering::Relaxed);
+        }
+        if let Some(inference_ms) = result.get("inference_ms").and_then(|v| v.as_f64()) {
+            let inference_us = (inference_ms * 1000.0) as u64;
+            self.last_inference_us.store(inference_us, Ordering::Relaxed);
+            self.inference_us_sum.fetch_add(inference_us, Ordering::Relaxed);
+        }
+    }
+
+    // 직전 프레임과 거의 같아 추론을 건너뛴 프레임 수를 증가
+    pub fn record_skipped_frame(&self) {
+        self.frames_skipped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // Prometheus 텍스트 형식으로 변환
+    pub fn render(&self) -> String {
+        let frames = self.frames_processed.load(Ordering::Relaxed);
+        let seconds = |us: u64| us as f64 / 1_000_000.0;
+        let mut out = String::new();
+        out.push_str("# HELP pose_nudge_frames_processed_total Frames analyzed by the monitoring loop.\n");
+        out.push_str("# TYPE pose_nudge_frames_processed_total counter\n");
+        out.push_str(&format!("pose_nudge_frames_processed_total {}\n", frames));
+        out.push_str("# HELP pose_nudge_frames_skipped_total Frames whose analysis was reused because the scene did not change.\n");
+        out.push_str("# TYPE pose_nudge_frames_skipped_total counter\n");
+        out.push_str(&format!(
+            "pose_nudge_frames_skipped_total {}\n",
+            self.frames_skipped.load(Ordering::Relaxed)
+        ));
+        out.push_str("# HELP pose_nudge_detections_total Frames in which a posture problem was detected.\n");
+        out.push_str("# TYPE pose_nudge_detections_total counter\n");
+        for (kind, counter) in [
+            ("turtle_neck", &self.turtle_neck_detections),
+            ("shoulder_misalignment", &self.shoulder_misalignment_detections),
+            ("hand_on_face", &self.hand_on_face_detections),
+            ("head_dropped", &self.head_dropped_detections),
+            ("shoulder_tension", &self.shoulder_tension_detections),
+        ] {
+            out.push_str(&format!(
+                "pose_nudge_detections_total{{type=\"{}\"}} {}\n",
+                kind,
+                counter.load(Ordering::Relaxed)
+            ));
+        }
+        out.push_str("# HELP pose_nudge_posture_score Posture score of the latest analyzed frame.\n");
+        out.push_str("# TYPE pose_nudge_posture_score gauge\n");
+        out.push_str(&format!(
+            "pose_nudge_posture_score {}\n",
+            self.current_score.load(Ordering::Relaxed)
+        ));
+        out.push_str("# HELP pose_nudge_inference_seconds Model inference latency.\n");
+        out.push_str("# TYPE pose_nudge_inference_seconds summary\n");
+        out.push_str(&format!(
+            "pose_nudge_inference_seconds_sum {}\n",
+            seconds(self.inference_us_sum.load(Ordering::Relaxed))
+        ));
+        out.push_str(&format!("pose_nudge_inference_seconds_count {}\n", frames));
+        out.push_str("# HELP pose_nudge_last_inference_seconds Inference latency of the latest analyzed frame.\n");
+        out.push_str("# TYPE pose_nudge_last_inference_seconds gauge\n");
+        out.push_str(&format!(
+            "pose_nudge_last_inference_seconds {}\n",
+            seconds(self.last_inference_us.load(Ordering::Relaxed))
+        ));
+        out
+    }
+}
+
+// localhost에만 바인딩된 HTTP 서버로 GET /metrics 요청에 응답
+pub async fn serve(port: u16, metrics: Arc<Metrics>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    info!("메트릭 서버 시작: http://127.0.0.1:{}/metrics", port);
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let metrics = metrics.clone();
+        tauri::async_runtime::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let n = match stream.read(&mut buf).await {
+                Ok(n) => n,
+                Err(e) => {
+                    warn!("메트릭 요청 읽기 실패: {}", e);
+                    return;
+                }
+            };
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let (status, body) = if request.starts_with("GET /metrics") {
+                ("200 OK", metrics.render())
+            } else {
+                ("404 Not Found", "not found\n".to_string())
+            };
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                warn!("메트릭 응답 전송 실패: {}", e);
+            }
+        });
+    }
+}
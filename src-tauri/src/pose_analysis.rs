@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose, Engine as _};
 use image::{ImageBuffer, Rgb};
-use log::info;
+use log::{info, warn};
 use ndarray::Array4;
 use ort::{
     session::{
@@ -11,10 +11,131 @@ use ort::{
     value::Value,
 };
 use parking_lot::Mutex; // std::sync::Mutex보다 효율적인 Mutex 사용
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tauri::{path::BaseDirectory, AppHandle, Manager};
 
+// 추론 시간이 이 값을 넘으면 경고 로그를 남김 (ms)
+const SLOW_INFERENCE_WARN_MS: f64 = 500.0;
+// 기준값 대비 변화가 캘리브레이션 표준편차의 이 배수를 넘어야 감지로 판단
+const BASELINE_STD_DEV_MULTIPLIER: f32 = 2.0;
+// analysis-update 이벤트(분석 결과 JSON)의 구조 버전. 필드를 추가/변경하면 올려야 함
+// v1 구조:
+//   schema_version: u32, status: "yolo_analysis_success" | "model_not_initialized"
+//   turtle_neck, shoulder_misalignment, hand_on_face: bool | null (감지 항목이 꺼져 있으면 null)
+//   shoulder_misalignment_side: "left" | "right" | null
+//   posture_score: 0~100, recommendations: 번역 키 배열
+//   confidence, detection_confidence: 0~1
+//   bounding_box: { x, y, width, height } (원본 프레임 좌표)
+//   in_frame: { fully_in_frame, head_cut_off, shoulders_cut_off, touching_edges }
+//   inference_ms, total_ms: 처리 시간 (ms)
+// v2: monitor_height: { direction: "raise" | "lower" | "ok", eye_offset_ratio, estimated_cm } | null 추가
+// v3: lean: { direction: "left" | "right" | "centered", magnitude, samples } | null 추가
+// v4: head_dropped: bool | null 추가 (머리가 어깨선 가까이까지 떨어진 심한 구부정 자세)
+// v5: image_normalization: "off" | "gamma" | "equalize" 추가 (추론 전 밝기 보정 방식, confidence 비교용)
+// v6: shoulder_tension: bool | null 추가 (양쪽 어깨를 함께 귀 쪽으로 올린 긴장 자세)
+// v7: stale_frame: bool 추가 (이미 반영된 프레임보다 먼저 캡처되어 감지 이력에 넣지 않은 프레임)
+// v8: confirmed_posture_score 추가 (거북목/어깨는 확정된 감지 상태로 계산한 점수, 알림 단계 판정용)
+pub const ANALYSIS_SCHEMA_VERSION: u32 = 8;
+// 추론 전 프레임 긴 변의 기본 최대 크기 (px), 모델 입력(640)보다 작게는 설정할 수 없음
+pub const DEFAULT_MAX_FRAME_DIMENSION: u32 = 1280;
+const MIN_FRAME_DIMENSION: u32 = 640;
+// 코(또는 눈)와 어깨선의 세로 간격이 어깨 너비의 이 비율보다 작으면 머리가 떨어진 것으로 판단
+// (바르게 앉으면 보통 어깨 너비의 0.5 이상)
+const HEAD_DROPPED_MAX_GAP_RATIO: f32 = 0.15;
+// 어깨-귀 세로 간격이 기준 자세보다 이 비율 이상 줄어들면 양쪽 어깨를 올린 긴장 자세로 판단
+const SHOULDER_TENSION_MIN_DROP_RATIO: f32 = 0.25;
+// 감마 밝기 보정에 사용할 감마 범위 (너무 어둡거나 밝은 화면에서 노이즈가 과하게 증폭되지 않도록 제한)
+const MIN_NORMALIZATION_GAMMA: f32 = 0.4;
+const MAX_NORMALIZATION_GAMMA: f32 = 2.5;
+// 감지 항목별로 돌아가며 보여줄 일반 팁 키 (프론트엔드 dashboard.tips.<key>에 문구가 있음)
+const TURTLE_NECK_TIPS: &[&str] = &["tip1", "chinTuck", "earsOverShoulders"];
+const SHOULDER_TIPS: &[&str] = &["tip4", "tip5", "shoulderRoll"];
+// 분석 화면의 팁이 매 주기 바뀌어 깜빡이지 않도록, 같은 감지 항목의 팁은 이 시간이 지나야 다음 것으로 넘김
+const TIP_ROTATION_COOLDOWN_SECS: u64 = 60;
+// 최소 감지 영역(프레임 대비 면적 비율)으로 설정할 수 있는 최댓값
+const MAX_MIN_DETECTION_AREA: f32 = 0.5;
+// 키포인트 신뢰도 기준 기본값과 설정 가능한 범위
+pub const DEFAULT_CONFIDENCE_THRESHOLD: f32 = 0.5;
+const MIN_CONFIDENCE_THRESHOLD: f32 = 0.2;
+const MAX_CONFIDENCE_THRESHOLD: f32 = 0.9;
+// 모델 기본 입력 크기와 저전력 추론 시 입력 크기 (px, 정사각형)
+// 고정 입력 모델은 세션에서 읽은 크기를 그대로 쓰고, 가변 입력 모델은 설정한 크기(32의 배수)를 사용
+// 저전력 추론은 모델이 가변 입력을 지원할 때만 320 입력으로 연산량을 약 1/4로 줄임
+// 고정 입력 모델에서는 입력 크기를 바꿀 수 없어 프레임만 LOW_POWER_MAX_FRAME_DIMENSION으로 먼저 줄이므로
+// 리사이즈 비용만 줄고 추론 시간은 거의 같음
+// 작은 입력에서는 작게 보이는 키포인트(귀, 손목)의 신뢰도가 떨어지고 멀리 앉으면 감지가 실패하기 쉬움
+// 흑백 입력은 컬러로 학습된 모델의 정확도를 떨어뜨리면서 연산량은 줄지 않아 사용하지 않음
+pub const DEFAULT_MODEL_INPUT_SIZE: u32 = 640;
+const MIN_MODEL_INPUT_SIZE: u32 = 160;
+const MAX_MODEL_INPUT_SIZE: u32 = 1280;
+const LOW_POWER_INPUT_SIZE: u32 = 320;
+const LOW_POWER_MAX_FRAME_DIMENSION: u32 = 480;
+// 좌우 기울임 추세: 최근 프레임 수, 판단에 필요한 최소 프레임 수, 기울었다고 볼 화면 너비 대비 치우침
+const LEAN_WINDOW_SIZE: usize = 20;
+const LEAN_MIN_SAMPLES: usize = 5;
+const LEAN_THRESHOLD_RATIO: f32 = 0.08;
+// 캘리브레이션 드리프트: 얼굴-어깨 비율을 보관할 최근 프레임 수, 판단에 필요한 최소 프레임 수,
+// 기준값 대비 중앙값 변화율 기준, 이 기준을 연속으로 넘어야 하는 프레임 수
+const DRIFT_WINDOW_SIZE: usize = 200;
+const DRIFT_MIN_SAMPLES: usize = 100;
+const DRIFT_THRESHOLD_RATIO: f32 = 0.2;
+const DRIFT_SUSTAIN_FRAMES: usize = 50;
+
+// COCO 키포인트 이름 (PoseKeypoints::to_array 순서와 같음)
+pub const COCO_KEYPOINT_NAMES: [&str; 17] = [
+    "nose",
+    "left_eye",
+    "right_eye",
+    "left_ear",
+    "right_ear",
+    "left_shoulder",
+    "right_shoulder",
+    "left_elbow",
+    "right_elbow",
+    "left_wrist",
+    "right_wrist",
+    "left_hip",
+    "right_hip",
+    "left_knee",
+    "right_knee",
+    "left_ankle",
+    "right_ankle",
+];
+// 감지 강도 단계별 원시값 (순서대로 1: 느슨하게, 2: 보통, 3: 엄격하게)
+// 거북목은 (RATIO_TOLERANCE, FORWARD_TOLERANCE), 어깨는 (TOLERANCE, MIN_ABSOLUTE_THRESHOLD)
+const TURTLE_NECK_SENSITIVITY_PRESETS: [(f32, f32); 3] = [(0.040, 0.030), (0.030, 0.020), (0.020, 0.015)];
+const SHOULDER_SENSITIVITY_PRESETS: [(f32, f32); 3] = [(1.2, 0.22), (0.9, 0.18), (0.7, 0.15)];
+// 기준 자세 JSON의 비율 항목 이름 (std_devs도 같은 이름을 사용)
+const BASELINE_RATIO_KEYS: [&str; 4] = ["face_shoulder_ratio", "shoulder_alignment", "head_forward_ratio", "shoulder_ear_ratio"];
+
+// COCO 스켈레톤 연결선 (키포인트 순번 쌍, 세션 녹화 애니메이션용)
+pub const COCO_SKELETON: [(usize, usize); 19] = [
+    (15, 13),
+    (13, 11),
+    (16, 14),
+    (14, 12),
+    (11, 12),
+    (5, 11),
+    (6, 12),
+    (5, 6),
+    (5, 7),
+    (6, 8),
+    (7, 9),
+    (8, 10),
+    (1, 2),
+    (0, 1),
+    (0, 2),
+    (1, 3),
+    (2, 4),
+    (3, 5),
+    (4, 6),
+];
+// COCO 가시성 플래그 기준 신뢰도: 이상이면 2(보임), VISIBILITY_LABELED 이상이면 1(가려짐), 미만이면 0(없음)
+const VISIBILITY_VISIBLE: f32 = 0.5;
+const VISIBILITY_LABELED: f32 = 0.2;
+
 // 키포인트 데이터 구조체
 #[derive(Debug, Clone)]
 pub struct KeyPoint {
@@ -45,22 +166,464 @@ pub struct PoseKeypoints {
     pub right_ankle: KeyPoint,
 }
 
+// 감지된 사람의 바운딩 박스 (원본 이미지 좌표, 좌상단 기준)
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BoundingBox {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+// 사용자가 화면 안에 제대로 들어와 있는지에 대한 진단 정보
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FrameFit {
+    pub fully_in_frame: bool,
+    pub head_cut_off: bool,
+    pub shoulders_cut_off: bool,
+    pub touching_edges: Vec<&'static str>, // 바운딩 박스가 닿은 화면 가장자리 (left/right/top/bottom)
+}
+
+// 눈높이로 추정한 모니터 높이 조정 권장 (참고용)
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MonitorHeightAdvice {
+    pub direction: &'static str, // raise: 모니터를 올림, lower: 모니터를 내림, ok: 적절
+    pub eye_offset_ratio: f32,   // 화면 중앙 대비 눈 높이 (화면 높이 비율, 양수면 눈이 중앙보다 위)
+    pub estimated_cm: u32,       // 어깨너비로 환산한 대략적인 조정 거리
+}
+
+// 최근 프레임 동안 상체가 화면 중앙에서 어느 쪽으로 치우쳐 있었는지 (사용자 기준 좌우)
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LeanSummary {
+    pub direction: &'static str, // left, right, centered
+    pub magnitude: f32,          // 평균 치우침 (화면 너비 비율, 절댓값)
+    pub samples: usize,
+}
+
+// 가장 최근 분석의 키포인트 (화면 표시 방향 좌표, 스무딩 적용 후)
+struct LatestPose {
+    keypoints: PoseKeypoints,
+    bounding_box: BoundingBox,
+    confidence: f32,
+    width: u32,
+    height: u32,
+    timestamp_ms: i64,
+}
+
+// 키포인트와 바운딩 박스를 포함한 단일 감지 결과
+#[derive(Debug, Clone)]
+pub struct PoseDetection {
+    pub keypoints: PoseKeypoints,
+    pub bounding_box: BoundingBox,
+    pub confidence: f32,
+}
+
+// 캘리브레이션 기준이 되는 자세 비율 측정값
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct BaselineRatios {
+    pub face_shoulder_ratio: Option<f32>,
+    pub shoulder_alignment: Option<f32>,
+    pub head_forward_ratio: Option<f32>,
+    pub shoulder_ear_ratio: Option<f32>, // 어깨-귀 세로 간격 / 어깨 너비 (어깨 긴장 감지용)
+}
+
+impl BaselineRatios {
+    pub fn is_empty(&self) -> bool {
+        self.face_shoulder_ratio.is_none()
+            && self.shoulder_alignment.is_none()
+            && self.head_forward_ratio.is_none()
+            && self.shoulder_ear_ratio.is_none()
+    }
+
+    // 여러 측정값의 항목별 평균 (측정되지 않은 값은 제외)
+    pub fn average(samples: &[BaselineRatios]) -> BaselineRatios {
+        fn mean(values: impl Iterator<Item = f32>) -> Option<f32> {
+            let values: Vec<f32> = values.collect();
+            if values.is_empty() {
+                None
+            } else {
+                Some(values.iter().sum::<f32>() / values.len() as f32)
+            }
+        }
+        BaselineRatios {
+            face_shoulder_ratio: mean(samples.iter().filter_map(|s| s.face_shoulder_ratio)),
+            shoulder_alignment: mean(samples.iter().filter_map(|s| s.shoulder_alignment)),
+            head_forward_ratio: mean(samples.iter().filter_map(|s| s.head_forward_ratio)),
+            shoulder_ear_ratio: mean(samples.iter().filter_map(|s| s.shoulder_ear_ratio)),
+        }
+    }
+
+    // 여러 측정값의 항목별 표준편차 (측정값이 2개 미만인 항목은 제외)
+    pub fn std_dev(samples: &[BaselineRatios]) -> BaselineRatios {
+        fn std_dev(values: impl Iterator<Item = f32>) -> Option<f32> {
+            let values: Vec<f32> = values.collect();
+            if values.len() < 2 {
+                return None;
+            }
+            let mean = values.iter().sum::<f32>() / values.len() as f32;
+            let variance =
+                values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32;
+            Some(variance.sqrt())
+        }
+        BaselineRatios {
+            face_shoulder_ratio: std_dev(samples.iter().filter_map(|s| s.face_shoulder_ratio)),
+            shoulder_alignment: std_dev(samples.iter().filter_map(|s| s.shoulder_alignment)),
+            head_forward_ratio: std_dev(samples.iter().filter_map(|s| s.head_forward_ratio)),
+            shoulder_ear_ratio: std_dev(samples.iter().filter_map(|s| s.shoulder_ear_ratio)),
+        }
+    }
+
+    // 현재 값이 기준값보다 허용치와 표준편차 배수를 모두 넘게 커졌는지 확인
+    fn exceeds(current: f32, baseline: f32, tolerance: f32, std_dev: Option<f32>) -> bool {
+        let beyond_tolerance = current > baseline + tolerance;
+        let beyond_variance = std_dev
+            .map(|sd| current > baseline + BASELINE_STD_DEV_MULTIPLIER * sd)
+            .unwrap_or(true);
+        beyond_tolerance && beyond_variance
+    }
+}
+
+// 자세 점수에서 감지 항목별로 깎는 점수 (각 0~100)
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct ScoreWeights {
+    pub turtle_neck: u8,
+    pub shoulder_misalignment: u8,
+    pub hand_on_face: u8,
+    #[serde(default = "default_head_dropped_weight")]
+    pub head_dropped: u8,
+    #[serde(default = "default_shoulder_tension_weight")]
+    pub shoulder_tension: u8,
+}
+
+fn default_head_dropped_weight() -> u8 {
+    30
+}
+
+fn default_shoulder_tension_weight() -> u8 {
+    10
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            turtle_neck: 30,
+            shoulder_misalignment: 20,
+            hand_on_face: 0,
+            head_dropped: default_head_dropped_weight(),
+            shoulder_tension: default_shoulder_tension_weight(),
+        }
+    }
+}
+
+// 감지 항목별 사용 여부 (꺼진 항목은 분석 결과에 null로 표시)
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct DetectorToggles {
+    pub turtle_neck: bool,
+    pub shoulder_misalignment: bool,
+    pub hand_on_face: bool,
+    #[serde(default = "default_enabled")]
+    pub head_dropped: bool,
+    #[serde(default = "default_enabled")]
+    pub shoulder_tension: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Default for DetectorToggles {
+    fn default() -> Self {
+        Self {
+            turtle_neck: true,
+            shoulder_misalignment: true,
+            hand_on_face: true,
+            head_dropped: true,
+            shoulder_tension: true,
+        }
+    }
+}
+
+impl DetectorToggles {
+    // 감지 항목 ID (분석 결과 JSON 키와 같음)
+    pub const NAMES: [&'static str; 5] = [
+        "turtle_neck",
+        "shoulder_misalignment",
+        "hand_on_face",
+        "head_dropped",
+        "shoulder_tension",
+    ];
+
+    pub fn get(&self, name: &str) -> Option<bool> {
+        match name {
+            "turtle_neck" => Some(self.turtle_neck),
+            "shoulder_misalignment" => Some(self.shoulder_misalignment),
+            "hand_on_face" => Some(self.hand_on_face),
+            "head_dropped" => Some(self.head_dropped),
+            "shoulder_tension" => Some(self.shoulder_tension),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, name: &str, enabled: bool) -> Result<()> {
+        match name {
+            "turtle_neck" => self.turtle_neck = enabled,
+            "shoulder_misalignment" => self.shoulder_misalignment = enabled,
+            "hand_on_face" => self.hand_on_face = enabled,
+            "head_dropped" => self.head_dropped = enabled,
+            "shoulder_tension" => self.shoulder_tension = enabled,
+            _ => return Err(anyhow!("알 수 없는 감지 항목입니다: {}", name)),
+        }
+        Ok(())
+    }
+}
+
+// 한 프레임의 감지 항목별 판정 결과 (점수 계산과 추천 메시지 생성에 사용)
+#[derive(Debug, Clone, Copy, Default)]
+struct PostureFlags {
+    turtle_neck: bool,
+    shoulder_misalignment: bool,
+    hand_on_face: bool,
+    head_dropped: bool,
+    shoulder_tension: bool,
+}
+
+// 추론 전 밝기 보정 방식: 감마 보정은 평균 밝기를 중간값으로 맞추고, 평활화는 밝기 히스토그램을 고르게 펼침
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageNormalization {
+    Off,
+    Gamma,
+    Equalize,
+}
+
+impl ImageNormalization {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "off" => Some(Self::Off),
+            "gamma" => Some(Self::Gamma),
+            "equalize" => Some(Self::Equalize),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Gamma => "gamma",
+            Self::Equalize => "equalize",
+        }
+    }
+}
+
+// 모델 입력 크기로 줄일 때 쓰는 보간 필터: Nearest가 가장 빠르고 Lanczos3가 가장 선명함
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResizeFilter {
+    Nearest,
+    Triangle,
+    CatmullRom,
+    Lanczos3,
+}
+
+impl ResizeFilter {
+    pub const ALL: [ResizeFilter; 4] = [
+        ResizeFilter::Nearest,
+        ResizeFilter::Triangle,
+        ResizeFilter::CatmullRom,
+        ResizeFilter::Lanczos3,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "nearest" => Some(Self::Nearest),
+            "triangle" => Some(Self::Triangle),
+            "catmull_rom" => Some(Self::CatmullRom),
+            "lanczos3" => Some(Self::Lanczos3),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Nearest => "nearest",
+            Self::Triangle => "triangle",
+            Self::CatmullRom => "catmull_rom",
+            Self::Lanczos3 => "lanczos3",
+        }
+    }
+
+    fn filter_type(&self) -> image::imageops::FilterType {
+        match self {
+            Self::Nearest => image::imageops::FilterType::Nearest,
+            Self::Triangle => image::imageops::FilterType::Triangle,
+            Self::CatmullRom => image::imageops::FilterType::CatmullRom,
+            Self::Lanczos3 => image::imageops::FilterType::Lanczos3,
+        }
+    }
+}
+
+// 여러 사람이 감지되었을 때 분석 대상을 고르는 방식
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubjectSelection {
+    HighestConfidence,
+    Largest,
+    Centered,
+}
+
+impl SubjectSelection {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "highest_confidence" => Some(Self::HighestConfidence),
+            "largest" => Some(Self::Largest),
+            "centered" => Some(Self::Centered),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::HighestConfidence => "highest_confidence",
+            Self::Largest => "largest",
+            Self::Centered => "centered",
+        }
+    }
+}
+
+impl PoseKeypoints {
+    // COCO 키포인트 순서(nose, eyes, ears, shoulders, ...)의 배열로 변환
+    pub fn to_array(&self) -> [KeyPoint; 17] {
+        [
+            self.nose.clone(),
+            self.left_eye.clone(),
+            self.right_eye.clone(),
+            self.left_ear.clone(),
+            self.right_ear.clone(),
+            self.left_shoulder.clone(),
+            self.right_shoulder.clone(),
+            self.left_elbow.clone(),
+            self.right_elbow.clone(),
+            self.left_wrist.clone(),
+            self.right_wrist.clone(),
+            self.left_hip.clone(),
+            self.right_hip.clone(),
+            self.left_knee.clone(),
+            self.right_knee.clone(),
+            self.left_ankle.clone(),
+            self.right_ankle.clone(),
+        ]
+    }
+
+    // COCO 형식의 [x1, y1, v1, x2, y2, v2, ...] 배열과 라벨이 있는 키포인트 수로 변환
+    pub fn to_coco(&self) -> (Vec<f32>, usize) {
+        let mut values = Vec::with_capacity(17 * 3);
+        let mut labeled = 0;
+        for point in self.to_array() {
+            let visibility = if point.confidence >= VISIBILITY_VISIBLE {
+                2
+            } else if point.confidence >= VISIBILITY_LABELED {
+                1
+            } else {
+                0
+            };
+            if visibility == 0 {
+                values.extend([0.0, 0.0, 0.0]);
+            } else {
+                labeled += 1;
+                values.extend([point.x, point.y, visibility as f32]);
+            }
+        }
+        (values, labeled)
+    }
+
+    // COCO 키포인트 순서의 배열에서 구조체 생성
+    pub fn from_array(points: [KeyPoint; 17]) -> Self {
+        let [
+            nose,
+            left_eye,
+            right_eye,
+            left_ear,
+            right_ear,
+            left_shoulder,
+            right_shoulder,
+            left_elbow,
+            right_elbow,
+            left_wrist,
+            right_wrist,
+            left_hip,
+            right_hip,
+            left_knee,
+            right_knee,
+            left_ankle,
+            right_ankle,
+        ] = points;
+        Self {
+            nose,
+            left_eye,
+            right_eye,
+            left_ear,
+            right_ear,
+            left_shoulder,
+            right_shoulder,
+            left_elbow,
+            right_elbow,
+            left_wrist,
+            right_wrist,
+            left_hip,
+            right_hip,
+            left_knee,
+            right_knee,
+            left_ankle,
+            right_ankle,
+        }
+    }
+}
+
 // 자세 분석기 메인 구조체
 pub struct PoseAnalyzer {
     session: Arc<Mutex<Option<Session>>>,
+    model_path: Mutex<Option<std::path::PathBuf>>,
+    optimization_level: Mutex<u8>, // ONNX 그래프 최적화 수준 (0~3)
     analysis_interval: Arc<Mutex<u64>>,
     last_analysis_time: Arc<Mutex<std::time::Instant>>,
-    confidence_threshold: f32,
-    recent_turtle_neck_results: Mutex<VecDeque<bool>>,
-    recent_shoulder_results: Mutex<VecDeque<bool>>,
+    confidence_threshold: Mutex<f32>, // 키포인트/감지 결과를 믿을 수 있다고 보는 최소 신뢰도
+    recent_turtle_neck_results: Mutex<VecDeque<(u64, bool)>>, // (캡처 순번, 감지 여부)
+    recent_shoulder_results: Mutex<VecDeque<(u64, bool)>>,
+    capture_sequence: Mutex<u64>, // 마지막으로 발급한 캡처 순번 (시스템 시계가 바뀌어도 순서 판단이 흔들리지 않도록 단조 증가)
     temporal_window_size: usize,
+    recent_keypoints: Mutex<VecDeque<PoseKeypoints>>, // 키포인트 스무딩용 최근 프레임
+    keypoint_smoothing_window: Mutex<usize>,
+    subject_selection: Mutex<SubjectSelection>,
+    image_normalization: Mutex<ImageNormalization>, // 추론 전 밝기 보정 (CPU를 더 쓰므로 기본은 끔)
+    resize_filter: Mutex<ResizeFilter>, // 모델 입력 크기로 줄일 때 쓰는 보간 필터
+    min_detection_area: Mutex<f32>, // 분석 대상으로 볼 최소 바운딩 박스 면적 (프레임 대비 비율, 0이면 제한 없음)
+    score_weights: Mutex<ScoreWeights>,
+    detector_toggles: Mutex<DetectorToggles>,
+    camera_rotation: Mutex<u16>, // 카메라 영상을 바로 세우기 위한 시계 방향 회전 각도 (0/90/180/270)
+    max_frame_dimension: Mutex<u32>, // 이보다 큰 프레임은 추론 전에 축소 (긴 변 기준 px)
+    tip_rotation: Mutex<HashMap<String, (usize, Instant)>>, // 팁 묶음별 마지막으로 보여준 순번과 바뀐 시각
+    recent_lean_offsets: Mutex<VecDeque<f32>>, // 상체 중심의 좌우 치우침 추세 계산용 최근 값
+    low_power_inference: Mutex<bool>,
+    latest_pose: Mutex<Option<LatestPose>>,
+    drift_ratios: Mutex<VecDeque<f32>>, // 캘리브레이션 드리프트 판단용 최근 얼굴-어깨 비율
+    drift_streak: Mutex<usize>,
+    drift_suggested: Mutex<bool>, // 재캘리브레이션 권장을 이미 보냈는지 (다시 캘리브레이션하면 초기화)
+    dynamic_input: Mutex<bool>, // 로드된 모델이 가변 입력 크기를 지원하는지
+    fixed_input_size: Mutex<Option<u32>>, // 로드된 모델의 고정 입력 크기 (가변 입력이면 None)
+    configured_input_size: Mutex<u32>, // 가변 입력 모델에 사용할 입력 크기 설정
+    session_load_generation: Mutex<u64>, // 모델 로드 시도 번호 (제한 시간을 넘겨 버려진 로드가 나중에 세션을 바꾸지 않도록)
     baseline_face_shoulder_ratio: Mutex<Option<f32>>,
     baseline_shoulder_alignment: Mutex<Option<f32>>,
     baseline_head_forward_ratio: Mutex<Option<f32>>,
+    baseline_shoulder_ear_ratio: Mutex<Option<f32>>,
+    baseline_std_devs: Mutex<BaselineRatios>, // 다중 샘플 캘리브레이션에서 측정한 항목별 표준편차
+    baseline_calibrated_at: Mutex<Option<i64>>, // 마지막 캘리브레이션 시각 (유닉스 초)
+    baseline_quality: Mutex<Option<f32>>, // 연속 촬영 캘리브레이션의 안정도 점수 (0~100, 측정하지 않았으면 None)
 
     // ✨ 추가된 설정 관련 필드들
     // Mutex로 감싸서 런타임에 동적으로 변경 가능하게 함
     temporal_threshold_count: Mutex<usize>, // 알림 빈도 (3번 중 N번)
+    release_threshold_count: Mutex<usize>, // 감지 해제 기준 (감지 횟수가 이 값 이하로 줄어야 해제)
+    temporal_decay: Mutex<f32>, // 한 프레임 오래될 때마다 곱하는 가중치 (1.0이면 모든 프레임 동일 가중치)
+    turtle_neck_latched: Mutex<bool>, // 히스테리시스 적용 후 현재 감지 상태
+    shoulder_latched: Mutex<bool>,
     turtle_neck_thresholds: Mutex<(f32, f32)>, // 거북목 감지 강도 (RATIO_TOLERANCE, FORWARD_TOLERANCE)
     shoulder_alignment_thresholds: Mutex<(f32, f32)>, // 어깨 정렬 감지 강도 (TOLERANCE, MIN_ABSOLUTE_THRESHOLD)
 }
@@ -69,25 +632,59 @@ impl PoseAnalyzer {
     // 생성자 함수
     pub fn new() -> Self {
         const WINDOW_SIZE: usize = 3;
+        const DEFAULT_SMOOTHING_WINDOW: usize = 3;
         // ✨ 기본값 설정: 모두 '보통' 단계
         const DEFAULT_THRESHOLD_COUNT: usize = 2; // 3번 중 2번 감지 시 알림
+        const DEFAULT_RELEASE_COUNT: usize = 0; // 3번 모두 감지되지 않아야 해제
         const DEFAULT_TURTLE_THRESHOLDS: (f32, f32) = (0.030, 0.020);
         const DEFAULT_SHOULDER_THRESHOLDS: (f32, f32) = (0.9, 0.18);
 
         Self {
             session: Arc::new(Mutex::new(None)),
+            model_path: Mutex::new(None),
+            optimization_level: Mutex::new(3),
             analysis_interval: Arc::new(Mutex::new(3000)),
             last_analysis_time: Arc::new(Mutex::new(std::time::Instant::now())),
-            confidence_threshold: 0.5,
+            confidence_threshold: Mutex::new(DEFAULT_CONFIDENCE_THRESHOLD),
             recent_turtle_neck_results: Mutex::new(VecDeque::with_capacity(WINDOW_SIZE)),
             recent_shoulder_results: Mutex::new(VecDeque::with_capacity(WINDOW_SIZE)),
+            capture_sequence: Mutex::new(0),
             temporal_window_size: WINDOW_SIZE,
+            recent_keypoints: Mutex::new(VecDeque::with_capacity(DEFAULT_SMOOTHING_WINDOW)),
+            keypoint_smoothing_window: Mutex::new(DEFAULT_SMOOTHING_WINDOW),
+            subject_selection: Mutex::new(SubjectSelection::HighestConfidence),
+            image_normalization: Mutex::new(ImageNormalization::Off),
+            resize_filter: Mutex::new(ResizeFilter::Triangle),
+            min_detection_area: Mutex::new(0.0),
+            score_weights: Mutex::new(ScoreWeights::default()),
+            detector_toggles: Mutex::new(DetectorToggles::default()),
+            camera_rotation: Mutex::new(0),
+            max_frame_dimension: Mutex::new(DEFAULT_MAX_FRAME_DIMENSION),
+            tip_rotation: Mutex::new(HashMap::new()),
+            recent_lean_offsets: Mutex::new(VecDeque::with_capacity(LEAN_WINDOW_SIZE)),
+            low_power_inference: Mutex::new(false),
+            latest_pose: Mutex::new(None),
+            drift_ratios: Mutex::new(VecDeque::with_capacity(DRIFT_WINDOW_SIZE)),
+            drift_streak: Mutex::new(0),
+            drift_suggested: Mutex::new(false),
+            dynamic_input: Mutex::new(false),
+            fixed_input_size: Mutex::new(None),
+            configured_input_size: Mutex::new(DEFAULT_MODEL_INPUT_SIZE),
+            session_load_generation: Mutex::new(0),
             baseline_face_shoulder_ratio: Mutex::new(None),
             baseline_shoulder_alignment: Mutex::new(None),
             baseline_head_forward_ratio: Mutex::new(None),
+            baseline_shoulder_ear_ratio: Mutex::new(None),
+            baseline_std_devs: Mutex::new(BaselineRatios::default()),
+            baseline_calibrated_at: Mutex::new(None),
+            baseline_quality: Mutex::new(None),
 
             // ✨ 추가된 필드 초기화
             temporal_threshold_count: Mutex::new(DEFAULT_THRESHOLD_COUNT),
+            release_threshold_count: Mutex::new(DEFAULT_RELEASE_COUNT),
+            temporal_decay: Mutex::new(1.0),
+            turtle_neck_latched: Mutex::new(false),
+            shoulder_latched: Mutex::new(false),
             turtle_neck_thresholds: Mutex::new(DEFAULT_TURTLE_THRESHOLDS),
             shoulder_alignment_thresholds: Mutex::new(DEFAULT_SHOULDER_THRESHOLDS),
         }
@@ -101,15 +698,81 @@ impl PoseAnalyzer {
             _ => 2, // 보통 (3번 중 2번)
         };
         *self.temporal_threshold_count.lock() = count;
+        self.clamp_release_threshold(count);
         info!("알림 빈도 설정 변경: 3번 중 {}번", count);
     }
 
+    // 시간 창 안에서 알림에 필요한 감지 횟수를 직접 설정 (1 ~ 창 크기)
+    pub fn set_required_detection_count(&self, count: usize) -> Result<()> {
+        if count == 0 || count > self.temporal_window_size {
+            return Err(anyhow!(
+                "감지 횟수는 1에서 {} 사이여야 합니다: {}",
+                self.temporal_window_size,
+                count
+            ));
+        }
+        *self.temporal_threshold_count.lock() = count;
+        self.clamp_release_threshold(count);
+        info!("알림 빈도 설정 변경: {}번 중 {}번", self.temporal_window_size, count);
+        Ok(())
+    }
+
+    pub fn required_detection_count(&self) -> usize {
+        *self.temporal_threshold_count.lock()
+    }
+
+    // 감지를 켜는 횟수와 끄는 횟수를 함께 설정 (히스테리시스, off_count < on_count <= 창 크기)
+    // 두 기준 사이에서는 직전 상태를 유지해 경계값 근처에서 감지가 깜빡이지 않게 함
+    pub fn set_detection_hysteresis(&self, on_count: usize, off_count: usize) -> Result<()> {
+        if off_count >= on_count {
+            return Err(anyhow!(
+                "해제 기준({})은 감지 기준({})보다 작아야 합니다",
+                off_count,
+                on_count
+            ));
+        }
+        self.set_required_detection_count(on_count)?;
+        *self.release_threshold_count.lock() = off_count;
+        info!("감지 히스테리시스 설정: {}번 이상 감지 시 켜짐, {}번 이하로 줄면 꺼짐", on_count, off_count);
+        Ok(())
+    }
+
+    // 감지 이력의 시간 감쇠 계수 설정 (0 < decay <= 1.0, 작을수록 최근 프레임의 비중이 커짐)
+    pub fn set_temporal_decay(&self, decay: f32) -> Result<()> {
+        if !(decay > 0.0 && decay <= 1.0) {
+            return Err(anyhow!("감쇠 계수는 0보다 크고 1.0 이하여야 합니다: {}", decay));
+        }
+        *self.temporal_decay.lock() = decay;
+        info!("감지 이력 감쇠 계수 설정: {}", decay);
+        Ok(())
+    }
+
+    pub fn temporal_decay(&self) -> f32 {
+        *self.temporal_decay.lock()
+    }
+
+    pub fn release_threshold_count(&self) -> usize {
+        *self.release_threshold_count.lock()
+    }
+
+    // 감지 기준이 낮아져 해제 기준과 같거나 작아지면 해제 기준을 한 단계 아래로 맞춤
+    fn clamp_release_threshold(&self, on_count: usize) {
+        let mut release = self.release_threshold_count.lock();
+        if *release >= on_count {
+            *release = on_count.saturating_sub(1);
+        }
+    }
+
+    pub fn temporal_window_size(&self) -> usize {
+        self.temporal_window_size
+    }
+
     // ✨ 추가된 함수: 거북목 감지 강도 설정
     pub fn set_turtle_neck_sensitivity(&self, level: u8) {
         let thresholds = match level {
-            1 => (0.040, 0.030), // 느슨하게 (기준치 초과 허용 범위 넓음)
-            3 => (0.020, 0.015), // 엄격하게 (기준치 초과 허용 범위 좁음)
-            _ => (0.030, 0.020), // 보통 (기본값)
+            1 => TURTLE_NECK_SENSITIVITY_PRESETS[0], // 느슨하게 (기준치 초과 허용 범위 넓음)
+            3 => TURTLE_NECK_SENSITIVITY_PRESETS[2], // 엄격하게 (기준치 초과 허용 범위 좁음)
+            _ => TURTLE_NECK_SENSITIVITY_PRESETS[1], // 보통 (기본값)
         };
         *self.turtle_neck_thresholds.lock() = thresholds;
         info!("거북목 감지 강도 변경: level {}", level);
@@ -118,35 +781,399 @@ impl PoseAnalyzer {
     // ✨ 추가된 함수: 어깨 정렬 감지 강도 설정
     pub fn set_shoulder_sensitivity(&self, level: u8) {
         let thresholds = match level {
-            1 => (1.2, 0.22), // 느슨하게
-            3 => (0.7, 0.15), // 엄격하게
-            _ => (0.9, 0.18), // 보통 (기본값)
+            1 => SHOULDER_SENSITIVITY_PRESETS[0], // 느슨하게
+            3 => SHOULDER_SENSITIVITY_PRESETS[2], // 엄격하게
+            _ => SHOULDER_SENSITIVITY_PRESETS[1], // 보통 (기본값)
         };
         *self.shoulder_alignment_thresholds.lock() = thresholds;
         info!("어깨 정렬 감지 강도 변경: level {}", level);
     }
 
+    // 현재 감지 강도가 몇 단계(1~3)인지 (원시값을 직접 지정했거나 강도 조절이 없는 항목이면 None)
+    pub fn sensitivity_level(&self, detector: &str) -> Option<u8> {
+        let (current, presets) = match detector {
+            "turtle_neck" => (*self.turtle_neck_thresholds.lock(), TURTLE_NECK_SENSITIVITY_PRESETS),
+            "shoulder_misalignment" => (*self.shoulder_alignment_thresholds.lock(), SHOULDER_SENSITIVITY_PRESETS),
+            _ => return None,
+        };
+        presets
+            .iter()
+            .position(|&preset| preset == current)
+            .map(|index| index as u8 + 1)
+    }
+
+    // 거북목/어깨 감지 강도 원시값 (캘리브레이션 문자열 내보내기용)
+    pub fn detection_thresholds(&self) -> ((f32, f32), (f32, f32)) {
+        (*self.turtle_neck_thresholds.lock(), *self.shoulder_alignment_thresholds.lock())
+    }
+
+    // 가져온 감지 강도 원시값 검증: 유한한 양수여야 하고, 프리셋처럼 첫 값(허용 범위)이 둘째 값(최소 기준)보다 작지 않아야 함
+    pub fn validate_detection_thresholds(turtle_neck: (f32, f32), shoulder_alignment: (f32, f32)) -> Result<()> {
+        for (name, (first, second)) in [("거북목", turtle_neck), ("어깨 정렬", shoulder_alignment)] {
+            if !(first.is_finite() && second.is_finite() && first > 0.0 && second > 0.0) {
+                return Err(anyhow!("{} 감지 강도는 0보다 큰 숫자여야 합니다: ({}, {})", name, first, second));
+            }
+            if first < second {
+                return Err(anyhow!("{} 감지 강도의 순서가 뒤바뀌었습니다: ({}, {})", name, first, second));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_detection_thresholds(&self, turtle_neck: (f32, f32), shoulder_alignment: (f32, f32)) {
+        *self.turtle_neck_thresholds.lock() = turtle_neck;
+        *self.shoulder_alignment_thresholds.lock() = shoulder_alignment;
+        info!("감지 강도 원시값 적용: 거북목 {:?}, 어깨 {:?}", turtle_neck, shoulder_alignment);
+    }
+
+    // 키포인트 스무딩에 사용할 프레임 수 설정 (1이면 스무딩 없음)
+    pub fn set_keypoint_smoothing_window(&self, window: usize) {
+        let window = window.clamp(1, 10);
+        *self.keypoint_smoothing_window.lock() = window;
+        let mut history = self.recent_keypoints.lock();
+        while history.len() > window {
+            history.pop_front();
+        }
+        info!("키포인트 스무딩 프레임 수 변경: {}", window);
+    }
+
+    // 분석 대상 선택 방식 설정
+    pub fn set_subject_selection(&self, selection: SubjectSelection) {
+        *self.subject_selection.lock() = selection;
+        info!("분석 대상 선택 방식 변경: {}", selection.name());
+    }
+
+    pub fn set_image_normalization(&self, normalization: ImageNormalization) {
+        *self.image_normalization.lock() = normalization;
+        info!("추론 전 밝기 보정 방식 변경: {}", normalization.name());
+    }
+
+    pub fn image_normalization(&self) -> ImageNormalization {
+        *self.image_normalization.lock()
+    }
+
+    pub fn set_resize_filter(&self, filter: ResizeFilter) {
+        *self.resize_filter.lock() = filter;
+        info!("전처리 보간 필터 변경: {}", filter.name());
+    }
+
+    pub fn resize_filter(&self) -> ResizeFilter {
+        *self.resize_filter.lock()
+    }
+
+    // 분석 대상으로 인정할 최소 바운딩 박스 면적 비율 설정 (뒤로 지나가는 사람처럼 멀리 있는 사람을 무시)
+    pub fn set_min_detection_area(&self, fraction: f32) -> Result<()> {
+        if !(0.0..=MAX_MIN_DETECTION_AREA).contains(&fraction) {
+            return Err(anyhow!(
+                "최소 감지 영역은 0에서 {} 사이여야 합니다: {}",
+                MAX_MIN_DETECTION_AREA,
+                fraction
+            ));
+        }
+        *self.min_detection_area.lock() = fraction;
+        info!("최소 감지 영역 설정: 프레임의 {:.1}%", fraction * 100.0);
+        Ok(())
+    }
+
+    pub fn min_detection_area(&self) -> f32 {
+        *self.min_detection_area.lock()
+    }
+
+    // 자세 점수 감점 가중치 설정 (각 항목은 100점을 넘지 않도록 제한)
+    pub fn set_score_weights(&self, weights: ScoreWeights) {
+        let weights = ScoreWeights {
+            turtle_neck: weights.turtle_neck.min(100),
+            shoulder_misalignment: weights.shoulder_misalignment.min(100),
+            hand_on_face: weights.hand_on_face.min(100),
+            head_dropped: weights.head_dropped.min(100),
+            shoulder_tension: weights.shoulder_tension.min(100),
+        };
+        *self.score_weights.lock() = weights;
+        info!("자세 점수 가중치 변경: {:?}", weights);
+    }
+
+    pub fn score_weights(&self) -> ScoreWeights {
+        *self.score_weights.lock()
+    }
+
+    // 감지 항목 하나를 켜거나 끔 (꺼지면 해당 항목의 최근 감지 이력도 초기화)
+    pub fn set_detector_enabled(&self, name: &str, enabled: bool) -> Result<()> {
+        self.detector_toggles.lock().set(name, enabled)?;
+        if !enabled {
+            match name {
+                "turtle_neck" => self.recent_turtle_neck_results.lock().clear(),
+                "shoulder_misalignment" => self.recent_shoulder_results.lock().clear(),
+                _ => {}
+            }
+        }
+        info!("감지 항목 설정 변경: {} = {}", name, enabled);
+        Ok(())
+    }
+
+    pub fn set_detector_toggles(&self, toggles: DetectorToggles) {
+        *self.detector_toggles.lock() = toggles;
+    }
+
+    pub fn detector_toggles(&self) -> DetectorToggles {
+        *self.detector_toggles.lock()
+    }
+
+    // 카메라 회전 각도 설정 (세로/회전 설치된 카메라용)
+    // 각도가 바뀌면 이전 좌표계의 키포인트 이력은 의미가 없으므로 초기화
+    pub fn set_camera_rotation(&self, degrees: u16) -> Result<()> {
+        if !matches!(degrees, 0 | 90 | 180 | 270) {
+            return Err(anyhow!("지원하지 않는 회전 각도입니다: {} (0/90/180/270)", degrees));
+        }
+        let previous = std::mem::replace(&mut *self.camera_rotation.lock(), degrees);
+        if previous != degrees {
+            self.reset_for_source_change();
+        }
+        info!("카메라 회전 각도 변경: {}도", degrees);
+        Ok(())
+    }
+
+    pub fn camera_rotation(&self) -> u16 {
+        *self.camera_rotation.lock()
+    }
+
+    // 고해상도 카메라 프레임 축소 기준 설정 (긴 변 기준)
+    pub fn set_max_frame_dimension(&self, max_dimension: u32) -> Result<()> {
+        if max_dimension < MIN_FRAME_DIMENSION {
+            return Err(anyhow!(
+                "최대 프레임 크기는 {}px 이상이어야 합니다: {}",
+                MIN_FRAME_DIMENSION,
+                max_dimension
+            ));
+        }
+        *self.max_frame_dimension.lock() = max_dimension;
+        info!("최대 프레임 크기 변경: {}px", max_dimension);
+        Ok(())
+    }
+
+    pub fn max_frame_dimension(&self) -> u32 {
+        *self.max_frame_dimension.lock()
+    }
+
+    // 저전력 추론 사용 여부 (배터리 절약 모드에서만 켜짐)
+    pub fn set_low_power_inference(&self, enabled: bool) {
+        let previous = std::mem::replace(&mut *self.low_power_inference.lock(), enabled);
+        if previous != enabled {
+            info!("저전력 추론: {} (입력 {}px)", enabled, self.inference_input_size());
+        }
+    }
+
+    pub fn low_power_inference(&self) -> bool {
+        *self.low_power_inference.lock()
+    }
+
+    // 가변 입력 모델에 사용할 입력 크기 설정 (32의 배수, 160~1280)
+    // 고정 입력 모델이 로드되어 있으면 모델의 입력 크기와 같아야 함
+    pub fn set_model_input_size(&self, size: u32) -> Result<()> {
+        if size % 32 != 0 || !(MIN_MODEL_INPUT_SIZE..=MAX_MODEL_INPUT_SIZE).contains(&size) {
+            return Err(anyhow!(
+                "모델 입력 크기는 {}~{} 사이의 32의 배수여야 합니다: {}",
+                MIN_MODEL_INPUT_SIZE,
+                MAX_MODEL_INPUT_SIZE,
+                size
+            ));
+        }
+        if let Some(fixed) = *self.fixed_input_size.lock() {
+            if fixed != size {
+                return Err(anyhow!("현재 모델은 {}px 고정 입력만 지원합니다: {}", fixed, size));
+            }
+        }
+        *self.configured_input_size.lock() = size;
+        info!("모델 입력 크기 설정: {}px", size);
+        Ok(())
+    }
+
+    pub fn fixed_input_size(&self) -> Option<u32> {
+        *self.fixed_input_size.lock()
+    }
+
+    // 저전력 추론을 적용하지 않은 모델 입력 크기 (고정 입력 모델은 모델의 크기)
+    pub fn model_input_size(&self) -> u32 {
+        self.fixed_input_size
+            .lock()
+            .unwrap_or_else(|| *self.configured_input_size.lock())
+    }
+
+    // 현재 추론에 사용할 모델 입력 크기
+    pub fn inference_input_size(&self) -> u32 {
+        let base = self.model_input_size();
+        if self.low_power_inference() && *self.dynamic_input.lock() {
+            LOW_POWER_INPUT_SIZE.min(base)
+        } else {
+            base
+        }
+    }
+
+    // 회전을 적용해 바로 세운 이미지의 크기
+    fn upright_dimensions(&self, width: u32, height: u32) -> (u32, u32) {
+        match self.camera_rotation() {
+            90 | 270 => (height, width),
+            _ => (width, height),
+        }
+    }
+
+    // 바로 세운 이미지 좌표 (u, v)를 원본(화면 표시) 방향 좌표로 되돌림
+    fn point_to_display(&self, u: f32, v: f32, display_width: u32, display_height: u32) -> (f32, f32) {
+        let (w, h) = (display_width as f32, display_height as f32);
+        match self.camera_rotation() {
+            90 => (v, h - u),
+            180 => (w - u, h - v),
+            270 => (w - v, u),
+            _ => (u, v),
+        }
+    }
+
+    // 바로 세운 이미지 기준 키포인트를 원본(화면 표시) 방향 좌표로 되돌림
+    fn keypoints_to_display(
+        &self,
+        keypoints: &PoseKeypoints,
+        display_width: u32,
+        display_height: u32,
+    ) -> PoseKeypoints {
+        PoseKeypoints::from_array(keypoints.to_array().map(|point| {
+            let (x, y) = self.point_to_display(point.x, point.y, display_width, display_height);
+            KeyPoint { x, y, confidence: point.confidence }
+        }))
+    }
+
+    // 바로 세운 이미지 기준 바운딩 박스를 원본(화면 표시) 방향 좌표로 되돌림
+    fn bounding_box_to_display(
+        &self,
+        bounding_box: &BoundingBox,
+        display_width: u32,
+        display_height: u32,
+    ) -> BoundingBox {
+        let to_display = |u: f32, v: f32| self.point_to_display(u, v, display_width, display_height);
+        let (x1, y1) = to_display(bounding_box.x, bounding_box.y);
+        let (x2, y2) = to_display(
+            bounding_box.x + bounding_box.width,
+            bounding_box.y + bounding_box.height,
+        );
+        BoundingBox {
+            x: x1.min(x2),
+            y: y1.min(y2),
+            width: (x2 - x1).abs(),
+            height: (y2 - y1).abs(),
+        }
+    }
+
     // ✨ 추가된 함수: 최근 결과 초기화 (알림 발생 시)
     pub fn clear_recent_results(&self) {
         self.recent_turtle_neck_results.lock().clear();
         self.recent_shoulder_results.lock().clear();
+        *self.turtle_neck_latched.lock() = false;
+        *self.shoulder_latched.lock() = false;
+        self.recent_keypoints.lock().clear();
+    }
+
+    // 새 모델 로드 시도를 시작하고 그 시도 번호를 반환 (이전 시도의 로드 결과는 이후 적용되지 않음)
+    pub fn begin_session_load(&self) -> u64 {
+        let mut generation = self.session_load_generation.lock();
+        *generation += 1;
+        *generation
+    }
+
+    // 모델 파일로 세션을 생성해 적용 (디스크 상태에 따라 오래 걸릴 수 있는 블로킹 작업)
+    // 그 사이 새 로드 시도가 시작되었으면 만든 세션을 버리고 오류를 반환
+    pub fn load_session(&self, model_path: &std::path::Path, generation: u64) -> Result<()> {
+        let session = self.build_session(model_path)?;
+        // 입력 텐서(NCHW)의 높이/너비가 음수면 가변 입력, 양수면 그 크기로 고정된 입력
+        let input_shape: Vec<i64> = session
+            .inputs
+            .first()
+            .and_then(|input| input.input_type.tensor_shape())
+            .map(|shape| shape.iter().copied().collect())
+            .ok_or_else(|| anyhow!("모델 입력 형식을 확인할 수 없습니다"))?;
+        if input_shape.len() != 4 {
+            return Err(anyhow!("지원하지 않는 모델 입력 형식입니다: {:?}", input_shape));
+        }
+        let (height, width) = (input_shape[2], input_shape[3]);
+        let fixed_input_size = if height > 0 && width > 0 {
+            if height != width {
+                return Err(anyhow!("정사각형 입력 모델만 지원합니다: {}x{}", width, height));
+            }
+            let configured = *self.configured_input_size.lock();
+            if configured != height as u32 {
+                warn!("설정한 입력 크기({}px) 대신 모델의 고정 입력 크기({}px)를 사용합니다", configured, height);
+            }
+            Some(height as u32)
+        } else {
+            None
+        };
+        let dynamic_input = fixed_input_size.is_none();
+        // 시도 번호를 확인하는 동안 잠금을 유지해 새 시도와 세션 교체가 엇갈리지 않게 함
+        let current_generation = self.session_load_generation.lock();
+        if *current_generation != generation {
+            return Err(anyhow!(
+                "더 최근의 모델 로드 시도({})가 있어 {}번 시도의 세션을 버립니다",
+                *current_generation,
+                generation
+            ));
+        }
+        info!("모델 입력: {:?} (가변 입력 {})", input_shape, dynamic_input);
+        *self.dynamic_input.lock() = dynamic_input;
+        *self.fixed_input_size.lock() = fixed_input_size;
+        *self.session.lock() = Some(session);
+        *self.model_path.lock() = Some(model_path.to_path_buf());
+        Ok(())
     }
 
-    // ONNX 모델 초기화
-    pub async fn initialize_model(&self, handle: AppHandle) -> Result<()> {
-        info!("YOLO-pose 모델 초기화 시작...");
-        let model_path = self.download_verified_yolo_model(handle).await?;
+    // 현재 최적화 수준으로 세션 생성
+    fn build_session(&self, model_path: &std::path::Path) -> Result<Session> {
+        let level = *self.optimization_level.lock();
+        let optimization_level = match level {
+            0 => GraphOptimizationLevel::Disable,
+            1 => GraphOptimizationLevel::Level1,
+            2 => GraphOptimizationLevel::Level2,
+            _ => GraphOptimizationLevel::Level3,
+        };
+        info!("ONNX 세션 생성: 최적화 수준 {}", level);
         let session = SessionBuilder::new()?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_optimization_level(optimization_level)?
             .with_intra_threads(4)?
             .commit_from_file(model_path)?;
-        *self.session.lock() = Some(session);
-        info!("YOLO-pose 모델 초기화 완료");
-        Ok(())
+        Ok(session)
+    }
+
+    // ONNX 그래프 최적화 수준 변경, 모델이 로드되어 있으면 세션을 다시 생성
+    // 세션을 다시 만들었으면 true 반환
+    pub fn set_optimization_level(&self, level: u8) -> Result<bool> {
+        if level > 3 {
+            return Err(anyhow!("지원하지 않는 최적화 수준입니다: {} (0~3)", level));
+        }
+        let previous = std::mem::replace(&mut *self.optimization_level.lock(), level);
+        let model_path = self.model_path.lock().clone();
+        let Some(model_path) = model_path else {
+            info!("최적화 수준 변경: {} (모델 로드 시 적용)", level);
+            return Ok(false);
+        };
+        match self.build_session(&model_path) {
+            Ok(session) => {
+                *self.session.lock() = Some(session);
+                info!("최적화 수준 {}로 세션 재생성 완료", level);
+                Ok(true)
+            }
+            Err(e) => {
+                *self.optimization_level.lock() = previous;
+                Err(anyhow!("최적화 수준 {}로 세션 재생성 실패: {}", level, e))
+            }
+        }
+    }
+
+    pub fn optimization_level(&self) -> u8 {
+        *self.optimization_level.lock()
+    }
+
+    pub fn model_path(&self) -> Option<std::path::PathBuf> {
+        self.model_path.lock().clone()
     }
 
     // 리소스 폴더에서 모델 파일 경로 확인
-    async fn download_verified_yolo_model(&self, handle: AppHandle) -> Result<std::path::PathBuf> {
+    pub async fn download_verified_yolo_model(&self, handle: AppHandle) -> Result<std::path::PathBuf> {
         let model_path = handle
             .path()
             .resolve("../models/yolo11n-pose.onnx", BaseDirectory::Resource)
@@ -162,6 +1189,37 @@ impl PoseAnalyzer {
         Ok(model_path)
     }
 
+    // 디버깅용으로 현재 분석기 설정과 감지 이력을 JSON으로 반환
+    pub fn debug_state(&self) -> serde_json::Value {
+        serde_json::json!({
+            "model_initialized": self.is_model_initialized(),
+            "optimization_level": self.optimization_level(),
+            "confidence_threshold": self.confidence_threshold(),
+            "temporal_window_size": self.temporal_window_size,
+            "required_detection_count": *self.temporal_threshold_count.lock(),
+            "release_detection_count": self.release_threshold_count(),
+            "temporal_decay": self.temporal_decay(),
+            "turtle_neck_latched": *self.turtle_neck_latched.lock(),
+            "shoulder_latched": *self.shoulder_latched.lock(),
+            "turtle_neck_thresholds": *self.turtle_neck_thresholds.lock(),
+            "shoulder_alignment_thresholds": *self.shoulder_alignment_thresholds.lock(),
+            "keypoint_smoothing_window": *self.keypoint_smoothing_window.lock(),
+            "subject_selection": self.subject_selection.lock().name(),
+            "min_detection_area": self.min_detection_area(),
+            "image_normalization": self.image_normalization().name(),
+            "resize_filter": self.resize_filter().name(),
+            "camera_rotation": self.camera_rotation(),
+            "max_frame_dimension": self.max_frame_dimension(),
+            "model_input_size": self.model_input_size(),
+            "inference_input_size": self.inference_input_size(),
+            "score_weights": self.score_weights(),
+            "detector_toggles": self.detector_toggles(),
+            "recent_turtle_neck_results": Self::history_flags(&self.recent_turtle_neck_results.lock()),
+            "recent_shoulder_results": Self::history_flags(&self.recent_shoulder_results.lock()),
+            "baseline": self.baseline_to_json(),
+        })
+    }
+
     // 모델 초기화 여부 확인
     pub fn is_model_initialized(&self) -> bool {
         self.session.lock().is_some()
@@ -176,65 +1234,244 @@ impl PoseAnalyzer {
         }
     }
 
-    // 이미지 버퍼를 분석하는 핵심 함수
+    // 프레임을 캡처할 때 호출해 다음 캡처 순번을 받음 (캡처 순서대로 증가)
+    pub fn next_capture_sequence(&self) -> u64 {
+        let mut sequence = self.capture_sequence.lock();
+        *sequence += 1;
+        *sequence
+    }
+
+    // 이미지 버퍼를 분석하는 핵심 함수 (캡처 순번을 모르면 지금 캡처한 것으로 봄)
     pub fn analyze_image_buffer(
         &self,
         image_buffer: &ImageBuffer<Rgb<u8>, Vec<u8>>,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        self.analyze_image_buffer_at(image_buffer, self.next_capture_sequence())
+    }
+
+    // 캡처 순번이 붙은 프레임을 분석
+    // 재연결 등으로 순서가 뒤바뀐 프레임은 결과만 돌려주고 감지 이력에는 넣지 않음
+    pub fn analyze_image_buffer_at(
+        &self,
+        image_buffer: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+        capture_sequence: u64,
+    ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let analysis_start = Instant::now();
         info!("analyze_image_buffer 시작, 이미지 크기: {}x{}", image_buffer.width(), image_buffer.height());
         if !self.is_model_initialized() {
             info!("모델 초기화되지 않음");
             return Ok(serde_json::json!({
+                "schema_version": ANALYSIS_SCHEMA_VERSION,
                 "status": "model_not_initialized",
                 "recommendations": ["AI 모델을 먼저 초기화해주세요"],
             })
             .to_string());
         }
 
-        let keypoints = self.extract_pose_keypoints(image_buffer)?;
+        let inference_start = Instant::now();
+        let detection = self.detect_pose(image_buffer)?;
+        let inference_ms = inference_start.elapsed().as_secs_f64() * 1000.0;
+        if inference_ms > SLOW_INFERENCE_WARN_MS {
+            warn!("추론 시간이 깁니다: {:.1}ms", inference_ms);
+        }
+        let keypoints = self.smooth_keypoints(detection.keypoints);
+        self.record_drift_sample(&keypoints);
 
-        let current_turtle_neck = self.detect_turtle_neck(&keypoints);
-        let current_shoulder_misalignment = self.detect_shoulder_misalignment(&keypoints);
-        let realtime_posture_score =
-            self.calculate_posture_score(current_turtle_neck, current_shoulder_misalignment);
+        // 꺼진 감지 항목은 실행하지 않고 None(null)으로 보고
+        let toggles = self.detector_toggles();
+        let current_turtle_neck = toggles
+            .turtle_neck
+            .then(|| self.detect_turtle_neck(&keypoints));
+        let current_shoulder_misalignment = toggles
+            .shoulder_misalignment
+            .then(|| self.detect_shoulder_misalignment(&keypoints));
+        let hand_on_face = toggles
+            .hand_on_face
+            .then(|| self.detect_hand_on_face(&keypoints));
+        let head_dropped = toggles
+            .head_dropped
+            .then(|| self.detect_head_dropped(&keypoints));
+        let shoulder_tension = toggles
+            .shoulder_tension
+            .then(|| self.detect_shoulder_tension(&keypoints));
+        let realtime_posture_score = self.calculate_posture_score(&PostureFlags {
+            turtle_neck: current_turtle_neck.unwrap_or(false),
+            shoulder_misalignment: current_shoulder_misalignment.unwrap_or(false),
+            hand_on_face: hand_on_face.unwrap_or(false),
+            head_dropped: head_dropped.unwrap_or(false),
+            shoulder_tension: shoulder_tension.unwrap_or(false),
+        });
 
-        // ✨ 수정: 설정된 알림 빈도(threshold_count)를 사용
-        let threshold_count = *self.temporal_threshold_count.lock();
+        let stale_frame = self.is_stale_capture(capture_sequence);
+        if stale_frame {
+            warn!("이미 반영된 프레임보다 먼저 캡처된 프레임이라 감지 이력에 넣지 않음 (순번 {})", capture_sequence);
+        }
+        let final_turtle_neck = current_turtle_neck
+            .map(|detected| {
+                self.push_temporal_result(
+                    &self.recent_turtle_neck_results,
+                    &self.turtle_neck_latched,
+                    detected,
+                    capture_sequence,
+                )
+            });
+        let final_shoulder_misalignment = current_shoulder_misalignment
+            .map(|detected| {
+                self.push_temporal_result(
+                    &self.recent_shoulder_results,
+                    &self.shoulder_latched,
+                    detected,
+                    capture_sequence,
+                )
+            });
 
-        let final_turtle_neck = {
-            let mut history = self.recent_turtle_neck_results.lock();
-            if history.len() >= self.temporal_window_size {
-                history.pop_front();
-            }
-            history.push_back(current_turtle_neck);
-            history.iter().filter(|&&detected| detected).count() >= threshold_count
+        // 어깨 비대칭이 확정된 경우에만 어느 쪽 어깨가 올라갔는지 보고
+        let shoulder_misalignment_side = if final_shoulder_misalignment == Some(true) {
+            self.detect_raised_shoulder_side(&keypoints)
+        } else {
+            None
         };
 
-        let final_shoulder_misalignment = {
-            let mut history = self.recent_shoulder_results.lock();
-            if history.len() >= self.temporal_window_size {
-                history.pop_front();
-            }
-            history.push_back(current_shoulder_misalignment);
-            history.iter().filter(|&&detected| detected).count() >= threshold_count
-        };
+        let (upright_width, upright_height) =
+            self.upright_dimensions(image_buffer.width(), image_buffer.height());
+        let in_frame = self.assess_frame_fit(
+            &keypoints,
+            &detection.bounding_box,
+            upright_width,
+            upright_height,
+        );
+        let bounding_box = self.bounding_box_to_display(
+            &detection.bounding_box,
+            image_buffer.width(),
+            image_buffer.height(),
+        );
+        let monitor_height = self.estimate_monitor_height(&keypoints, upright_height);
+        let lean = self.update_lean(&keypoints, upright_width);
+        // 내보내기/녹화용 키포인트는 카메라 영상과 겹쳐 볼 수 있도록 화면 표시 방향 좌표로 저장
+        *self.latest_pose.lock() = Some(LatestPose {
+            keypoints: self.keypoints_to_display(&keypoints, image_buffer.width(), image_buffer.height()),
+            bounding_box: bounding_box.clone(),
+            confidence: detection.confidence,
+            width: image_buffer.width(),
+            height: image_buffer.height(),
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+        });
 
-        let recommendations =
-            self.generate_recommendations(final_turtle_neck, final_shoulder_misalignment);
+        let confirmed_flags = PostureFlags {
+            turtle_neck: final_turtle_neck.unwrap_or(false),
+            shoulder_misalignment: final_shoulder_misalignment.unwrap_or(false),
+            hand_on_face: hand_on_face.unwrap_or(false),
+            head_dropped: head_dropped.unwrap_or(false),
+            shoulder_tension: shoulder_tension.unwrap_or(false),
+        };
+        // 알림 단계는 이 프레임 점수가 아니라 확정된 감지 상태로 계산한 점수로 판정
+        let confirmed_posture_score = self.calculate_posture_score(&confirmed_flags);
+        let recommendations = self.generate_recommendations(
+            &confirmed_flags,
+            shoulder_misalignment_side,
+            monitor_height.as_ref(),
+            lean.as_ref(),
+        );
         let avg_confidence = self.calculate_average_confidence(&keypoints);
 
         let result = serde_json::json!({
+            "schema_version": ANALYSIS_SCHEMA_VERSION,
             "turtle_neck": final_turtle_neck,
             "shoulder_misalignment": final_shoulder_misalignment,
+            "shoulder_misalignment_side": shoulder_misalignment_side,
+            "hand_on_face": hand_on_face,
+            "head_dropped": head_dropped,
+            "shoulder_tension": shoulder_tension,
             "posture_score": realtime_posture_score,
+            "confirmed_posture_score": confirmed_posture_score,
             "recommendations": recommendations,
             "confidence": avg_confidence,
+            "detection_confidence": detection.confidence,
+            "bounding_box": bounding_box,
+            "in_frame": in_frame,
+            "monitor_height": monitor_height,
+            "lean": lean,
+            "image_normalization": self.image_normalization().name(),
+            "stale_frame": stale_frame,
+            "inference_ms": inference_ms,
+            "total_ms": analysis_start.elapsed().as_secs_f64() * 1000.0,
             "status": "yolo_analysis_success"
         });
 
         Ok(result.to_string())
     }
 
+    // 최근 감지 이력에 결과를 추가하고 히스테리시스를 적용한 감지 상태를 반환
+    // 꺼져 있으면 알림 빈도(threshold_count) 이상 감지되어야 켜지고, 켜져 있으면 해제 기준 이하로 줄어야 꺼짐
+    // 감지 횟수는 감쇠 가중치를 적용한 뒤 창 크기 기준으로 환산 (감쇠 1.0이면 단순 횟수와 같음)
+    // 이력의 가장 최근 캡처 순번보다 앞선 결과는 버리고 현재 감지 상태를 그대로 반환
+    fn push_temporal_result(
+        &self,
+        history: &Mutex<VecDeque<(u64, bool)>>,
+        latched: &Mutex<bool>,
+        detected: bool,
+        capture_sequence: u64,
+    ) -> bool {
+        let threshold_count = *self.temporal_threshold_count.lock();
+        let release_count = *self.release_threshold_count.lock();
+        let mut history = history.lock();
+        if history.back().is_some_and(|&(latest, _)| capture_sequence < latest) {
+            return *latched.lock();
+        }
+        if history.len() >= self.temporal_window_size {
+            history.pop_front();
+        }
+        history.push_back((capture_sequence, detected));
+        let count = Self::weighted_detection_count(&history, self.temporal_decay());
+
+        let mut latched = latched.lock();
+        if !*latched && count >= threshold_count as f32 {
+            *latched = true;
+        } else if *latched && count <= release_count as f32 {
+            *latched = false;
+        }
+        *latched
+    }
+
+    // 최신 프레임부터 가중치 1, decay, decay^2 ... 을 곱한 감지 비율에 이력 길이를 곱한 값
+    fn weighted_detection_count(history: &VecDeque<(u64, bool)>, decay: f32) -> f32 {
+        // 동일 가중치일 때는 부동소수점 오차 없이 단순 횟수 사용
+        if decay >= 1.0 {
+            return history.iter().filter(|&&(_, detected)| detected).count() as f32;
+        }
+        let mut weight = 1.0;
+        let mut total_weight = 0.0;
+        let mut detected_weight = 0.0;
+        for &(_, detected) in history.iter().rev() {
+            total_weight += weight;
+            if detected {
+                detected_weight += weight;
+            }
+            weight *= decay;
+        }
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        detected_weight / total_weight * history.len() as f32
+    }
+
+    // 두 감지 이력 중 어느 쪽이든 더 최근 프레임이 이미 반영되어 있으면 오래된 프레임
+    fn is_stale_capture(&self, capture_sequence: u64) -> bool {
+        [&self.recent_turtle_neck_results, &self.recent_shoulder_results]
+            .iter()
+            .any(|history| {
+                history
+                    .lock()
+                    .back()
+                    .is_some_and(|&(latest, _)| capture_sequence < latest)
+            })
+    }
+
+    // 디버그 출력용: 캡처 순번을 뺀 감지 여부만
+    fn history_flags(history: &VecDeque<(u64, bool)>) -> Vec<bool> {
+        history.iter().map(|&(_, detected)| detected).collect()
+    }
+
     // Base64 이미지 데이터를 분석하는 래퍼 함수
     pub fn analyze_image_sync(
         &self,
@@ -264,33 +1501,236 @@ impl PoseAnalyzer {
         &self,
         image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
     ) -> Result<PoseKeypoints, Box<dyn std::error::Error + Send + Sync>> {
-        info!("키포인트 추출 시작");
-        let input_tensor = self.preprocess_image(image)?;
+        Ok(self.detect_pose(image)?.keypoints)
+    }
+
+    // 이미지에서 분석 대상 한 명의 포즈를 감지
+    fn detect_pose(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+    ) -> Result<PoseDetection, Box<dyn std::error::Error + Send + Sync>> {
+        self.detect_pose_with_size(
+            image,
+            self.inference_input_size(),
+            self.low_power_inference(),
+            self.resize_filter(),
+        )
+    }
+
+    fn detect_pose_with_size(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+        input_size: u32,
+        low_power: bool,
+        filter: ResizeFilter,
+    ) -> Result<PoseDetection, Box<dyn std::error::Error + Send + Sync>> {
+        info!("키포인트 추출 시작 (입력 {}px)", input_size);
+        // 회전 설치된 카메라는 영상을 바로 세운 뒤 분석 (이후 키포인트 계산은 바로 세운 좌표 기준)
+        let rotated = self.rotate_to_upright(image);
+        let image = rotated.as_ref().unwrap_or(image);
+        self.detect_upright_pose(image, input_size, low_power, filter)
+    }
+
+    // 카메라 회전 각도만큼 시계 방향으로 돌려 바로 세운 이미지 (회전이 없으면 None)
+    fn rotate_to_upright(&self, image: &ImageBuffer<Rgb<u8>, Vec<u8>>) -> Option<ImageBuffer<Rgb<u8>, Vec<u8>>> {
+        match self.camera_rotation() {
+            90 => Some(image::imageops::rotate90(image)),
+            180 => Some(image::imageops::rotate180(image)),
+            270 => Some(image::imageops::rotate270(image)),
+            _ => None,
+        }
+    }
+
+    // 이미 바로 선 이미지에서 포즈를 감지 (카메라 회전을 적용하지 않음)
+    fn detect_upright_pose(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+        input_size: u32,
+        low_power: bool,
+        filter: ResizeFilter,
+    ) -> Result<PoseDetection, Box<dyn std::error::Error + Send + Sync>> {
+        // 큰 프레임은 비율을 유지한 채 먼저 축소 (키포인트는 원본 크기 기준으로 환산되므로 좌표계는 동일)
+        let max_dimension = if low_power {
+            self.max_frame_dimension().min(LOW_POWER_MAX_FRAME_DIMENSION)
+        } else {
+            self.max_frame_dimension()
+        };
+        let longest_side = image.width().max(image.height());
+        let downscaled;
+        let input_image = if longest_side > max_dimension {
+            let scale = max_dimension as f32 / longest_side as f32;
+            let width = ((image.width() as f32 * scale).round() as u32).max(1);
+            let height = ((image.height() as f32 * scale).round() as u32).max(1);
+            downscaled = image::imageops::thumbnail(image, width, height);
+            &downscaled
+        } else {
+            image
+        };
+        let input_tensor = self.preprocess_image(input_image, input_size, filter)?;
         let mut session_guard = self.session.lock();
         let session = session_guard
             .as_mut()
             .ok_or("YOLO-pose 모델이 초기화되지 않았습니다")?;
         let outputs = session.run(ort::inputs!["images" => input_tensor])?;
         info!("모델 실행 성공");
-        self.postprocess_output(&outputs, image.width(), image.height())
+        self.postprocess_output(&outputs, image.width(), image.height(), input_size)
+    }
+
+    // 같은 프레임을 기본 추론과 저전력 추론으로 각각 분석해 소요 시간과 키포인트 평균 신뢰도를 비교
+    // 반환값: (저전력 여부, 입력 크기, 소요 시간 ms, 평균 신뢰도, 감지 성공 여부)
+    pub fn measure_low_power_tradeoff(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+    ) -> Vec<(bool, u32, f64, f32, bool)> {
+        let model_size = self.model_input_size();
+        let low_power_size = if *self.dynamic_input.lock() {
+            LOW_POWER_INPUT_SIZE.min(model_size)
+        } else {
+            model_size
+        };
+        [(false, model_size), (true, low_power_size)]
+            .into_iter()
+            .map(|(low_power, input_size)| {
+                let start = Instant::now();
+                let detection = self.detect_pose_with_size(image, input_size, low_power, self.resize_filter());
+                let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+                let confidence = detection
+                    .as_ref()
+                    .map(|d| self.calculate_average_confidence(&d.keypoints))
+                    .unwrap_or(0.0);
+                info!(
+                    "저전력 비교 - 저전력 {}, 입력 {}px: {:.1}ms, 평균 신뢰도 {:.2}",
+                    low_power, input_size, elapsed_ms, confidence
+                );
+                (low_power, input_size, elapsed_ms, confidence, detection.is_ok())
+            })
+            .collect()
+    }
+
+    // 같은 프레임을 보간 필터별로 분석해 소요 시간과 키포인트 평균 신뢰도를 비교
+    // 반환값: (필터, 소요 시간 ms, 평균 신뢰도, 감지 성공 여부)
+    pub fn measure_resize_filters(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+    ) -> Vec<(ResizeFilter, f64, f32, bool)> {
+        let input_size = self.inference_input_size();
+        let low_power = self.low_power_inference();
+        ResizeFilter::ALL
+            .into_iter()
+            .map(|filter| {
+                let start = Instant::now();
+                let detection = self.detect_pose_with_size(image, input_size, low_power, filter);
+                let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+                let confidence = detection
+                    .as_ref()
+                    .map(|d| self.calculate_average_confidence(&d.keypoints))
+                    .unwrap_or(0.0);
+                info!(
+                    "보간 필터 비교 - {}: {:.1}ms, 평균 신뢰도 {:.2}",
+                    filter.name(), elapsed_ms, confidence
+                );
+                (filter, elapsed_ms, confidence, detection.is_ok())
+            })
+            .collect()
+    }
+
+    // 전처리와 모델 실행만 반복해 회당 소요 시간(ms)을 측정 (후처리·감지 이력은 건드리지 않음)
+    pub fn benchmark_inference(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+        iterations: u32,
+    ) -> Result<Vec<f64>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut latencies = Vec::with_capacity(iterations as usize);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let input_tensor = self.preprocess_image(image, self.inference_input_size(), self.resize_filter())?;
+            let mut session_guard = self.session.lock();
+            let session = session_guard
+                .as_mut()
+                .ok_or("YOLO-pose 모델이 초기화되지 않았습니다")?;
+            session.run(ort::inputs!["images" => input_tensor])?;
+            latencies.push(start.elapsed().as_secs_f64() * 1000.0);
+        }
+        Ok(latencies)
     }
 
     // 이미지를 모델 입력 형식에 맞게 전처리
     fn preprocess_image(
         &self,
         image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+        input_size: u32,
+        filter: ResizeFilter,
     ) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
         info!("이미지 전처리 시작");
-        let resized_image =
-            image::imageops::resize(image, 640, 640, image::imageops::FilterType::Triangle);
-        let mut input_data = Vec::with_capacity(3 * 640 * 640);
+        let mut resized_image =
+            image::imageops::resize(image, input_size, input_size, filter.filter_type());
+        // 밝기 보정은 축소된 입력 이미지에만 적용해 비용을 줄임
+        match self.image_normalization() {
+            ImageNormalization::Off => {}
+            ImageNormalization::Gamma => Self::apply_gamma_normalization(&mut resized_image),
+            ImageNormalization::Equalize => Self::apply_histogram_equalization(&mut resized_image),
+        }
+        let size = input_size as usize;
+        let mut input_data = Vec::with_capacity(3 * size * size);
         for channel in 0..3 {
             for pixel in resized_image.pixels() {
                 input_data.push(pixel.0[channel] as f32 / 255.0);
             }
         }
-        let input_array = Array4::from_shape_vec((1, 3, 640, 640), input_data)?;
-        Ok(Value::from_array(input_array)?.into())
+        let input_array = Array4::from_shape_vec((1, 3, size, size), input_data)?;
+        Ok(Value::from_array(input_array)?.into())
+    }
+
+    fn pixel_luma(pixel: &Rgb<u8>) -> u8 {
+        let [r, g, b] = pixel.0;
+        ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+    }
+
+    // 평균 밝기가 중간(0.5)이 되도록 감마를 정해 모든 채널에 적용 (과도한 보정을 막기 위해 감마 범위 제한)
+    fn apply_gamma_normalization(image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>) {
+        let pixel_count = (image.width() * image.height()).max(1) as f32;
+        let mean = image.pixels().map(|p| Self::pixel_luma(p) as f32).sum::<f32>() / pixel_count / 255.0;
+        if mean <= 0.0 || mean >= 1.0 {
+            return;
+        }
+        let gamma = (0.5f32.ln() / mean.ln()).clamp(MIN_NORMALIZATION_GAMMA, MAX_NORMALIZATION_GAMMA);
+        let lut: Vec<u8> = (0..=255u32)
+            .map(|v| ((v as f32 / 255.0).powf(gamma) * 255.0).round() as u8)
+            .collect();
+        for pixel in image.pixels_mut() {
+            for channel in pixel.0.iter_mut() {
+                *channel = lut[*channel as usize];
+            }
+        }
+    }
+
+    // 밝기 히스토그램 평활화: 밝기 누적 분포로 새 밝기를 구하고, 색이 바뀌지 않도록 RGB를 같은 비율로 조정
+    fn apply_histogram_equalization(image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>) {
+        let mut histogram = [0u32; 256];
+        for pixel in image.pixels() {
+            histogram[Self::pixel_luma(pixel) as usize] += 1;
+        }
+        let total: u32 = histogram.iter().sum();
+        let cdf_min = histogram.iter().copied().find(|&count| count > 0).unwrap_or(0);
+        if total <= cdf_min {
+            return; // 한 가지 밝기로만 이루어진 이미지
+        }
+        let mut lut = [0u8; 256];
+        let mut cumulative = 0u32;
+        for (value, count) in histogram.iter().enumerate() {
+            cumulative += count;
+            lut[value] = (cumulative.saturating_sub(cdf_min) as f32 / (total - cdf_min) as f32 * 255.0).round() as u8;
+        }
+        for pixel in image.pixels_mut() {
+            let luma = Self::pixel_luma(pixel);
+            if luma == 0 {
+                continue;
+            }
+            let scale = lut[luma as usize] as f32 / luma as f32;
+            for channel in pixel.0.iter_mut() {
+                *channel = (*channel as f32 * scale).min(255.0) as u8;
+            }
+        }
     }
 
     // 모델 출력값을 후처리하여 키포인트 데이터로 변환
@@ -299,7 +1739,8 @@ impl PoseAnalyzer {
         outputs: &SessionOutputs,
         orig_width: u32,
         orig_height: u32,
-    ) -> Result<PoseKeypoints, Box<dyn std::error::Error + Send + Sync>> {
+        input_size: u32,
+    ) -> Result<PoseDetection, Box<dyn std::error::Error + Send + Sync>> {
         info!("출력 후처리 시작");
         let output = outputs
             .get("output0")
@@ -311,154 +1752,55 @@ impl PoseAnalyzer {
         }
         let detections = shape[2] as usize;
         info!("detections 수: {}", detections);
+
+        // 출력 행 0~3은 입력(input_size x input_size) 기준 박스 중심(cx, cy)과 크기(w, h), 4는 신뢰도
+        let input_size = input_size as f32;
+        let center = input_size / 2.0;
+        let selection = *self.subject_selection.lock();
+        let min_area = self.min_detection_area() * input_size * input_size;
         let mut best_detection = None;
-        let mut best_confidence = 0.0f32;
+        let mut best_score = f32::MIN;
         for i in 0..detections {
-            let confidence_idx = 4 * detections + i;
-            let confidence = data[confidence_idx];
-            //info!("detection {} confidence: {}", i, confidence);
-            if confidence > best_confidence && confidence > self.confidence_threshold {
-                best_confidence = confidence;
+            let confidence = data[4 * detections + i];
+            if confidence <= self.confidence_threshold() {
+                continue;
+            }
+            let (cx, cy) = (data[i], data[detections + i]);
+            let (w, h) = (data[2 * detections + i], data[3 * detections + i]);
+            if w * h < min_area {
+                continue;
+            }
+            let score = match selection {
+                SubjectSelection::HighestConfidence => confidence,
+                SubjectSelection::Largest => w * h,
+                SubjectSelection::Centered => -((cx - center).powi(2) + (cy - center).powi(2)).sqrt(),
+            };
+            if score > best_score {
+                best_score = score;
                 best_detection = Some(i);
             }
         }
         let detection_idx =
             best_detection.ok_or("신뢰할 수 있는 pose detection을 찾을 수 없습니다")?;
-        info!("최적 detection 찾음: {}", detection_idx);
-        let scale_x = orig_width as f32 / 640.0;
-        let scale_y = orig_height as f32 / 640.0;
-        let keypoints = PoseKeypoints {
-            nose: self.extract_keypoint_from_data(data, shape, detection_idx, 0, scale_x, scale_y),
-            left_eye: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                1,
-                scale_x,
-                scale_y,
-            ),
-            right_eye: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                2,
-                scale_x,
-                scale_y,
-            ),
-            left_ear: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                3,
-                scale_x,
-                scale_y,
-            ),
-            right_ear: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                4,
-                scale_x,
-                scale_y,
-            ),
-            left_shoulder: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                5,
-                scale_x,
-                scale_y,
-            ),
-            right_shoulder: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                6,
-                scale_x,
-                scale_y,
-            ),
-            left_elbow: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                7,
-                scale_x,
-                scale_y,
-            ),
-            right_elbow: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                8,
-                scale_x,
-                scale_y,
-            ),
-            left_wrist: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                9,
-                scale_x,
-                scale_y,
-            ),
-            right_wrist: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                10,
-                scale_x,
-                scale_y,
-            ),
-            left_hip: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                11,
-                scale_x,
-                scale_y,
-            ),
-            right_hip: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                12,
-                scale_x,
-                scale_y,
-            ),
-            left_knee: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                13,
-                scale_x,
-                scale_y,
-            ),
-            right_knee: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                14,
-                scale_x,
-                scale_y,
-            ),
-            left_ankle: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                15,
-                scale_x,
-                scale_y,
-            ),
-            right_ankle: self.extract_keypoint_from_data(
-                data,
-                shape,
-                detection_idx,
-                16,
-                scale_x,
-                scale_y,
-            ),
-        };
-        Ok(keypoints)
+        info!("최적 detection 찾음: {} ({})", detection_idx, selection.name());
+        let scale_x = orig_width as f32 / input_size;
+        let scale_y = orig_height as f32 / input_size;
+        let keypoints = PoseKeypoints::from_array(std::array::from_fn(|keypoint_idx| {
+            self.extract_keypoint_from_data(data, shape, detection_idx, keypoint_idx, scale_x, scale_y)
+        }));
+        let (cx, cy) = (data[detection_idx], data[detections + detection_idx]);
+        let (w, h) = (data[2 * detections + detection_idx], data[3 * detections + detection_idx]);
+        let bounding_box = BoundingBox {
+            x: (cx - w / 2.0) * scale_x,
+            y: (cy - h / 2.0) * scale_y,
+            width: w * scale_x,
+            height: h * scale_y,
+        };
+        Ok(PoseDetection {
+            keypoints,
+            bounding_box,
+            confidence: data[4 * detections + detection_idx],
+        })
     }
 
     // 후처리된 데이터에서 특정 키포인트 정보를 추출
@@ -482,6 +1824,48 @@ impl PoseAnalyzer {
         KeyPoint { x, y, confidence }
     }
 
+    // 최근 프레임들의 키포인트를 신뢰도 가중 평균하여 단일 프레임 노이즈를 줄임
+    fn smooth_keypoints(&self, keypoints: PoseKeypoints) -> PoseKeypoints {
+        let window = *self.keypoint_smoothing_window.lock();
+        let mut history = self.recent_keypoints.lock();
+        while history.len() >= window {
+            history.pop_front();
+        }
+        history.push_back(keypoints.clone());
+        if history.len() < 2 {
+            return keypoints;
+        }
+
+        let frames: Vec<[KeyPoint; 17]> = history.iter().map(|k| k.to_array()).collect();
+        let latest = keypoints.to_array();
+        let smoothed: [KeyPoint; 17] = std::array::from_fn(|i| {
+            let weight_sum: f32 = frames.iter().map(|f| f[i].confidence).sum();
+            if weight_sum <= 0.0 {
+                return latest[i].clone();
+            }
+            KeyPoint {
+                x: frames.iter().map(|f| f[i].x * f[i].confidence).sum::<f32>() / weight_sum,
+                y: frames.iter().map(|f| f[i].y * f[i].confidence).sum::<f32>() / weight_sum,
+                confidence: weight_sum / frames.len() as f32,
+            }
+        });
+        PoseKeypoints::from_array(smoothed)
+    }
+
+    // 분석 대상 선택 및 신뢰도 기준
+    pub fn confidence_threshold(&self) -> f32 {
+        *self.confidence_threshold.lock()
+    }
+
+    // 신뢰도 기준을 허용 범위로 맞춰 설정하고 실제 적용된 값을 반환
+    // 낮추면 어두운 환경에서도 감지되지만 오감지가 늘고, 높이면 그 반대
+    pub fn set_confidence_threshold(&self, threshold: f32) -> f32 {
+        let applied = threshold.clamp(MIN_CONFIDENCE_THRESHOLD, MAX_CONFIDENCE_THRESHOLD);
+        *self.confidence_threshold.lock() = applied;
+        info!("신뢰도 기준 설정: {} (요청 {})", applied, threshold);
+        applied
+    }
+
     // 주요 키포인트의 평균 신뢰도 계산
     fn calculate_average_confidence(&self, keypoints: &PoseKeypoints) -> f32 {
         let confidences = vec![
@@ -503,11 +1887,17 @@ impl PoseAnalyzer {
     fn detect_turtle_neck(&self, keypoints: &PoseKeypoints) -> bool {
         // ✨ 수정: 설정된 감지 강도(thresholds)를 사용
         let (ratio_tolerance, forward_tolerance) = *self.turtle_neck_thresholds.lock();
+        let std_devs = self.baseline_std_devs.lock().clone();
 
         let is_face_too_close = {
             if let Some(baseline_ratio) = *self.baseline_face_shoulder_ratio.lock() {
                 if let Some(current_ratio) = self.calculate_face_shoulder_ratio(keypoints) {
-                    current_ratio > baseline_ratio + ratio_tolerance
+                    BaselineRatios::exceeds(
+                        current_ratio,
+                        baseline_ratio,
+                        ratio_tolerance,
+                        std_devs.face_shoulder_ratio,
+                    )
                 } else {
                     false
                 }
@@ -519,7 +1909,12 @@ impl PoseAnalyzer {
         let is_head_forward = {
             if let Some(baseline_forward) = *self.baseline_head_forward_ratio.lock() {
                 if let Some(current_forward) = self.calculate_head_forward_ratio(keypoints) {
-                    current_forward > baseline_forward + forward_tolerance
+                    BaselineRatios::exceeds(
+                        current_forward,
+                        baseline_forward,
+                        forward_tolerance,
+                        std_devs.head_forward_ratio,
+                    )
                 } else {
                     false
                 }
@@ -536,9 +1931,9 @@ impl PoseAnalyzer {
 
     // 어깨 비대칭 감지 로직
     fn detect_shoulder_misalignment(&self, keypoints: &PoseKeypoints) -> bool {
-        if keypoints.left_shoulder.confidence < 0.5
-            || keypoints.right_shoulder.confidence < 0.5
-            || keypoints.nose.confidence < 0.5
+        if keypoints.left_shoulder.confidence < self.confidence_threshold()
+            || keypoints.right_shoulder.confidence < self.confidence_threshold()
+            || keypoints.nose.confidence < self.confidence_threshold()
         {
             return false;
         }
@@ -559,7 +1954,13 @@ impl PoseAnalyzer {
         let (tolerance, min_absolute_threshold) = *self.shoulder_alignment_thresholds.lock();
 
         if let Some(baseline_corrected_ratio) = *self.baseline_shoulder_alignment.lock() {
-            let is_worse_than_baseline = corrected_ratio > baseline_corrected_ratio + tolerance;
+            let std_dev = self.baseline_std_devs.lock().shoulder_alignment;
+            let is_worse_than_baseline = BaselineRatios::exceeds(
+                corrected_ratio,
+                baseline_corrected_ratio,
+                tolerance,
+                std_dev,
+            );
             let is_objectively_bad = corrected_ratio > min_absolute_threshold;
             is_worse_than_baseline && is_objectively_bad
         } else {
@@ -568,28 +1969,130 @@ impl PoseAnalyzer {
         }
     }
 
+    // 손목이 얼굴 근처에 있는지 감지 (턱 괴기, 머리 받치기 등)
+    fn detect_hand_on_face(&self, keypoints: &PoseKeypoints) -> bool {
+        if keypoints.nose.confidence < self.confidence_threshold() {
+            return false;
+        }
+        // 머리 크기 기준: 양 귀 사이 거리, 귀가 안 보이면 어깨 너비의 절반
+        let head_size = if keypoints.left_ear.confidence >= self.confidence_threshold() && keypoints.right_ear.confidence >= self.confidence_threshold() {
+            (keypoints.left_ear.x - keypoints.right_ear.x).abs()
+        } else if keypoints.left_shoulder.confidence >= self.confidence_threshold() && keypoints.right_shoulder.confidence >= self.confidence_threshold() {
+            (keypoints.left_shoulder.x - keypoints.right_shoulder.x).abs() * 0.5
+        } else {
+            return false;
+        };
+        if head_size < 1.0 {
+            return false;
+        }
+        let shoulder_line_y = if keypoints.left_shoulder.confidence >= self.confidence_threshold() && keypoints.right_shoulder.confidence >= self.confidence_threshold() {
+            Some((keypoints.left_shoulder.y + keypoints.right_shoulder.y) / 2.0)
+        } else {
+            None
+        };
+
+        [
+            (&keypoints.left_wrist, &keypoints.left_elbow),
+            (&keypoints.right_wrist, &keypoints.right_elbow),
+        ]
+        .iter()
+        .any(|(wrist, elbow)| {
+            if wrist.confidence < self.confidence_threshold() {
+                return false;
+            }
+            let distance = ((wrist.x - keypoints.nose.x).powi(2) + (wrist.y - keypoints.nose.y).powi(2)).sqrt();
+            // 손목이 어깨선보다 위에 있어야 하고, 팔꿈치가 보이면 손목보다 아래에 있어야 함
+            let above_shoulders = shoulder_line_y.map_or(true, |y| wrist.y < y);
+            let elbow_below = elbow.confidence < self.confidence_threshold() || elbow.y > wrist.y;
+            distance < head_size * 1.2 && above_shoulders && elbow_below
+        })
+    }
+
+    // 머리가 어깨선 가까이(또는 아래)까지 떨어졌는지 확인 (책상에 턱을 대거나 심하게 구부정한 자세)
+    // 비율 기반 거북목 감지는 머리 크기와 어깨 너비가 함께 줄어들면 놓칠 수 있어 세로 위치를 직접 비교
+    fn detect_head_dropped(&self, keypoints: &PoseKeypoints) -> bool {
+        let threshold = self.confidence_threshold();
+        if keypoints.left_shoulder.confidence < threshold || keypoints.right_shoulder.confidence < threshold {
+            return false;
+        }
+        let shoulder_width = (keypoints.left_shoulder.x - keypoints.right_shoulder.x).abs();
+        if shoulder_width < 1.0 {
+            return false;
+        }
+        // 코가 안 보이면 양 눈의 중점으로 대신함
+        let head_y = if keypoints.nose.confidence >= threshold {
+            keypoints.nose.y
+        } else if keypoints.left_eye.confidence >= threshold && keypoints.right_eye.confidence >= threshold {
+            (keypoints.left_eye.y + keypoints.right_eye.y) / 2.0
+        } else {
+            return false;
+        };
+        let shoulder_line_y = (keypoints.left_shoulder.y + keypoints.right_shoulder.y) / 2.0;
+        // 이미지 좌표계는 y가 아래로 증가하므로 양수면 머리가 어깨선 위에 있음
+        let gap = shoulder_line_y - head_y;
+        gap < shoulder_width * HEAD_DROPPED_MAX_GAP_RATIO
+    }
+
+    // 양쪽 어깨를 함께 귀 쪽으로 올렸는지 확인 (긴장해서 움츠린 자세)
+    // 좌우 대칭이라 어깨 비대칭 감지로는 잡히지 않으므로 캘리브레이션한 어깨-귀 간격과 비교 (기준값이 없으면 판단하지 않음)
+    fn detect_shoulder_tension(&self, keypoints: &PoseKeypoints) -> bool {
+        let Some(baseline) = *self.baseline_shoulder_ear_ratio.lock() else {
+            return false;
+        };
+        let Some(current) = self.calculate_shoulder_ear_ratio(keypoints) else {
+            return false;
+        };
+        let std_dev = self.baseline_std_devs.lock().shoulder_ear_ratio;
+        let beyond_tolerance = current < baseline * (1.0 - SHOULDER_TENSION_MIN_DROP_RATIO);
+        let beyond_variance = std_dev
+            .map(|sd| current < baseline - BASELINE_STD_DEV_MULTIPLIER * sd)
+            .unwrap_or(true);
+        beyond_tolerance && beyond_variance
+    }
+
+    // 더 높이 올라간 어깨 방향 ("left" | "right"), 이미지 좌표계는 y가 아래로 증가
+    fn detect_raised_shoulder_side(&self, keypoints: &PoseKeypoints) -> Option<&'static str> {
+        if keypoints.left_shoulder.confidence < self.confidence_threshold() || keypoints.right_shoulder.confidence < self.confidence_threshold() {
+            return None;
+        }
+        let height_diff = keypoints.left_shoulder.y - keypoints.right_shoulder.y;
+        if height_diff < 0.0 {
+            Some("left")
+        } else if height_diff > 0.0 {
+            Some("right")
+        } else {
+            None
+        }
+    }
+
     // 자세 점수 계산
-    fn calculate_posture_score(
-        &self,
-        turtle_neck_detected: bool,
-        shoulder_misalignment_detected: bool,
-    ) -> u8 {
+    fn calculate_posture_score(&self, flags: &PostureFlags) -> u8 {
+        let weights = self.score_weights();
         let mut score = 100u8;
-        if turtle_neck_detected {
-            score = score.saturating_sub(30);
+        if flags.turtle_neck {
+            score = score.saturating_sub(weights.turtle_neck);
+        }
+        if flags.shoulder_misalignment {
+            score = score.saturating_sub(weights.shoulder_misalignment);
         }
-        if shoulder_misalignment_detected {
-            score = score.saturating_sub(20);
+        if flags.hand_on_face {
+            score = score.saturating_sub(weights.hand_on_face);
+        }
+        if flags.head_dropped {
+            score = score.saturating_sub(weights.head_dropped);
+        }
+        if flags.shoulder_tension {
+            score = score.saturating_sub(weights.shoulder_tension);
         }
         score
     }
 
     // 얼굴-어깨 비율 계산 (거북목 감지용)
     fn calculate_face_shoulder_ratio(&self, keypoints: &PoseKeypoints) -> Option<f32> {
-        if keypoints.left_eye.confidence < 0.5
-            || keypoints.right_eye.confidence < 0.5
-            || keypoints.left_shoulder.confidence < 0.5
-            || keypoints.right_shoulder.confidence < 0.5
+        if keypoints.left_eye.confidence < self.confidence_threshold()
+            || keypoints.right_eye.confidence < self.confidence_threshold()
+            || keypoints.left_shoulder.confidence < self.confidence_threshold()
+            || keypoints.right_shoulder.confidence < self.confidence_threshold()
         {
             return None;
         }
@@ -602,29 +2105,415 @@ impl PoseAnalyzer {
         }
     }
 
-    // 기준 자세 설정 (캘리브레이션)
-    pub fn set_baseline_posture(
+    // 이미지 한 장에서 바운딩 박스와 화면 이탈 여부만 진단 (감지 이력은 변경하지 않음)
+    pub fn frame_diagnostics(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+    ) -> Result<(BoundingBox, FrameFit), Box<dyn std::error::Error + Send + Sync>> {
+        let detection = self.detect_pose(image)?;
+        let (upright_width, upright_height) = self.upright_dimensions(image.width(), image.height());
+        let in_frame = self.assess_frame_fit(
+            &detection.keypoints,
+            &detection.bounding_box,
+            upright_width,
+            upright_height,
+        );
+        let bounding_box = self.bounding_box_to_display(&detection.bounding_box, image.width(), image.height());
+        Ok((bounding_box, in_frame))
+    }
+
+    // 키포인트와 바운딩 박스가 이미지 경계에 걸렸는지 확인
+    fn assess_frame_fit(
+        &self,
+        keypoints: &PoseKeypoints,
+        bounding_box: &BoundingBox,
+        width: u32,
+        height: u32,
+    ) -> FrameFit {
+        const EDGE_MARGIN_RATIO: f32 = 0.02;
+        let (width, height) = (width as f32, height as f32);
+        let margin_x = width * EDGE_MARGIN_RATIO;
+        let margin_y = height * EDGE_MARGIN_RATIO;
+        let is_cut_off = |point: &KeyPoint| {
+            point.confidence < self.confidence_threshold()
+                || point.x < margin_x
+                || point.x > width - margin_x
+                || point.y < margin_y
+                || point.y > height - margin_y
+        };
+
+        let head_cut_off = is_cut_off(&keypoints.nose)
+            || (is_cut_off(&keypoints.left_ear) && is_cut_off(&keypoints.right_ear));
+        let shoulders_cut_off =
+            is_cut_off(&keypoints.left_shoulder) || is_cut_off(&keypoints.right_shoulder);
+
+        let mut touching_edges = Vec::new();
+        if bounding_box.x < margin_x {
+            touching_edges.push("left");
+        }
+        if bounding_box.x + bounding_box.width > width - margin_x {
+            touching_edges.push("right");
+        }
+        if bounding_box.y < margin_y {
+            touching_edges.push("top");
+        }
+        // 책상 앞에 앉은 상반신은 보통 화면 하단에 걸리므로 하단은 참고용으로만 보고
+        if bounding_box.y + bounding_box.height > height - margin_y {
+            touching_edges.push("bottom");
+        }
+
+        FrameFit {
+            fully_in_frame: !head_cut_off && !shoulders_cut_off,
+            head_cut_off,
+            shoulders_cut_off,
+            touching_edges,
+        }
+    }
+
+    // 웹캠이 모니터 위에 있다고 가정하고, 눈이 화면 중앙보다 얼마나 위/아래에 있는지로 모니터 높이를 추정
+    // 눈이 중앙보다 위면 시선이 아래를 향하므로 모니터를 올리고, 아래면 내리도록 권장
+    // 눈이나 어깨의 신뢰도가 낮으면 추정하지 않음
+    fn estimate_monitor_height(&self, keypoints: &PoseKeypoints, height: u32) -> Option<MonitorHeightAdvice> {
+        const MIN_CONFIDENCE: f32 = 0.5;
+        const DEAD_ZONE_RATIO: f32 = 0.08;
+        const AVERAGE_SHOULDER_WIDTH_CM: f32 = 38.0;
+        const MAX_ESTIMATED_CM: f32 = 30.0;
+
+        let required = [
+            &keypoints.left_eye,
+            &keypoints.right_eye,
+            &keypoints.left_shoulder,
+            &keypoints.right_shoulder,
+        ];
+        if height == 0 || required.iter().any(|point| point.confidence < MIN_CONFIDENCE) {
+            return None;
+        }
+        let shoulder_width = (keypoints.left_shoulder.x - keypoints.right_shoulder.x).abs();
+        if shoulder_width < 1.0 {
+            return None;
+        }
+
+        let eye_y = (keypoints.left_eye.y + keypoints.right_eye.y) / 2.0;
+        let offset_px = height as f32 / 2.0 - eye_y;
+        let eye_offset_ratio = offset_px / height as f32;
+        let direction = if eye_offset_ratio.abs() < DEAD_ZONE_RATIO {
+            "ok"
+        } else if eye_offset_ratio > 0.0 {
+            "raise"
+        } else {
+            "lower"
+        };
+        let estimated_cm = if direction == "ok" {
+            0
+        } else {
+            (offset_px.abs() / shoulder_width * AVERAGE_SHOULDER_WIDTH_CM)
+                .min(MAX_ESTIMATED_CM)
+                .round() as u32
+        };
+        Some(MonitorHeightAdvice {
+            direction,
+            eye_offset_ratio,
+            estimated_cm,
+        })
+    }
+
+    // 어깨 중심(엉덩이가 보이면 엉덩이 중심과 평균)의 화면 중앙 대비 좌우 위치를 기록하고 최근 추세를 반환
+    // 카메라가 사용자를 마주 보므로 화면 왼쪽(x 감소)이 사용자의 오른쪽
+    fn update_lean(&self, keypoints: &PoseKeypoints, width: u32) -> Option<LeanSummary> {
+        const MIN_CONFIDENCE: f32 = 0.5;
+        if width > 0
+            && keypoints.left_shoulder.confidence >= MIN_CONFIDENCE
+            && keypoints.right_shoulder.confidence >= MIN_CONFIDENCE
+        {
+            let shoulder_center = (keypoints.left_shoulder.x + keypoints.right_shoulder.x) / 2.0;
+            let torso_center = if keypoints.left_hip.confidence >= MIN_CONFIDENCE
+                && keypoints.right_hip.confidence >= MIN_CONFIDENCE
+            {
+                (shoulder_center + (keypoints.left_hip.x + keypoints.right_hip.x) / 2.0) / 2.0
+            } else {
+                shoulder_center
+            };
+            let offset = (torso_center - width as f32 / 2.0) / width as f32;
+            let mut offsets = self.recent_lean_offsets.lock();
+            if offsets.len() >= LEAN_WINDOW_SIZE {
+                offsets.pop_front();
+            }
+            offsets.push_back(offset);
+        }
+        self.lean_summary()
+    }
+
+    pub fn lean_summary(&self) -> Option<LeanSummary> {
+        let offsets = self.recent_lean_offsets.lock();
+        if offsets.len() < LEAN_MIN_SAMPLES {
+            return None;
+        }
+        let mean = offsets.iter().sum::<f32>() / offsets.len() as f32;
+        let direction = if mean.abs() < LEAN_THRESHOLD_RATIO {
+            "centered"
+        } else if mean < 0.0 {
+            "right"
+        } else {
+            "left"
+        };
+        Some(LeanSummary {
+            direction,
+            magnitude: mean.abs(),
+            samples: offsets.len(),
+        })
+    }
+
+    // 가장 최근 감지 결과를 COCO 키포인트 annotation 형식으로 반환 (감지 결과가 없으면 None)
+    pub fn latest_pose_coco(&self) -> Option<serde_json::Value> {
+        let latest = self.latest_pose.lock();
+        let pose = latest.as_ref()?;
+        let (keypoints, num_keypoints) = pose.keypoints.to_coco();
+        let bbox = &pose.bounding_box;
+        Some(serde_json::json!({
+            "timestamp_ms": pose.timestamp_ms,
+            "image_width": pose.width,
+            "image_height": pose.height,
+            "category": { "name": "person", "keypoints": COCO_KEYPOINT_NAMES },
+            "keypoints": keypoints,
+            "num_keypoints": num_keypoints,
+            "bbox": [bbox.x, bbox.y, bbox.width, bbox.height],
+            "score": pose.confidence,
+        }))
+    }
+
+    // 얼굴-어깨 비율의 장기 중앙값이 기준값에서 일정 비율 이상 벗어난 상태가 이어지는지 추적
+    fn record_drift_sample(&self, keypoints: &PoseKeypoints) {
+        let Some(baseline) = *self.baseline_face_shoulder_ratio.lock() else {
+            return;
+        };
+        let Some(ratio) = self.calculate_face_shoulder_ratio(keypoints) else {
+            return;
+        };
+        let mut ratios = self.drift_ratios.lock();
+        if ratios.len() >= DRIFT_WINDOW_SIZE {
+            ratios.pop_front();
+        }
+        ratios.push_back(ratio);
+        let mut streak = self.drift_streak.lock();
+        match Self::median(ratios.iter().copied()) {
+            Some(median)
+                if ratios.len() >= DRIFT_MIN_SAMPLES
+                    && baseline > 0.0
+                    && ((median - baseline) / baseline).abs() > DRIFT_THRESHOLD_RATIO =>
+            {
+                *streak += 1;
+            }
+            _ => *streak = 0,
+        }
+    }
+
+    // 드리프트가 충분히 오래 이어졌으면 (현재 중앙값, 기준값)을 한 번만 반환
+    pub fn take_recalibration_suggestion(&self) -> Option<(f32, f32)> {
+        if *self.drift_streak.lock() < DRIFT_SUSTAIN_FRAMES {
+            return None;
+        }
+        let mut suggested = self.drift_suggested.lock();
+        if *suggested {
+            return None;
+        }
+        let baseline = (*self.baseline_face_shoulder_ratio.lock())?;
+        let median = Self::median(self.drift_ratios.lock().iter().copied())?;
+        *suggested = true;
+        Some((median, baseline))
+    }
+
+    fn median(values: impl Iterator<Item = f32>) -> Option<f32> {
+        let mut values: Vec<f32> = values.collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(|a, b| a.total_cmp(b));
+        let mid = values.len() / 2;
+        Some(if values.len() % 2 == 0 {
+            (values[mid - 1] + values[mid]) / 2.0
+        } else {
+            values[mid]
+        })
+    }
+
+    fn reset_drift_tracking(&self) {
+        self.drift_ratios.lock().clear();
+        *self.drift_streak.lock() = 0;
+        *self.drift_suggested.lock() = false;
+    }
+
+    // 카메라 소스/포맷/회전이 바뀌면 이전 화면 기준의 모든 이력을 비움 (기울임 추세 포함)
+    pub fn reset_for_source_change(&self) {
+        self.clear_recent_results();
+        self.recent_lean_offsets.lock().clear();
+    }
+
+    // 감지 기준 조정용 원시 측정값: 한 프레임의 비율, 현재 기준값과 감지 강도, 감지 항목별 판정 결과
+    // (스무딩과 시간축 판정, 분석 기록 갱신 없이 이 프레임만으로 계산)
+    pub fn raw_detection_values(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error + Send + Sync>> {
+        let detection = self.detect_pose(image)?;
+        let keypoints = &detection.keypoints;
+        Ok(serde_json::json!({
+            "ratios": {
+                "face_shoulder_ratio": self.calculate_face_shoulder_ratio(keypoints),
+                "head_forward_ratio": self.calculate_head_forward_ratio(keypoints),
+                "shoulder_alignment": self.calculate_shoulder_alignment_ratio(keypoints),
+                "shoulder_ear_ratio": self.calculate_shoulder_ear_ratio(keypoints),
+            },
+            "baseline": self.baseline_to_json(),
+            "turtle_neck_thresholds": *self.turtle_neck_thresholds.lock(),
+            "shoulder_alignment_thresholds": *self.shoulder_alignment_thresholds.lock(),
+            "detected": {
+                "turtle_neck": self.detect_turtle_neck(keypoints),
+                "shoulder_misalignment": self.detect_shoulder_misalignment(keypoints),
+                "hand_on_face": self.detect_hand_on_face(keypoints),
+                "head_dropped": self.detect_head_dropped(keypoints),
+                "shoulder_tension": self.detect_shoulder_tension(keypoints),
+            },
+            "confidence": detection.confidence,
+            "keypoint_confidence": self.calculate_average_confidence(keypoints),
+        }))
+    }
+
+    // 저장된 이미지 한 장을 분석 (실시간 모니터링 상태는 바꾸지 않음)
+    // 키포인트 스무딩, 감지 이력, 기울기/드리프트 추적, 최근 포즈, 팁 순환, 카메라 회전을 모두 적용하지 않고 이 이미지의 감지 결과만 반환
+    pub fn analyze_still_image(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error + Send + Sync>> {
+        let detection = self.detect_upright_pose(
+            image,
+            self.inference_input_size(),
+            self.low_power_inference(),
+            self.resize_filter(),
+        )?;
+        let keypoints = &detection.keypoints;
+        let toggles = self.detector_toggles();
+        let turtle_neck = toggles.turtle_neck.then(|| self.detect_turtle_neck(keypoints));
+        let shoulder_misalignment = toggles
+            .shoulder_misalignment
+            .then(|| self.detect_shoulder_misalignment(keypoints));
+        let hand_on_face = toggles.hand_on_face.then(|| self.detect_hand_on_face(keypoints));
+        let head_dropped = toggles.head_dropped.then(|| self.detect_head_dropped(keypoints));
+        let shoulder_tension = toggles
+            .shoulder_tension
+            .then(|| self.detect_shoulder_tension(keypoints));
+        let shoulder_misalignment_side = if shoulder_misalignment == Some(true) {
+            self.detect_raised_shoulder_side(keypoints)
+        } else {
+            None
+        };
+        let posture_score = self.calculate_posture_score(&PostureFlags {
+            turtle_neck: turtle_neck.unwrap_or(false),
+            shoulder_misalignment: shoulder_misalignment.unwrap_or(false),
+            hand_on_face: hand_on_face.unwrap_or(false),
+            head_dropped: head_dropped.unwrap_or(false),
+            shoulder_tension: shoulder_tension.unwrap_or(false),
+        });
+        let (coco_keypoints, num_keypoints) = keypoints.to_coco();
+        let bbox = &detection.bounding_box;
+        Ok(serde_json::json!({
+            "schema_version": ANALYSIS_SCHEMA_VERSION,
+            "turtle_neck": turtle_neck,
+            "shoulder_misalignment": shoulder_misalignment,
+            "shoulder_misalignment_side": shoulder_misalignment_side,
+            "hand_on_face": hand_on_face,
+            "head_dropped": head_dropped,
+            "shoulder_tension": shoulder_tension,
+            "posture_score": posture_score,
+            "confidence": self.calculate_average_confidence(keypoints),
+            "detection_confidence": detection.confidence,
+            "image_width": image.width(),
+            "image_height": image.height(),
+            "keypoints": {
+                "category": { "name": "person", "keypoints": COCO_KEYPOINT_NAMES },
+                "keypoints": coco_keypoints,
+                "num_keypoints": num_keypoints,
+                "bbox": [bbox.x, bbox.y, bbox.width, bbox.height],
+                "score": detection.confidence,
+            },
+            "status": "yolo_analysis_success",
+        }))
+    }
+
+    // 카메라 화면 품질 평가: 주요 키포인트 평균 신뢰도 (사람이 없으면 None). 분석 기록은 바꾸지 않음
+    pub fn view_quality(&self, image: &ImageBuffer<Rgb<u8>, Vec<u8>>) -> Option<f32> {
+        let keypoints = self.extract_pose_keypoints(image).ok()?;
+        Some(self.calculate_average_confidence(&keypoints))
+    }
+
+    // 캘리브레이션 미리보기: 기준 자세와 같은 방식으로 비율만 계산하고 저장하지 않음
+    pub fn preview_baseline_posture(
         &self,
         base64_data: &str,
+    ) -> Result<(BaselineRatios, f32), Box<dyn std::error::Error + Send + Sync>> {
+        let image_data = self.decode_base64_image(base64_data)?;
+        self.measure_baseline_ratios(&image_data)
+    }
+
+    // 이미지 한 장에서 기준 비율과 주요 키포인트 평균 신뢰도를 측정 (기준값은 변경하지 않음)
+    pub fn measure_baseline_ratios(
+        &self,
+        image: &ImageBuffer<Rgb<u8>, Vec<u8>>,
+    ) -> Result<(BaselineRatios, f32), Box<dyn std::error::Error + Send + Sync>> {
+        let keypoints = self.extract_pose_keypoints(image)?;
+        let ratios = BaselineRatios {
+            face_shoulder_ratio: self.calculate_face_shoulder_ratio(&keypoints),
+            shoulder_alignment: self.calculate_shoulder_alignment_ratio(&keypoints),
+            head_forward_ratio: self.calculate_head_forward_ratio(&keypoints),
+            shoulder_ear_ratio: self.calculate_shoulder_ear_ratio(&keypoints),
+        };
+        Ok((ratios, self.calculate_average_confidence(&keypoints)))
+    }
+
+    // 측정된 비율을 기준 자세로 적용하고 파일에 저장 (단일 측정이므로 표준편차는 초기화)
+    pub fn apply_baseline(
+        &self,
+        ratios: &BaselineRatios,
         handle: &AppHandle,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let image_data = self.decode_base64_image(base64_data)?;
-        let keypoints = self.extract_pose_keypoints(&image_data)?;
+        self.apply_baseline_with_std_devs(ratios, &BaselineRatios::default(), handle)
+    }
+
+    // 측정된 비율과 표준편차를 기준 자세로 적용하고 파일에 저장
+    pub fn apply_baseline_with_std_devs(
+        &self,
+        ratios: &BaselineRatios,
+        std_devs: &BaselineRatios,
+        handle: &AppHandle,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.apply_baseline_with_quality(ratios, std_devs, None, handle)
+    }
 
-        if let Some(ratio) = self.calculate_face_shoulder_ratio(&keypoints) {
+    // 기준 자세 적용과 함께 캘리브레이션 시각과 안정도 점수를 기록
+    pub fn apply_baseline_with_quality(
+        &self,
+        ratios: &BaselineRatios,
+        std_devs: &BaselineRatios,
+        quality: Option<f32>,
+        handle: &AppHandle,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.reset_drift_tracking();
+        *self.baseline_std_devs.lock() = std_devs.clone();
+        if let Some(ratio) = ratios.face_shoulder_ratio {
             *self.baseline_face_shoulder_ratio.lock() = Some(ratio);
         }
-        if let Some(shoulder_alignment) = self.calculate_shoulder_alignment_ratio(&keypoints) {
+        if let Some(shoulder_alignment) = ratios.shoulder_alignment {
             *self.baseline_shoulder_alignment.lock() = Some(shoulder_alignment);
         }
-        if let Some(forward_ratio) = self.calculate_head_forward_ratio(&keypoints) {
+        if let Some(forward_ratio) = ratios.head_forward_ratio {
             *self.baseline_head_forward_ratio.lock() = Some(forward_ratio);
         }
+        if let Some(shoulder_ear_ratio) = ratios.shoulder_ear_ratio {
+            *self.baseline_shoulder_ear_ratio.lock() = Some(shoulder_ear_ratio);
+        }
 
-        if self.baseline_face_shoulder_ratio.lock().is_some()
-            || self.baseline_shoulder_alignment.lock().is_some()
-            || self.baseline_head_forward_ratio.lock().is_some()
-        {
+        if self.is_calibrated() {
+            *self.baseline_calibrated_at.lock() = Some(chrono::Utc::now().timestamp());
+            *self.baseline_quality.lock() = quality;
             self.save_baseline_to_file(handle)?;
             Ok(())
         } else {
@@ -633,15 +2522,11 @@ impl PoseAnalyzer {
     }
 
     // 베이스라인을 파일에 저장
-    fn save_baseline_to_file(&self, handle: &AppHandle) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    pub fn save_baseline_to_file(&self, handle: &AppHandle) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let app_data_path = handle.path().app_data_dir().map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?;
         let baseline_file = app_data_path.join("baseline.json");
 
-        let baseline_data = serde_json::json!({
-            "face_shoulder_ratio": *self.baseline_face_shoulder_ratio.lock(),
-            "shoulder_alignment": *self.baseline_shoulder_alignment.lock(),
-            "head_forward_ratio": *self.baseline_head_forward_ratio.lock()
-        });
+        let baseline_data = self.baseline_to_json();
 
         let json_str = serde_json::to_string_pretty(&baseline_data)?;
         std::fs::write(&baseline_file, json_str)?;
@@ -649,6 +2534,124 @@ impl PoseAnalyzer {
         Ok(())
     }
 
+    // 베이스라인을 미설정 상태로 되돌리고 baseline.json을 삭제 (삭제한 파일이 있었으면 true)
+    pub fn clear_baseline(&self, handle: &AppHandle) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        self.apply_baseline_json(&serde_json::json!({}));
+        self.reset_drift_tracking();
+        let app_data_path = handle.path().app_data_dir().map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?;
+        let baseline_file = app_data_path.join("baseline.json");
+        if !baseline_file.exists() {
+            return Ok(false);
+        }
+        std::fs::remove_file(&baseline_file)?;
+        info!("베이스라인 삭제 완료: {:?}", baseline_file);
+        Ok(true)
+    }
+
+    // 현재 베이스라인을 baseline.json 형식으로 변환
+    pub fn baseline_to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "face_shoulder_ratio": *self.baseline_face_shoulder_ratio.lock(),
+            "shoulder_alignment": *self.baseline_shoulder_alignment.lock(),
+            "head_forward_ratio": *self.baseline_head_forward_ratio.lock(),
+            "shoulder_ear_ratio": *self.baseline_shoulder_ear_ratio.lock(),
+            "std_devs": *self.baseline_std_devs.lock(),
+            "calibrated_at": *self.baseline_calibrated_at.lock(),
+            "quality_score": *self.baseline_quality.lock()
+        })
+    }
+
+    // 기준 자세가 하나라도 설정되어 있는지
+    pub fn is_calibrated(&self) -> bool {
+        self.baseline_face_shoulder_ratio.lock().is_some()
+            || self.baseline_shoulder_alignment.lock().is_some()
+            || self.baseline_head_forward_ratio.lock().is_some()
+            || self.baseline_shoulder_ear_ratio.lock().is_some()
+    }
+
+    // 마지막 캘리브레이션 시각 (유닉스 초, 기록이 없으면 None)
+    pub fn calibrated_at(&self) -> Option<i64> {
+        *self.baseline_calibrated_at.lock()
+    }
+
+    // 마지막 캘리브레이션의 안정도 점수 (연속 촬영 캘리브레이션에서만 기록)
+    pub fn calibration_quality(&self) -> Option<f32> {
+        *self.baseline_quality.lock()
+    }
+
+    // 가져온 기준 자세 검증: 비율은 없거나(null) 0 이상의 유한한 숫자, 하나 이상은 있어야 하며
+    // 표준편차는 0 이상, 안정도 점수는 0~100, 캘리브레이션 시각은 정수여야 함
+    pub fn validate_baseline_json(baseline_data: &serde_json::Value) -> Result<()> {
+        fn non_negative(value: Option<&serde_json::Value>, name: &str) -> Result<Option<f64>> {
+            match value {
+                None | Some(serde_json::Value::Null) => Ok(None),
+                Some(v) => v
+                    .as_f64()
+                    .filter(|n| n.is_finite() && *n >= 0.0)
+                    .map(Some)
+                    .ok_or_else(|| anyhow!("기준 자세의 {} 값이 올바르지 않습니다: {}", name, v)),
+            }
+        }
+        if !baseline_data.is_object() {
+            return Err(anyhow!("기준 자세 형식이 올바르지 않습니다."));
+        }
+        for key in BASELINE_RATIO_KEYS {
+            non_negative(baseline_data.get(key), key)?;
+        }
+        if !Self::baseline_json_has_ratios(baseline_data) {
+            return Err(anyhow!("기준 자세에 측정값이 없습니다."));
+        }
+        match baseline_data.get("std_devs") {
+            None | Some(serde_json::Value::Null) => {}
+            Some(std_devs) if std_devs.is_object() => {
+                for key in BASELINE_RATIO_KEYS {
+                    non_negative(std_devs.get(key), &format!("{} 표준편차", key))?;
+                }
+            }
+            Some(v) => return Err(anyhow!("기준 자세의 표준편차 형식이 올바르지 않습니다: {}", v)),
+        }
+        if non_negative(baseline_data.get("quality_score"), "quality_score")?.is_some_and(|q| q > 100.0) {
+            return Err(anyhow!("기준 자세의 안정도 점수는 0~100이어야 합니다."));
+        }
+        match baseline_data.get("calibrated_at") {
+            None | Some(serde_json::Value::Null) => {}
+            Some(v) if v.as_i64().is_some() => {}
+            Some(v) => return Err(anyhow!("기준 자세의 캘리브레이션 시각이 올바르지 않습니다: {}", v)),
+        }
+        Ok(())
+    }
+
+    // 기준 자세 JSON에 측정된 비율이 하나라도 있는지 (캘리브레이션하지 않은 기기의 값이면 false)
+    pub fn baseline_json_has_ratios(baseline_data: &serde_json::Value) -> bool {
+        BASELINE_RATIO_KEYS
+            .iter()
+            .any(|key| baseline_data.get(key).is_some_and(|v| !v.is_null()))
+    }
+
+    // baseline.json 형식의 값으로 베이스라인을 교체 (없는 항목은 미설정으로 처리)
+    pub fn apply_baseline_json(&self, baseline_data: &serde_json::Value) {
+        let read = |key: &str| baseline_data.get(key).and_then(|v| v.as_f64()).map(|v| v as f32);
+        *self.baseline_face_shoulder_ratio.lock() = read("face_shoulder_ratio");
+        *self.baseline_shoulder_alignment.lock() = read("shoulder_alignment");
+        *self.baseline_head_forward_ratio.lock() = read("head_forward_ratio");
+        *self.baseline_shoulder_ear_ratio.lock() = read("shoulder_ear_ratio");
+        *self.baseline_calibrated_at.lock() = baseline_data.get("calibrated_at").and_then(|v| v.as_i64());
+        *self.baseline_quality.lock() = read("quality_score");
+        let std_devs = baseline_data.get("std_devs");
+        let read_std = |key: &str| {
+            std_devs
+                .and_then(|v| v.get(key))
+                .and_then(|v| v.as_f64())
+                .map(|v| v as f32)
+        };
+        *self.baseline_std_devs.lock() = BaselineRatios {
+            face_shoulder_ratio: read_std("face_shoulder_ratio"),
+            shoulder_alignment: read_std("shoulder_alignment"),
+            head_forward_ratio: read_std("head_forward_ratio"),
+            shoulder_ear_ratio: read_std("shoulder_ear_ratio"),
+        };
+    }
+
     // 베이스라인을 파일에서 로드
     pub fn load_baseline_from_file(&self, handle: &AppHandle) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let app_data_path = handle.path().app_data_dir().map_err(|e| format!("앱 데이터 디렉토리를 찾을 수 없습니다: {}", e))?;
@@ -657,16 +2660,7 @@ impl PoseAnalyzer {
         if baseline_file.exists() {
             let json_str = std::fs::read_to_string(&baseline_file)?;
             let baseline_data: serde_json::Value = serde_json::from_str(&json_str)?;
-
-            if let Some(ratio) = baseline_data.get("face_shoulder_ratio").and_then(|v| v.as_f64()) {
-                *self.baseline_face_shoulder_ratio.lock() = Some(ratio as f32);
-            }
-            if let Some(alignment) = baseline_data.get("shoulder_alignment").and_then(|v| v.as_f64()) {
-                *self.baseline_shoulder_alignment.lock() = Some(alignment as f32);
-            }
-            if let Some(forward) = baseline_data.get("head_forward_ratio").and_then(|v| v.as_f64()) {
-                *self.baseline_head_forward_ratio.lock() = Some(forward as f32);
-            }
+            self.apply_baseline_json(&baseline_data);
             info!("베이스라인 로드 완료: {:?}", baseline_file);
         } else {
             info!("베이스라인 파일이 존재하지 않습니다: {:?}", baseline_file);
@@ -676,9 +2670,9 @@ impl PoseAnalyzer {
 
     // 어깨 정렬 비율 계산 (어깨 비대칭 감지용)
     fn calculate_shoulder_alignment_ratio(&self, keypoints: &PoseKeypoints) -> Option<f32> {
-        if keypoints.left_shoulder.confidence < 0.5
-            || keypoints.right_shoulder.confidence < 0.5
-            || keypoints.nose.confidence < 0.5
+        if keypoints.left_shoulder.confidence < self.confidence_threshold()
+            || keypoints.right_shoulder.confidence < self.confidence_threshold()
+            || keypoints.nose.confidence < self.confidence_threshold()
         {
             return None;
         }
@@ -692,12 +2686,31 @@ impl PoseAnalyzer {
         }
     }
 
+    // 어깨-귀 세로 간격을 어깨 너비로 나눈 비율 (어깨 긴장 감지용). 귀가 잘 보이지 않으면 계산하지 않음
+    fn calculate_shoulder_ear_ratio(&self, keypoints: &PoseKeypoints) -> Option<f32> {
+        if keypoints.left_ear.confidence < self.confidence_threshold()
+            || keypoints.right_ear.confidence < self.confidence_threshold()
+            || keypoints.left_shoulder.confidence < self.confidence_threshold()
+            || keypoints.right_shoulder.confidence < self.confidence_threshold()
+        {
+            return None;
+        }
+        let shoulder_width = (keypoints.right_shoulder.x - keypoints.left_shoulder.x).abs();
+        if shoulder_width < 1.0 {
+            return None;
+        }
+        // 이미지 좌표계는 y가 아래로 증가하므로 어깨 y - 귀 y가 세로 간격
+        let left_gap = keypoints.left_shoulder.y - keypoints.left_ear.y;
+        let right_gap = keypoints.right_shoulder.y - keypoints.right_ear.y;
+        Some((left_gap + right_gap) / 2.0 / shoulder_width)
+    }
+
     // 머리 전방 비율 계산 (거북목 감지용)
     fn calculate_head_forward_ratio(&self, keypoints: &PoseKeypoints) -> Option<f32> {
-        if keypoints.left_ear.confidence < 0.5
-            || keypoints.right_ear.confidence < 0.5
-            || keypoints.left_shoulder.confidence < 0.5
-            || keypoints.right_shoulder.confidence < 0.5
+        if keypoints.left_ear.confidence < self.confidence_threshold()
+            || keypoints.right_ear.confidence < self.confidence_threshold()
+            || keypoints.left_shoulder.confidence < self.confidence_threshold()
+            || keypoints.right_shoulder.confidence < self.confidence_threshold()
         {
             return None;
         }
@@ -711,25 +2724,76 @@ impl PoseAnalyzer {
         } // 절대값으로 변경하여 좌우 방향에 무관하게 전방 기울기만 측정
     }
 
+    // 팁 묶음(pool)에서 이번에 보여줄 순번. 마지막으로 바뀐 뒤 cooldown이 지났으면 다음 순번으로 넘김
+    // (처음 요청한 묶음은 0번부터 시작, 알림 문구처럼 매번 바꿀 때는 cooldown을 0으로 전달)
+    pub fn next_tip_variant(&self, pool: &str, pool_size: usize, cooldown: Duration) -> usize {
+        if pool_size <= 1 {
+            return 0;
+        }
+        let now = Instant::now();
+        let mut rotation = self.tip_rotation.lock();
+        let Some(entry) = rotation.get_mut(pool) else {
+            rotation.insert(pool.to_string(), (0, now));
+            return 0;
+        };
+        if now.duration_since(entry.1) >= cooldown {
+            *entry = ((entry.0 + 1) % pool_size, now);
+        }
+        entry.0.min(pool_size - 1)
+    }
+
+    fn rotating_tip(&self, pool: &str, tips: &[&str]) -> String {
+        let cooldown = Duration::from_secs(TIP_ROTATION_COOLDOWN_SECS);
+        tips[self.next_tip_variant(pool, tips.len(), cooldown)].to_string()
+    }
+
     // 감지 결과에 따른 추천 메시지 생성
     fn generate_recommendations(
         &self,
-        turtle_neck: bool,
-        shoulder_misalignment: bool,
+        flags: &PostureFlags,
+        shoulder_misalignment_side: Option<&str>,
+        monitor_height: Option<&MonitorHeightAdvice>,
+        lean: Option<&LeanSummary>,
     ) -> Vec<String> {
         // 프론트엔드 i18n 처리에 맞춰 '키'를 반환하도록 변경합니다.
         // 프론트엔드는 수신된 값이 'tip1' 같은 tip 키이면 `dashboard.tips.<key>`로,
         // 'motivation.excellent' 같은 dotted key이면 `dashboard.<dotted>`로 해석합니다.
         let mut recommendations = Vec::new();
-        if turtle_neck {
-            // dashboard.tips.tip1, dashboard.tips.tip2에 매핑되는 키
-            recommendations.push("tip1".to_string());
-            recommendations.push("tip2".to_string());
+        if flags.turtle_neck {
+            // 일반 팁은 TURTLE_NECK_TIPS에서 돌아가며 안내 (dashboard.tips.tip1 등)
+            recommendations.push(self.rotating_tip("turtle_neck", TURTLE_NECK_TIPS));
+            // 모니터 높이를 추정할 수 있으면 일반적인 눈높이 팁 대신 방향별 팁(dashboard.tips.monitorRaise 등)을 안내
+            match monitor_height.map(|advice| advice.direction) {
+                Some("raise") => recommendations.push("monitorRaise".to_string()),
+                Some("lower") => recommendations.push("monitorLower".to_string()),
+                _ => recommendations.push("tip2".to_string()),
+            }
+        }
+        if flags.shoulder_misalignment {
+            // 올라간 쪽을 알면 방향별 팁(dashboard.tips.shoulderLeftRaised 등)을 우선 안내
+            match shoulder_misalignment_side {
+                Some("left") => recommendations.push("shoulderLeftRaised".to_string()),
+                Some("right") => recommendations.push("shoulderRightRaised".to_string()),
+                _ => {}
+            }
+            // 일반 팁은 SHOULDER_TIPS에서 돌아가며 안내 (dashboard.tips.tip4 등)
+            recommendations.push(self.rotating_tip("shoulder_misalignment", SHOULDER_TIPS));
+        }
+        if matches!(lean.map(|lean| lean.direction), Some("left") | Some("right")) {
+            // 한쪽으로 기울어 앉는 습관이 이어지면 중앙에 앉도록 안내 (dashboard.tips.sitCentered)
+            recommendations.push("sitCentered".to_string());
         }
-        if shoulder_misalignment {
-            // dashboard.tips.tip4, dashboard.tips.tip5에 매핑되는 키
-            recommendations.push("tip4".to_string());
-            recommendations.push("tip5".to_string());
+        if flags.head_dropped {
+            // 머리가 어깨 높이까지 떨어진 심한 자세는 바로 앉도록 안내 (dashboard.tips.sitUpright)
+            recommendations.push("sitUpright".to_string());
+        }
+        if flags.shoulder_tension {
+            // 양쪽 어깨가 함께 올라간 긴장 자세는 어깨 힘을 빼도록 안내 (dashboard.tips.relaxShoulders)
+            recommendations.push("relaxShoulders".to_string());
+        }
+        if flags.hand_on_face {
+            // 턱을 괴는 자세는 피로 신호이므로 휴식 권장 (dashboard.tips.takeBreak)
+            recommendations.push("takeBreak".to_string());
         }
         if recommendations.is_empty() {
             // 전체 네임스페이스가 dashboard.motivation.excellent로 존재하므로 dotted key 전송
@@ -738,3 +2802,140 @@ impl PoseAnalyzer {
         recommendations
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_temporal_result_drops_out_of_order_captures() {
+        let analyzer = PoseAnalyzer::new();
+        let history = &analyzer.recent_turtle_neck_results;
+        let latched = &analyzer.turtle_neck_latched;
+
+        // 기본 기준은 3번 중 2번 감지 시 켜짐
+        assert!(!analyzer.push_temporal_result(history, latched, true, 2));
+        // 이미 반영된 순번 2보다 먼저 캡처된 프레임은 이력에 넣지 않고 현재 상태만 반환
+        assert!(analyzer.is_stale_capture(1));
+        assert!(!analyzer.push_temporal_result(history, latched, true, 1));
+        assert_eq!(history.lock().len(), 1);
+
+        assert!(analyzer.push_temporal_result(history, latched, true, 3));
+        let sequences: Vec<u64> = history.lock().iter().map(|&(sequence, _)| sequence).collect();
+        assert_eq!(sequences, vec![2, 3]);
+    }
+
+    // 회전된 테스트 이미지의 한 점을 바로 세운 이미지에서 찾은 뒤(감지 위치), 화면 표시 좌표로 되돌리면 원래 위치인지 확인
+    #[test]
+    fn rotated_frame_detections_map_back_to_display() {
+        let (width, height) = (8u32, 5u32);
+        let (x, y) = (6u32, 1u32);
+        for rotation in [0u16, 90, 180, 270] {
+            let analyzer = PoseAnalyzer::new();
+            analyzer.set_camera_rotation(rotation).unwrap();
+            let mut frame = ImageBuffer::<Rgb<u8>, Vec<u8>>::new(width, height);
+            frame.put_pixel(x, y, Rgb([255, 255, 255]));
+
+            let upright = analyzer.rotate_to_upright(&frame).unwrap_or_else(|| frame.clone());
+            assert_eq!(
+                (upright.width(), upright.height()),
+                analyzer.upright_dimensions(width, height)
+            );
+            let (u, v, _) = upright
+                .enumerate_pixels()
+                .find(|(_, _, pixel)| pixel.0 == [255, 255, 255])
+                .unwrap();
+
+            // 픽셀 중심 좌표로 비교
+            let point = KeyPoint { x: u as f32 + 0.5, y: v as f32 + 0.5, confidence: 1.0 };
+            let points = PoseKeypoints::from_array(std::array::from_fn(|_| point.clone()));
+            let display = analyzer.keypoints_to_display(&points, width, height);
+            assert_eq!((display.nose.x, display.nose.y), (x as f32 + 0.5, y as f32 + 0.5), "회전 {}도", rotation);
+
+            let bounding_box = analyzer.bounding_box_to_display(
+                &BoundingBox { x: u as f32, y: v as f32, width: 1.0, height: 1.0 },
+                width,
+                height,
+            );
+            assert_eq!((bounding_box.x, bounding_box.y), (x as f32, y as f32), "회전 {}도", rotation);
+        }
+    }
+
+    // 카메라 소스가 바뀌면 이전 카메라의 감지 이력, 스무딩용 키포인트, 기울기 추세가 모두 비워지는지 확인
+    #[test]
+    fn source_change_empties_temporal_buffers() {
+        let analyzer = PoseAnalyzer::new();
+        for sequence in 1..=3 {
+            analyzer.push_temporal_result(
+                &analyzer.recent_turtle_neck_results,
+                &analyzer.turtle_neck_latched,
+                true,
+                sequence,
+            );
+            analyzer.push_temporal_result(
+                &analyzer.recent_shoulder_results,
+                &analyzer.shoulder_latched,
+                true,
+                sequence,
+            );
+        }
+        let point = KeyPoint { x: 10.0, y: 10.0, confidence: 0.9 };
+        analyzer
+            .recent_keypoints
+            .lock()
+            .push_back(PoseKeypoints::from_array(std::array::from_fn(|_| point.clone())));
+        analyzer.recent_lean_offsets.lock().push_back(0.2);
+        assert!(*analyzer.turtle_neck_latched.lock());
+
+        analyzer.reset_for_source_change();
+
+        assert!(analyzer.recent_turtle_neck_results.lock().is_empty());
+        assert!(analyzer.recent_shoulder_results.lock().is_empty());
+        assert!(analyzer.recent_keypoints.lock().is_empty());
+        assert!(analyzer.recent_lean_offsets.lock().is_empty());
+        assert!(!*analyzer.turtle_neck_latched.lock());
+        assert!(!*analyzer.shoulder_latched.lock());
+
+        // 회전 각도 변경도 소스 변경으로 보고 같은 버퍼를 비움
+        analyzer.recent_lean_offsets.lock().push_back(0.2);
+        analyzer.set_camera_rotation(90).unwrap();
+        assert!(analyzer.recent_lean_offsets.lock().is_empty());
+    }
+
+    #[test]
+    fn capture_sequence_increases_monotonically() {
+        let analyzer = PoseAnalyzer::new();
+        let first = analyzer.next_capture_sequence();
+        let second = analyzer.next_capture_sequence();
+        assert!(second > first);
+        assert!(!analyzer.is_stale_capture(first));
+    }
+
+    #[test]
+    fn imported_thresholds_must_be_positive_and_ordered() {
+        assert!(PoseAnalyzer::validate_detection_thresholds((0.03, 0.02), (0.9, 0.18)).is_ok());
+        assert!(PoseAnalyzer::validate_detection_thresholds((f32::NAN, 0.02), (0.9, 0.18)).is_err());
+        assert!(PoseAnalyzer::validate_detection_thresholds((0.03, -0.02), (0.9, 0.18)).is_err());
+        assert!(PoseAnalyzer::validate_detection_thresholds((0.03, 0.02), (0.18, 0.9)).is_err());
+    }
+
+    #[test]
+    fn imported_baseline_is_rejected_when_invalid() {
+        let valid = serde_json::json!({ "face_shoulder_ratio": 0.4, "quality_score": 80.0, "calibrated_at": 1 });
+        assert!(PoseAnalyzer::validate_baseline_json(&valid).is_ok());
+        assert!(PoseAnalyzer::validate_baseline_json(&serde_json::json!({})).is_err());
+        assert!(PoseAnalyzer::validate_baseline_json(&serde_json::json!({ "face_shoulder_ratio": -0.4 })).is_err());
+        assert!(PoseAnalyzer::validate_baseline_json(&serde_json::json!({ "face_shoulder_ratio": "0.4" })).is_err());
+        assert!(PoseAnalyzer::validate_baseline_json(
+            &serde_json::json!({ "face_shoulder_ratio": 0.4, "quality_score": 150.0 })
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn uncalibrated_baseline_export_has_no_ratios() {
+        let analyzer = PoseAnalyzer::new();
+        assert!(!PoseAnalyzer::baseline_json_has_ratios(&analyzer.baseline_to_json()));
+        assert!(PoseAnalyzer::baseline_json_has_ratios(&serde_json::json!({ "head_forward_ratio": 0.1 })));
+    }
+}
@@ -27,6 +27,19 @@ const AboutPage = () => {
   const [currentVersion, setCurrentVersion] = useState<string>(t('about.loading', '로딩 중...'));
   const [updateStatus, setUpdateStatus] = useState<string>('');
   const [checkingUpdate, setCheckingUpdate] = useState<boolean>(false);
+  const [resourceUsage, setResourceUsage] = useState<{ cpu_percent_of_total: number; memory_bytes: number } | null>(null);
+
+  useEffect(() => {
+    // 앱이 사용하는 CPU/메모리를 주기적으로 표시
+    const fetchUsage = () => {
+      invoke<{ cpu_percent_of_total: number; memory_bytes: number }>('get_resource_usage')
+        .then(setResourceUsage)
+        .catch(console.error);
+    };
+    fetchUsage();
+    const timer = setInterval(fetchUsage, 5000);
+    return () => clearInterval(timer);
+  }, []);
 
   useEffect(() => {
     const fetchVersion = async () => {
@@ -69,6 +82,8 @@ const AboutPage = () => {
               <div className="flex justify-between"><span className="font-medium">{t('about.version', '버전')}</span><span>{currentVersion}</span></div>
               <div className="flex justify-between"><span className="font-medium">{t('about.developer', '개발자')}</span><span>dduldduck</span></div>
               <div className="flex justify-between"><span className="font-medium">{t('about.build', '빌드')}</span><span>Tauri + React</span></div>
+              <div className="flex justify-between"><span className="font-medium">{t('about.cpuUsage', 'CPU 사용률')}</span><span>{resourceUsage ? `${resourceUsage.cpu_percent_of_total.toFixed(1)}%` : '-'}</span></div>
+              <div className="flex justify-between"><span className="font-medium">{t('about.memoryUsage', '메모리 사용량')}</span><span>{resourceUsage ? `${(resourceUsage.memory_bytes / 1024 / 1024).toFixed(1)} MB` : '-'}</span></div>
             </div>
             <div className="pt-4 border-t">
               <Button onClick={handleCheckUpdate} disabled={checkingUpdate} className="w-full">
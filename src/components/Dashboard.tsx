@@ -63,25 +63,27 @@ const Dashboard: React.FC = () => {
       setError('');
       const db = await getDb();
 
-      const todayStart = Math.floor(new Date().setHours(0, 0, 0, 0) / 1000);
-      const sixDaysAgo = Math.floor(new Date(new Date().setDate(new Date().getDate() - 5)).setHours(0,0,0,0) / 1000);
+      // posture_log.timestamp는 밀리초 단위, 테스트 감지 기록(is_test)은 통계에서 제외
+      const todayStart = new Date().setHours(0, 0, 0, 0);
+      const sixDaysAgo = new Date(new Date().setDate(new Date().getDate() - 5)).setHours(0,0,0,0);
 
       const [statsResult, chartResult] = await Promise.all([
         db.select<any[]>(`
             SELECT
-                (SELECT COUNT(DISTINCT date(timestamp, 'unixepoch')) FROM posture_log) as total_sessions,
+                (SELECT COUNT(DISTINCT date(timestamp / 1000, 'unixepoch')) FROM posture_log WHERE is_test = 0) as total_sessions,
                 AVG(CASE WHEN timestamp >= $1 THEN score ELSE NULL END) as average_posture_score,
                 SUM(CASE WHEN (is_turtle_neck = 1 OR is_shoulder_misaligned = 1) AND timestamp >= $1 THEN 1 ELSE 0 END) as detection_count_today,
                 COUNT(CASE WHEN timestamp >= $1 THEN 1 ELSE NULL END) as records_today,
                 SUM(CASE WHEN score >= 80 AND timestamp >= $1 THEN 1 ELSE 0 END) as good_records_today
             FROM posture_log
+            WHERE is_test = 0
         `, [todayStart]),
         db.select<DailyScore[]>(`
             SELECT
-                strftime('%m-%d', datetime(timestamp, 'unixepoch', 'localtime')) as name,
+                strftime('%m-%d', datetime(timestamp / 1000, 'unixepoch', 'localtime')) as name,
                 ROUND(AVG(score)) as score
             FROM posture_log
-            WHERE timestamp >= $1
+            WHERE is_test = 0 AND timestamp >= $1
             GROUP BY name
             ORDER BY name ASC
             LIMIT 6